@@ -12,6 +12,12 @@ mod spec;
     about = "A tiny framework for writing specs"
 )]
 struct Cli {
+    /// When to colour output: auto (terminals only, off with NO_COLOR), always, or never
+    #[arg(long, global = true, value_name = "WHEN")]
+    color: Option<String>,
+    /// Output format: text, or json (same as `--json` on list, status, view, templates, next, velocity)
+    #[arg(long, global = true, value_name = "FORMAT")]
+    output: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -23,18 +29,46 @@ enum Commands {
         /// Overwrite existing command files with the latest skill prompts
         #[arg(short, long)]
         force: bool,
+        /// List the embedded skills without writing anything
+        #[arg(long, conflicts_with_all = ["force", "print"])]
+        list_skills: bool,
+        /// Print one embedded skill's content to stdout without writing anything
+        #[arg(long, value_name = "SKILL", conflicts_with = "force")]
+        print: Option<String>,
+        /// Print only the shell completion setup line, without writing skills
+        #[arg(long, conflicts_with_all = ["force", "list_skills", "print"])]
+        print_completion: bool,
+        /// Shell for --print-completion: bash, zsh, or fish (default: from $SHELL)
+        #[arg(long, requires = "print_completion")]
+        shell: Option<String>,
     },
 
     /// Create a new spec
     New {
         /// Spec name in kebab-case
-        spec_name: String,
+        #[arg(required_unless_present = "from_file", conflicts_with = "from_file")]
+        spec_name: Option<String>,
+        /// Create one spec per line of the given file
+        #[arg(long, value_name = "FILE")]
+        from_file: Option<String>,
+        /// Create the spec in this group (same as prefixing the name with `group/`)
+        #[arg(short, long, add = ArgValueCompleter::new(spec::complete_group_names))]
+        group: Option<String>,
         /// Use a named template (from .specs/templates/ or ~/.config/tinyspec/templates/)
         #[arg(short, long)]
         template: Option<String>,
+        /// Set the front matter `applications` (repeatable; also applies to templates)
+        #[arg(short, long = "application", value_name = "NAME", add = ArgValueCompleter::new(spec::complete_repo_names))]
+        applications: Vec<String>,
+        /// Use this file's contents (or stdin with `-`) as the spec body, under generated front matter
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["template", "from_file"])]
+        body_from: Option<String>,
         /// Skip hook execution for this invocation
         #[arg(long)]
         no_hooks: bool,
+        /// Skip the automatic Markdown format pass after writing
+        #[arg(long)]
+        no_format: bool,
     },
 
     /// List all specs
@@ -42,29 +76,80 @@ enum Commands {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Print the curated .specs/_index.md overview instead
+        #[arg(long, conflicts_with = "json")]
+        index: bool,
+        /// Render an aligned table (default when stdout is a terminal)
+        #[arg(long, conflicts_with_all = ["json", "index"])]
+        table: bool,
         /// Include archived specs
-        #[arg(long)]
+        #[arg(long, alias = "archived")]
         include_archived: bool,
         /// Filter by tag
         #[arg(long)]
         tag: Option<String>,
+        /// Skip the status glyph and percentage (cheaper: reads only front matter)
+        #[arg(long, conflicts_with_all = ["json", "index", "table"])]
+        no_status: bool,
+        /// Sort by date (default), name, title, status, or progress
+        #[arg(long, value_name = "KEY", conflicts_with = "index")]
+        sort: Option<String>,
+        /// Reverse the sort order
+        #[arg(long, conflicts_with = "index")]
+        reverse: bool,
+        /// Order and group specs like the dashboard: in progress first, then completed
+        #[arg(long, conflicts_with_all = ["index", "sort", "reverse", "include_archived"])]
+        by_status: bool,
+        /// Only specs changed in git: uncommitted changes, plus commits since --base
+        #[arg(long, conflicts_with_all = ["index", "by_status"])]
+        changed: bool,
+        /// Base ref for --changed, e.g. origin/main (compares from the merge base)
+        #[arg(long, value_name = "REF", requires = "changed")]
+        base: Option<String>,
     },
 
+    /// Regenerate .specs/_index.md from the current specs
+    Reindex,
+
     /// Display the contents of a spec
     View {
-        /// Spec name
-        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
-        spec_name: String,
+        /// Spec name (use `-` to read it from stdin; omit if using --all)
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names), required_unless_present = "all")]
+        spec_name: Option<String>,
+        /// Print every spec, each under a name header (unmapped applications only warn unless --strict)
+        #[arg(long, conflicts_with_all = ["spec_name", "json", "resolve_check"])]
+        all: bool,
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Print only the Implementation Plan section (with --json, its task tree)
+        #[arg(long)]
+        plan: bool,
+        /// Verify that all applications resolve via config, printing nothing on success
+        #[arg(long, alias = "check", conflicts_with_all = ["json", "plan"])]
+        resolve_check: bool,
+        /// Leave unmapped applications as-is with a warning instead of failing
+        #[arg(long, conflicts_with = "strict")]
+        lenient: bool,
+        /// Fail if any application is unmapped (overrides `lenient_applications` in config)
+        #[arg(long)]
+        strict: bool,
+        /// Omit the YAML front matter and print only the body
+        #[arg(long, conflicts_with_all = ["json", "resolve_check"])]
+        no_front_matter: bool,
+        /// Render application references in the body as Markdown links to their URL or path
+        #[arg(long, conflicts_with_all = ["json", "resolve_check"])]
+        render_apps_as_links: bool,
     },
 
     /// Open a spec in your default editor
     Edit {
-        /// Spec name
+        /// Spec name (use `-` to read it from stdin)
         #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
         spec_name: String,
+        /// Open the editor at this section's heading (e.g. "Implementation Plan")
+        #[arg(long, value_name = "HEADING")]
+        section: Option<String>,
     },
 
     /// Delete a spec
@@ -74,57 +159,192 @@ enum Commands {
         spec_name: String,
     },
 
+    /// Rename a spec, keeping its timestamp and group, and update references to it
+    Rename {
+        /// Current spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        old: String,
+        /// New spec name in kebab-case
+        new: String,
+        /// Also set the front-matter title from the new name
+        #[arg(long)]
+        update_title: bool,
+    },
+
     /// Mark a task as complete
     Check {
-        /// Spec name
+        /// Spec name, or `spec:task` to give both at once (use `-` to read it from stdin)
         #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
         spec_name: String,
-        /// Task ID (e.g. A, A.1, B, or emoji like 🧪, 🧪.1)
-        task_id: String,
+        /// Task ID (e.g. A, A.1, B, or emoji like 🧪, 🧪.1), or `next`
+        #[arg(add = ArgValueCompleter::new(spec::complete_check_task_ids))]
+        task_id: Option<String>,
+        /// Pick tasks to check/uncheck from an interactive checklist, saved in one write
+        #[arg(short, long, conflicts_with = "task_id")]
+        interactive: bool,
+        /// Check the one unchecked task whose description contains TEXT (case-insensitive)
+        #[arg(long = "match", value_name = "TEXT", conflicts_with_all = ["task_id", "interactive"])]
+        matching: Option<String>,
+        /// Check only the task's direct subtasks (A.1, A.2, ...), not the task itself
+        #[arg(long, conflicts_with_all = ["interactive", "all"])]
+        subtasks: bool,
+        /// Check the task and all of its subtasks, at every depth
+        #[arg(long, conflicts_with = "interactive")]
+        all: bool,
+        /// Record today's date on the task as ` (done YYYY-MM-DD)`
+        #[arg(long, conflicts_with = "interactive")]
+        timestamp: bool,
         /// Skip hook execution for this invocation
         #[arg(long)]
         no_hooks: bool,
+        /// Skip the automatic Markdown format pass after writing
+        #[arg(long)]
+        no_format: bool,
     },
 
     /// Mark a task as incomplete
     Uncheck {
-        /// Spec name
+        /// Spec name, or `spec:task` to give both at once (use `-` to read it from stdin)
         #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
         spec_name: String,
-        /// Task ID (e.g. A, A.1, B, or emoji like 🧪, 🧪.1)
-        task_id: String,
+        /// Task ID (e.g. A, A.1, B, or emoji like 🧪, 🧪.1), or `next`
+        #[arg(add = ArgValueCompleter::new(spec::complete_uncheck_task_ids))]
+        task_id: Option<String>,
+        /// Pick tasks to check/uncheck from an interactive checklist, saved in one write
+        #[arg(short, long, conflicts_with = "task_id")]
+        interactive: bool,
         /// Skip hook execution for this invocation
         #[arg(long)]
         no_hooks: bool,
+        /// Skip the automatic Markdown format pass after writing
+        #[arg(long)]
+        no_format: bool,
+    },
+
+    /// Add a task to a spec's plan, assigning the next free ID
+    AddTask {
+        /// Spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: String,
+        /// Task description
+        description: String,
+        /// Add a subtask under this task (e.g. A adds A.1, A.2, ...)
+        #[arg(long)]
+        under: Option<String>,
+        /// Use this ID instead of the next free one (e.g. C or A.3)
+        #[arg(long)]
+        id: Option<String>,
+        /// Add to the Test Plan instead of the Implementation Plan
+        #[arg(long)]
+        test: bool,
+        /// Skip the automatic Markdown format pass after writing
+        #[arg(long)]
+        no_format: bool,
+    },
+
+    /// Copy one spec's Implementation Plan into another as unchecked tasks
+    CloneTasks {
+        /// Spec to copy the plan from
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        src: String,
+        /// Spec to copy the plan into
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        dest: String,
+        /// Add after the existing tasks, renumbering copied groups to free letters
+        #[arg(long, conflicts_with = "replace")]
+        append: bool,
+        /// Replace the existing tasks
+        #[arg(long)]
+        replace: bool,
+        /// Skip the automatic Markdown format pass after writing
+        #[arg(long)]
+        no_format: bool,
+    },
+
+    /// Uncheck every task in a spec to start a new iteration
+    Reset {
+        /// Spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: String,
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+        /// Skip the automatic Markdown format pass after writing
+        #[arg(long)]
+        no_format: bool,
+    },
+
+    /// Renumber a spec's task groups as letters (A, B, ...) or numbers (1, 2, ...)
+    ReflowIds {
+        /// Spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: String,
+        /// Numbering scheme: letters or numeric
+        #[arg(long, value_name = "SCHEME")]
+        to: String,
+        /// Skip the automatic Markdown format pass after writing
+        #[arg(long)]
+        no_format: bool,
     },
 
     /// Format a spec's Markdown (or all specs with --all)
     Format {
-        /// Spec name (omit if using --all)
-        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names), required_unless_present = "all")]
+        /// Spec name, or `group/` for every spec in a group (omit if using --all or --group)
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names), required_unless_present_any = ["all", "group"])]
         spec_name: Option<String>,
         /// Format all specs
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["spec_name", "group"])]
         all: bool,
+        /// Format every spec in this group folder
+        #[arg(long, conflicts_with = "spec_name", add = ArgValueCompleter::new(spec::complete_group_names))]
+        group: Option<String>,
+        /// With --all or a group, report specs that would change without writing (fails if any would)
+        #[arg(long)]
+        check: bool,
+    },
+
+    /// Print the next unchecked task and its first unchecked subtask
+    Next {
+        /// Spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Show completion progress for a spec (or all specs)
     Status {
-        /// Spec name (shows all specs if omitted)
+        /// Spec name (shows all specs if omitted; use `-` to read it from stdin)
         #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
         spec_name: Option<String>,
         /// Output as JSON
         #[arg(long)]
         json: bool,
         /// Include archived specs
-        #[arg(long)]
+        #[arg(long, alias = "archived")]
         include_archived: bool,
         /// Ignore test tasks when computing completion
         #[arg(long)]
         skip_tests: bool,
+        /// Count only leaf tasks (tasks without subtasks) toward completion
+        #[arg(long)]
+        count_leaves_only: bool,
         /// Filter by tag
         #[arg(long)]
         tag: Option<String>,
+        /// Pivot all specs by `application`, with per-application subtotals
+        #[arg(long, value_name = "FIELD", conflicts_with = "spec_name")]
+        group_by: Option<String>,
+        /// Also print the spec's task tree with each task's checked state
+        #[arg(long, requires = "spec_name", conflicts_with = "json")]
+        tree: bool,
+        /// List only specs with a Test Plan task annotated `result: fail`
+        #[arg(long, conflicts_with_all = ["spec_name", "group_by"])]
+        failed: bool,
+        /// Count only tasks assigned to this owner (`@owner` at the start of a task)
+        #[arg(long, conflicts_with_all = ["spec_name", "group_by", "failed"])]
+        owner: Option<String>,
     },
 
     /// Manage repository configuration (~/.tinyspec/config.yaml)
@@ -134,13 +354,27 @@ enum Commands {
     },
 
     /// List available spec templates
-    Templates,
+    #[command(alias = "template", args_conflicts_with_subcommands = true)]
+    Templates {
+        /// Output as JSON, including the variables each template references
+        #[arg(long)]
+        json: bool,
+        #[cfg(feature = "remote-templates")]
+        #[command(subcommand)]
+        action: Option<TemplatesAction>,
+    },
 
     /// Launch a real-time TUI dashboard showing spec progress
     Dashboard {
+        /// Open directly in this spec's detail view
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: Option<String>,
         /// Include archived specs
-        #[arg(long)]
+        #[arg(long, alias = "archived")]
         include_archived: bool,
+        /// Refresh interval in milliseconds (50–5000, default 250)
+        #[arg(long, value_name = "MS")]
+        interval: Option<u64>,
     },
 
     /// Search specs by title or body content
@@ -153,6 +387,34 @@ enum Commands {
         /// Filter by spec status
         #[arg(long, value_name = "STATUS")]
         status: Option<String>,
+        /// Match spec titles only, not body content
+        #[arg(long)]
+        title_only: bool,
+        /// Treat the query as a regular expression
+        #[arg(short = 'e', long)]
+        regex: bool,
+    },
+
+    /// Rename a group, moving all of its specs
+    RenameGroup {
+        /// Current group name
+        #[arg(add = ArgValueCompleter::new(spec::complete_group_names))]
+        old: String,
+        /// New group name
+        new: String,
+    },
+
+    /// Move a spec into a group, or out of its group with --ungroup
+    Move {
+        /// Spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: String,
+        /// Target group (created if needed)
+        #[arg(required_unless_present = "ungroup", conflicts_with = "ungroup", add = ArgValueCompleter::new(spec::complete_group_names))]
+        group: Option<String>,
+        /// Move the spec back to the top of .specs/
+        #[arg(long)]
+        ungroup: bool,
     },
 
     /// Move a spec to the archive
@@ -181,6 +443,50 @@ enum Commands {
         all: bool,
     },
 
+    /// Find spec names with more than one file (and clean them up with --apply)
+    Dedupe {
+        /// Keep the newest file for each name and move the older copies to .specs/.trash/
+        #[arg(long)]
+        apply: bool,
+    },
+
+    /// Print the JSON Schema for spec front matter (`lint` validates against it)
+    Schema,
+
+    /// Upgrade every spec to the latest format version
+    Migrate {
+        /// Report what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Show a spec's dependency chain and flag unmet dependencies
+    Deps {
+        /// Spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: String,
+    },
+
+    /// Report tasks completed per day or week, from `check --timestamp` dates
+    Velocity {
+        /// Group completions by ISO week instead of by day
+        #[arg(long)]
+        weekly: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Emit a Mermaid flowchart of a spec's task tree
+    Graph {
+        /// Spec name
+        #[arg(add = ArgValueCompleter::new(spec::complete_spec_names))]
+        spec_name: String,
+        /// Write the Mermaid source to a file instead of stdout
+        #[arg(long, value_name = "FILE")]
+        out: Option<String>,
+    },
+
     /// Manage and test lifecycle hooks
     Hooks {
         #[command(subcommand)]
@@ -214,6 +520,24 @@ enum HooksAction {
     },
 }
 
+#[cfg(feature = "remote-templates")]
+#[derive(Subcommand)]
+enum TemplatesAction {
+    /// Fetch a template from an https URL to a .md file, or all templates in a git repository
+    Add {
+        /// URL of a .md file, or a git repository (ending in .git, or git@/ssh:// form)
+        source: String,
+        /// Save a single-file template under this name instead of the file's name
+        #[arg(long)]
+        name: Option<String>,
+        /// Overwrite existing user templates with the same name
+        #[arg(long)]
+        force: bool,
+    },
+    /// Re-fetch every source listed under `template_sources` in ~/.tinyspec/config.yaml
+    Sync,
+}
+
 #[derive(Subcommand)]
 enum ConfigAction {
     /// Map a repository name to a folder path
@@ -222,96 +546,400 @@ enum ConfigAction {
         repo_name: String,
         /// Folder path
         path: String,
+        /// Link target for `view --render-apps-as-links` (e.g. the repository URL)
+        #[arg(long)]
+        url: Option<String>,
     },
     /// List all repository mappings
     List,
     /// Remove a repository mapping
     Remove {
         /// Repository name
+        #[arg(add = ArgValueCompleter::new(spec::complete_repo_names))]
         repo_name: String,
     },
+    /// Print the config file location tinyspec uses and whether it exists
+    Path,
+}
+
+impl Commands {
+    /// The command's `--json` switch, so `--output json` can turn it on. `None`
+    /// when the command, or a text-only flag given with it, has no JSON form.
+    fn json_flag(&mut self) -> Option<&mut bool> {
+        match self {
+            #[cfg(feature = "remote-templates")]
+            Commands::Templates {
+                action: Some(_), ..
+            } => None,
+            Commands::List {
+                json,
+                index: false,
+                table: false,
+                no_status: false,
+                ..
+            }
+            | Commands::View {
+                json,
+                all: false,
+                resolve_check: false,
+                no_front_matter: false,
+                render_apps_as_links: false,
+                ..
+            }
+            | Commands::Status {
+                json, tree: false, ..
+            }
+            | Commands::Templates { json, .. }
+            | Commands::Next { json, .. }
+            | Commands::Velocity { json, .. } => Some(json),
+            _ => None,
+        }
+    }
+}
+
+/// Apply the global `--output text|json` to the parsed command.
+fn apply_output_format(command: &mut Commands, output: Option<&str>) -> Result<(), String> {
+    match output {
+        None | Some("text") => Ok(()),
+        Some("json") => match command.json_flag() {
+            Some(json) => {
+                *json = true;
+                Ok(())
+            }
+            None => Err("--output json is not supported by this command \
+                 (use it with list, status, view, templates, next, or velocity, without text-only flags)"
+                .to_string()),
+        },
+        Some(other) => Err(format!("Invalid --output value '{other}'. Use: text, json")),
+    }
 }
 
 fn main() {
     clap_complete::CompleteEnv::with_factory(Cli::command).complete();
 
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    if let Err(e) = spec::set_color_mode(cli.color.as_deref())
+        .and_then(|()| apply_output_format(&mut cli.command, cli.output.as_deref()))
+        .and_then(|()| spec::check_project_config())
+    {
+        spec::report_error(&e);
+        process::exit(1);
+    }
 
     let result = match cli.command {
-        Commands::Init { force } => spec::init(force),
+        Commands::Init {
+            force,
+            list_skills,
+            print,
+            print_completion,
+            shell,
+        } => {
+            if print_completion {
+                spec::print_completion(shell.as_deref())
+            } else if list_skills {
+                spec::list_skills()
+            } else if let Some(skill) = print {
+                spec::print_skill(&skill)
+            } else {
+                spec::init(force)
+            }
+        }
         Commands::New {
             spec_name,
+            from_file,
+            group,
             template,
+            applications,
+            body_from,
             no_hooks,
+            no_format,
         } => {
-            if no_hooks {
-                spec::new_spec(&spec_name, template.as_deref())
+            if let Some(file) = from_file {
+                spec::new_specs_from_file(
+                    &file,
+                    group.as_deref(),
+                    template.as_deref(),
+                    &applications,
+                    !no_hooks,
+                    !no_format,
+                )
             } else {
-                spec::new_spec_with_hooks(&spec_name, template.as_deref())
+                spec::with_group(&spec_name.unwrap(), group.as_deref()).and_then(|input| {
+                    let body = body_from.as_deref().map(spec::read_body_arg).transpose()?;
+                    if no_hooks {
+                        spec::new_spec(
+                            &input,
+                            template.as_deref(),
+                            &applications,
+                            body.as_deref(),
+                            !no_format,
+                        )
+                    } else {
+                        spec::new_spec_with_hooks(
+                            &input,
+                            template.as_deref(),
+                            &applications,
+                            body.as_deref(),
+                            !no_format,
+                        )
+                    }
+                })
             }
         }
         Commands::List {
             json,
+            index,
+            table,
             include_archived,
             tag,
-        } => spec::list(json, include_archived, tag.as_deref()),
-        Commands::View { spec_name, json } => spec::view(&spec_name, json),
-        Commands::Edit { spec_name } => spec::edit(&spec_name),
+            no_status,
+            sort,
+            reverse,
+            by_status,
+            changed,
+            base,
+        } => {
+            if index {
+                spec::print_index()
+            } else {
+                spec::list(&spec::ListOptions {
+                    json,
+                    table,
+                    include_archived,
+                    tag: tag.as_deref(),
+                    show_status: !no_status,
+                    sort: sort.as_deref(),
+                    reverse,
+                    by_status,
+                    changed_since: changed.then(|| base.as_deref().unwrap_or("HEAD")),
+                })
+            }
+        }
+        Commands::Reindex => spec::reindex(),
+        Commands::View {
+            spec_name,
+            all,
+            json,
+            plan,
+            resolve_check,
+            lenient,
+            strict,
+            no_front_matter,
+            render_apps_as_links,
+        } => {
+            let lenient = match (lenient, strict) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            };
+            let options = spec::ViewOptions {
+                json,
+                plan,
+                resolve_check,
+                lenient,
+                front_matter: !no_front_matter,
+                links: render_apps_as_links,
+            };
+            if all {
+                spec::view_all(&options)
+            } else {
+                spec::read_spec_name_arg(&spec_name.unwrap())
+                    .and_then(|name| spec::view(&name, &options))
+            }
+        }
+        Commands::Edit { spec_name, section } => spec::read_spec_name_arg(&spec_name)
+            .and_then(|name| spec::edit(&name, section.as_deref())),
         Commands::Delete { spec_name } => spec::delete(&spec_name),
+        Commands::Rename {
+            old,
+            new,
+            update_title,
+        } => spec::rename(&old, &new, update_title),
         Commands::Check {
             spec_name,
             task_id,
+            interactive,
+            matching,
+            subtasks,
+            all,
+            timestamp,
             no_hooks,
-        } => {
-            if no_hooks {
-                spec::check_task_no_hooks(&spec_name, &task_id, true)
+            no_format,
+        } => spec::read_spec_name_arg(&spec_name).and_then(|arg| {
+            let scope = if subtasks {
+                spec::TaskScope::Subtasks
+            } else if all {
+                spec::TaskScope::All
             } else {
-                spec::check_task(&spec_name, &task_id, true)
+                spec::TaskScope::Task
+            };
+            let options = spec::CheckOptions {
+                scope,
+                timestamp,
+                fire_hooks: !no_hooks,
+                format: !no_format,
+            };
+            if interactive {
+                return spec::check_interactive(&arg, &options);
             }
-        }
+            let (name, task_id) = match matching {
+                Some(text) => {
+                    let task_id = spec::find_task_by_text(&arg, &text)?;
+                    (arg, task_id)
+                }
+                None => spec::split_task_ref(&arg, task_id.as_deref())?,
+            };
+            spec::check_task(&name, &task_id, true, &options)
+        }),
         Commands::Uncheck {
             spec_name,
             task_id,
+            interactive,
             no_hooks,
-        } => {
-            if no_hooks {
-                spec::check_task_no_hooks(&spec_name, &task_id, false)
-            } else {
-                spec::check_task(&spec_name, &task_id, false)
+            no_format,
+        } => spec::read_spec_name_arg(&spec_name).and_then(|arg| {
+            let options = spec::CheckOptions {
+                fire_hooks: !no_hooks,
+                format: !no_format,
+                ..Default::default()
+            };
+            if interactive {
+                return spec::check_interactive(&arg, &options);
             }
-        }
-        Commands::Format { spec_name, all } => {
-            if all {
-                spec::format_all_specs()
+            let (name, task_id) = spec::split_task_ref(&arg, task_id.as_deref())?;
+            spec::check_task(&name, &task_id, false, &options)
+        }),
+        Commands::AddTask {
+            spec_name,
+            description,
+            under,
+            id,
+            test,
+            no_format,
+        } => spec::add_task(
+            &spec_name,
+            &description,
+            under.as_deref(),
+            id.as_deref(),
+            test,
+            !no_format,
+        ),
+        Commands::CloneTasks {
+            src,
+            dest,
+            append,
+            replace,
+            no_format,
+        } => spec::clone_tasks(&src, &dest, append, replace, !no_format),
+        Commands::Reset {
+            spec_name,
+            yes,
+            no_format,
+        } => spec::reset(&spec_name, yes, !no_format),
+        Commands::ReflowIds {
+            spec_name,
+            to,
+            no_format,
+        } => spec::reflow_ids(&spec_name, &to, !no_format),
+        Commands::Format {
+            spec_name,
+            all,
+            group,
+            check,
+        } => {
+            let group = group.or_else(|| {
+                spec_name
+                    .as_deref()
+                    .and_then(|name| name.strip_suffix('/'))
+                    .map(String::from)
+            });
+            if all || group.is_some() {
+                spec::format_all_specs(group.as_deref(), check)
+            } else if check {
+                Err("--check needs --all or a group".to_string())
             } else {
                 spec::format_spec(spec_name.as_deref().unwrap())
             }
         }
+        Commands::Next { spec_name, json } => spec::next(&spec_name, json),
         Commands::Status {
             spec_name,
             json,
             include_archived,
             skip_tests,
+            count_leaves_only,
             tag,
-        } => spec::status(
-            spec_name.as_deref(),
-            json,
-            include_archived,
-            skip_tests,
-            tag.as_deref(),
-        ),
+            group_by,
+            tree,
+            failed,
+            owner,
+        } => spec_name
+            .as_deref()
+            .map(spec::read_spec_name_arg)
+            .transpose()
+            .and_then(|name| {
+                spec::status(
+                    name.as_deref(),
+                    &spec::StatusOptions {
+                        json,
+                        include_archived,
+                        skip_tests,
+                        leaves_only: count_leaves_only,
+                        tag: tag.as_deref(),
+                        group_by: group_by.as_deref(),
+                        tree,
+                        failed,
+                        owner: owner.as_deref(),
+                    },
+                )
+            }),
         Commands::Config { action } => match action {
-            ConfigAction::Set { repo_name, path } => spec::config_set(&repo_name, &path),
+            ConfigAction::Set {
+                repo_name,
+                path,
+                url,
+            } => spec::config_set(&repo_name, &path, url.as_deref()),
             ConfigAction::List => spec::config_list(),
             ConfigAction::Remove { repo_name } => spec::config_remove(&repo_name),
+            ConfigAction::Path => spec::config_show_path(),
+        },
+        #[cfg(feature = "remote-templates")]
+        Commands::Templates {
+            action: Some(action),
+            ..
+        } => match action {
+            TemplatesAction::Add {
+                source,
+                name,
+                force,
+            } => spec::template_add(&source, name.as_deref(), force),
+            TemplatesAction::Sync => spec::template_sync(),
         },
-        Commands::Templates => spec::list_templates(),
-        Commands::Dashboard { include_archived } => spec::dashboard::run(include_archived),
+        Commands::Templates { json, .. } => spec::list_templates(json),
+        Commands::Dashboard {
+            spec_name,
+            include_archived,
+            interval,
+        } => spec::dashboard::run(spec_name.as_deref(), include_archived, interval),
         Commands::Search {
             query,
             group,
             status,
-        } => spec::search(&query, group.as_deref(), status.as_deref()),
+            title_only,
+            regex,
+        } => spec::search(
+            &query,
+            group.as_deref(),
+            status.as_deref(),
+            title_only,
+            regex,
+        ),
+        Commands::RenameGroup { old, new } => spec::rename_group(&old, &new),
+        Commands::Move {
+            spec_name,
+            group,
+            ungroup: _,
+        } => spec::move_spec(&spec_name, group.as_deref()),
         Commands::Archive {
             spec_name,
             all_completed,
@@ -324,6 +952,12 @@ fn main() {
         }
         Commands::Unarchive { spec_name } => spec::unarchive_spec(&spec_name),
         Commands::Lint { spec_name, all } => spec::lint(spec_name.as_deref(), all),
+        Commands::Dedupe { apply } => spec::dedupe(apply),
+        Commands::Schema => spec::schema(),
+        Commands::Migrate { dry_run } => spec::migrate(dry_run),
+        Commands::Deps { spec_name } => spec::deps(&spec_name),
+        Commands::Velocity { weekly, json } => spec::velocity(weekly, json),
+        Commands::Graph { spec_name, out } => spec::graph(&spec_name, out.as_deref()),
         Commands::Hooks { action } => match action {
             HooksAction::Test { event } => spec::hooks_test(&event),
         },
@@ -333,7 +967,7 @@ fn main() {
     };
 
     if let Err(e) = result {
-        eprintln!("Error: {e}");
+        spec::report_error(&e);
         process::exit(1);
     }
 }
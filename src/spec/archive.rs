@@ -1,7 +1,11 @@
 use std::fs;
 use std::path::PathBuf;
 
-use super::{collect_spec_files, find_spec, specs_dir};
+use super::errors::with_hint;
+use super::{
+    collect_spec_files, create_dir_within, find_spec, is_spec_file, remove_empty_group_dir,
+    sort_spec_paths, specs_dir,
+};
 
 const ARCHIVE_DIR: &str = "archive";
 
@@ -25,13 +29,13 @@ pub fn archive_spec(name: &str) -> Result<(), String> {
         archive_root.join(group)
     };
 
-    fs::create_dir_all(&dest_dir)
-        .map_err(|e| format!("Failed to create archive directory: {e}"))?;
+    create_dir_within(&dest_dir, &specs_root)?;
 
     let filename = path.file_name().unwrap_or_default();
     let dest = dest_dir.join(filename);
 
     fs::rename(&path, &dest).map_err(|e| format!("Failed to archive spec: {e}"))?;
+    remove_empty_group_dir(parent, &specs_root);
 
     println!("Archived: {}", dest.display());
     Ok(())
@@ -41,7 +45,7 @@ pub fn unarchive_spec(name: &str) -> Result<(), String> {
     // Search within the archive directory
     let archive_root = archive_dir();
     if !archive_root.exists() {
-        return Err(format!("No archived spec found matching '{name}'"));
+        return Err(no_archived_spec(name));
     }
 
     let archived_path = find_archived_spec(name)?;
@@ -58,13 +62,13 @@ pub fn unarchive_spec(name: &str) -> Result<(), String> {
         specs_root.join(group)
     };
 
-    fs::create_dir_all(&dest_dir)
-        .map_err(|e| format!("Failed to create destination directory: {e}"))?;
+    create_dir_within(&dest_dir, &specs_root)?;
 
     let filename = archived_path.file_name().unwrap_or_default();
     let dest = dest_dir.join(filename);
 
     fs::rename(&archived_path, &dest).map_err(|e| format!("Failed to unarchive spec: {e}"))?;
+    remove_empty_group_dir(archived_parent, &archive_root);
 
     println!("Unarchived: {}", dest.display());
     Ok(())
@@ -99,7 +103,7 @@ pub fn archive_all_completed() -> Result<(), String> {
 pub(crate) fn find_archived_spec(name: &str) -> Result<PathBuf, String> {
     let archive_root = archive_dir();
     if !archive_root.exists() {
-        return Err(format!("No archived spec found matching '{name}'"));
+        return Err(no_archived_spec(name));
     }
 
     let mut matches = Vec::new();
@@ -124,7 +128,7 @@ pub(crate) fn find_archived_spec(name: &str) -> Result<PathBuf, String> {
     }
 
     match matches.len() {
-        0 => Err(format!("No archived spec found matching '{name}'")),
+        0 => Err(no_archived_spec(name)),
         1 => Ok(matches.into_iter().next().unwrap()),
         _ => {
             matches.sort();
@@ -142,7 +146,8 @@ fn is_spec_match(path: &std::path::Path, name: &str) -> bool {
             == Some(name)
 }
 
-/// Collect spec files including the archive directory.
+/// Collect spec files including the archive directory, in `sort_spec_paths`
+/// order (archived specs sort as the `archive` group and its subgroups).
 pub(crate) fn collect_spec_files_with_archived() -> Result<Vec<PathBuf>, String> {
     let mut files = collect_spec_files()?;
 
@@ -156,16 +161,24 @@ pub(crate) fn collect_spec_files_with_archived() -> Result<Vec<PathBuf>, String>
                 if let Ok(sub_entries) = fs::read_dir(&path) {
                     for sub_entry in sub_entries.flatten() {
                         let sub_path = sub_entry.path();
-                        if sub_path.extension().is_some_and(|ext| ext == "md") {
+                        if is_spec_file(&sub_path) {
                             files.push(sub_path);
                         }
                     }
                 }
-            } else if path.extension().is_some_and(|ext| ext == "md") {
+            } else if is_spec_file(&path) {
                 files.push(path);
             }
         }
     }
 
+    sort_spec_paths(&mut files, &specs_dir());
     Ok(files)
 }
+
+fn no_archived_spec(name: &str) -> String {
+    with_hint(
+        format!("No archived spec found matching '{name}'"),
+        "run `tinyspec list --include-archived` to see archived specs",
+    )
+}
@@ -0,0 +1,180 @@
+use std::io::{self, IsTerminal, Write};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{
+    self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
+    enable_raw_mode,
+};
+use crossterm::{execute, queue};
+
+use super::summary::{TaskNode, flatten_tasks};
+
+/// One row of the checklist prompt.
+pub(crate) struct Item {
+    /// Task ID the row toggles.
+    pub id: String,
+    pub label: String,
+    /// Nesting level, used for indentation (0 = top-level task).
+    pub depth: usize,
+    pub checked: bool,
+}
+
+/// Show a full-screen checklist of `items` and let the user toggle them.
+/// Returns the final checked state of every item, or `None` if cancelled.
+pub(crate) fn run(title: &str, items: &[Item]) -> Result<Option<Vec<bool>>, String> {
+    if !io::stdout().is_terminal() {
+        return Err("--interactive requires a terminal".into());
+    }
+
+    let mut stdout = io::stdout();
+    enable_raw_mode().map_err(|e| format!("Failed to enable raw mode: {e}"))?;
+    let _restore = RestoreTerminal;
+    execute!(stdout, EnterAlternateScreen, Hide).map_err(|e| e.to_string())?;
+
+    event_loop(&mut stdout, title, items)
+}
+
+/// Leaves the alternate screen and raw mode when dropped, so the shell is
+/// usable again even if the checklist errors or panics.
+struct RestoreTerminal;
+
+impl Drop for RestoreTerminal {
+    fn drop(&mut self) {
+        execute!(io::stdout(), Show, LeaveAlternateScreen).ok();
+        disable_raw_mode().ok();
+    }
+}
+
+/// One row per task and each of its subtasks at any depth, in order.
+pub(crate) fn items_for<'a>(tasks: impl IntoIterator<Item = &'a TaskNode>) -> Vec<Item> {
+    tasks
+        .into_iter()
+        .flat_map(|t| flatten_tasks(std::slice::from_ref(t)))
+        .map(|(task, depth)| Item {
+            id: task.id.clone(),
+            label: format!("{}: {}", task.id, task.description),
+            depth,
+            checked: task.checked,
+        })
+        .collect()
+}
+
+/// The `(task_id, checked)` pairs whose final state in `selected` (as returned
+/// by `run`) differs from the item's original state.
+pub(crate) fn changes(items: &[Item], selected: &[bool]) -> Vec<(String, bool)> {
+    items
+        .iter()
+        .zip(selected)
+        .filter(|(item, now)| item.checked != **now)
+        .map(|(item, now)| (item.id.clone(), *now))
+        .collect()
+}
+
+fn event_loop(
+    stdout: &mut io::Stdout,
+    title: &str,
+    items: &[Item],
+) -> Result<Option<Vec<bool>>, String> {
+    let mut state: Vec<bool> = items.iter().map(|item| item.checked).collect();
+    let mut selected = 0usize;
+    let mut offset = 0usize;
+
+    loop {
+        let (_, height) = terminal::size().map_err(|e| e.to_string())?;
+        let visible = (height as usize).saturating_sub(2).max(1);
+
+        // Keep the selection on screen
+        if selected < offset {
+            offset = selected;
+        } else if selected >= offset + visible {
+            offset = selected + 1 - visible;
+        }
+
+        draw(stdout, title, items, &state, selected, offset, visible).map_err(|e| e.to_string())?;
+
+        if let Event::Key(key) = event::read().map_err(|e| e.to_string())?
+            && key.kind == KeyEventKind::Press
+        {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => selected = selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    selected = (selected + 1).min(items.len() - 1);
+                }
+                KeyCode::Char(' ') => state[selected] = !state[selected],
+                KeyCode::Enter => return Ok(Some(state)),
+                KeyCode::Esc | KeyCode::Char('q') => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn draw(
+    stdout: &mut io::Stdout,
+    title: &str,
+    items: &[Item],
+    state: &[bool],
+    selected: usize,
+    offset: usize,
+    visible: usize,
+) -> io::Result<()> {
+    queue!(
+        stdout,
+        Clear(ClearType::All),
+        MoveTo(0, 0),
+        SetAttribute(Attribute::Bold),
+        Print(title),
+        SetAttribute(Attribute::Reset)
+    )?;
+
+    for (row, index) in (offset..items.len()).take(visible).enumerate() {
+        let item = &items[index];
+        let pointer = if index == selected { ">" } else { " " };
+        let mark = if state[index] { "x" } else { " " };
+        let changed = if state[index] != item.checked {
+            " *"
+        } else {
+            ""
+        };
+        let indent = "  ".repeat(item.depth);
+        queue!(
+            stdout,
+            MoveTo(0, row as u16 + 2),
+            Print(format!(
+                "{pointer} {indent}[{mark}] {}{changed}",
+                item.label
+            ))
+        )?;
+    }
+
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::summary::parse_tasks_from_content;
+
+    #[test]
+    fn changes_map_rows_back_to_task_ids() {
+        let tasks = parse_tasks_from_content(
+            "# Implementation Plan\n\n- [ ] A: One\n    - [x] A.1: Sub\n    - [ ] A.2: Sub\n- [ ] B: Two\n",
+        );
+        let items = items_for(&tasks);
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, ["A", "A.1", "A.2", "B"]);
+        assert_eq!(
+            items.iter().map(|item| item.depth).collect::<Vec<_>>(),
+            [0, 1, 1, 0]
+        );
+
+        // Untouched rows produce nothing; toggled ones report their new state
+        assert!(changes(&items, &[false, true, false, false]).is_empty());
+        assert_eq!(
+            changes(&items, &[false, false, true, false]),
+            [("A.1".to_string(), false), ("A.2".to_string(), true)]
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::OnceLock;
+
+/// When tinyspec colours its output, from `--color` (and `NO_COLOR`).
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColorMode {
+    /// Colour terminals only
+    Auto,
+    Always,
+    Never,
+}
+
+static COLOR_MODE: OnceLock<ColorMode> = OnceLock::new();
+
+/// Resolve `--color auto|always|never` once at startup. Without the flag, or
+/// with `auto`, a non-empty `NO_COLOR` means `never`; an explicit `always` or
+/// `never` wins over it.
+pub fn set_color_mode(value: Option<&str>) -> Result<(), String> {
+    let mode = match value {
+        None | Some("auto") => mode_from_env(),
+        Some("always") => ColorMode::Always,
+        Some("never") => ColorMode::Never,
+        Some(other) => {
+            return Err(format!(
+                "Invalid --color value '{other}'. Use: auto, always, never"
+            ));
+        }
+    };
+    // crossterm checks NO_COLOR on its own; make it follow the resolved mode instead
+    crossterm::style::force_color_output(mode != ColorMode::Never);
+    COLOR_MODE.set(mode).ok();
+    Ok(())
+}
+
+fn mode_from_env() -> ColorMode {
+    if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        ColorMode::Never
+    } else {
+        ColorMode::Auto
+    }
+}
+
+/// Whether to colour output going to a stream; `terminal` is whether that
+/// stream is a terminal, which only matters in `auto` mode.
+pub(crate) fn color_enabled(terminal: bool) -> bool {
+    match COLOR_MODE.get().copied().unwrap_or_else(mode_from_env) {
+        ColorMode::Auto => terminal,
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+    }
+}
@@ -1,34 +1,128 @@
 use std::fs;
-use std::io::{self, BufRead, Write};
+use std::io::{self, BufRead, IsTerminal, Write};
 use std::process::Command;
 
 use serde::Serialize;
 
 use chrono::Local;
 
-use super::config::{config_path, load_config};
+use super::config::{config_path, configured_sections, configured_specs_dir, load_config};
+use super::errors::{report_warning, with_hint};
 use super::format::format_file;
 use super::hooks::{Event, HookContext, run_hooks};
-use super::summary::{SpecStatus, load_spec_summary};
+use super::migrate::LATEST_VERSION;
+use super::summary::{
+    CodeFence, SpecStatus, TaskNode, flatten_tasks, load_spec_summary, parse_task_line,
+    split_done_annotation,
+};
+use super::tasks::validate_task_id;
 use super::templates::{collect_templates, find_template, substitute_variables};
 use super::{
-    SPECS_DIR, TIMESTAMP_PREFIX_LEN, collect_spec_files, discover_git_root, extract_spec_name,
-    find_spec, parse_front_matter, parse_spec_input, specs_dir,
+    ORDER_FILE, SPECS_DIR, SpecOrder, TIMESTAMP_PREFIX_LEN, bare_spec_name, collect_spec_files,
+    create_dir_within, discover_git_root, display_path, extract_spec_name, find_spec,
+    front_matter_close, is_spec_file, no_specs_message, parse_front_matter, parse_spec_input,
+    read_spec, read_spec_or_warn, remove_empty_group_dir, set_title, specs_dir,
+    validate_group_name, validate_kebab_case,
 };
 
-pub fn new_spec(input: &str, template_name: Option<&str>) -> Result<(), String> {
-    new_spec_impl(input, template_name, false)
+/// Create a spec from the template (or scaffold). With `body`, the standard
+/// front matter is generated and `body` follows it instead of the scaffold.
+pub fn new_spec(
+    input: &str,
+    template_name: Option<&str>,
+    applications: &[String],
+    body: Option<&str>,
+    format: bool,
+) -> Result<(), String> {
+    new_spec_impl(input, template_name, applications, body, false, format)
+}
+
+pub fn new_spec_with_hooks(
+    input: &str,
+    template_name: Option<&str>,
+    applications: &[String],
+    body: Option<&str>,
+    format: bool,
+) -> Result<(), String> {
+    new_spec_impl(input, template_name, applications, body, true, format)
+}
+
+/// Apply `new --group <g>` to a spec name, yielding the equivalent `g/name` input.
+/// Errors if the name already carries its own `group/` prefix.
+pub fn with_group(input: &str, group: Option<&str>) -> Result<String, String> {
+    match group {
+        None => Ok(input.to_string()),
+        Some(g) if input.contains('/') => Err(format!(
+            "'{input}' already includes a group; drop --group {g} or use a plain name"
+        )),
+        Some(g) => Ok(format!("{g}/{input}")),
+    }
 }
 
-pub fn new_spec_with_hooks(input: &str, template_name: Option<&str>) -> Result<(), String> {
-    new_spec_impl(input, template_name, true)
+/// Create one spec per non-empty line of `file`, continuing past failures.
+/// Lines starting with `#` are treated as comments.
+pub fn new_specs_from_file(
+    file: &str,
+    group: Option<&str>,
+    template_name: Option<&str>,
+    applications: &[String],
+    fire_hooks: bool,
+    format: bool,
+) -> Result<(), String> {
+    let content = fs::read_to_string(file).map_err(|e| format!("Failed to read '{file}': {e}"))?;
+
+    let mut created = 0;
+    let mut failed = 0;
+    for (i, line) in content.lines().enumerate() {
+        let name = line.trim();
+        if name.is_empty() || name.starts_with('#') {
+            continue;
+        }
+        match with_group(name, group).and_then(|input| {
+            new_spec_impl(
+                &input,
+                template_name,
+                applications,
+                None,
+                fire_hooks,
+                format,
+            )
+        }) {
+            Ok(()) => created += 1,
+            Err(e) => {
+                eprintln!("line {}: {name}: {e}", i + 1);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("Created {created} spec(s), {failed} failed.");
+    if failed > 0 {
+        Err(format!("Failed to create {failed} spec(s) from '{file}'"))
+    } else {
+        Ok(())
+    }
 }
 
-fn new_spec_impl(input: &str, template_name: Option<&str>, fire_hooks: bool) -> Result<(), String> {
+fn new_spec_impl(
+    input: &str,
+    template_name: Option<&str>,
+    applications: &[String],
+    body: Option<&str>,
+    fire_hooks: bool,
+    format: bool,
+) -> Result<(), String> {
+    let input = match input.strip_suffix(".md") {
+        Some(stripped) => {
+            eprintln!("Note: dropping '.md' from '{input}'; the extension is added automatically.");
+            stripped
+        }
+        None => input,
+    };
     let (group, name) = parse_spec_input(input)?;
 
     // Enforce global uniqueness — check if name already exists anywhere
-    let existing = collect_spec_files().unwrap_or_default();
+    let existing = collect_spec_files()?;
     for path in &existing {
         if let Some(filename) = path.file_name().and_then(|f| f.to_str())
             && extract_spec_name(filename) == Some(name)
@@ -40,8 +134,22 @@ fn new_spec_impl(input: &str, template_name: Option<&str>, fire_hooks: bool) ->
         }
     }
 
-    // If .specs/ doesn't exist yet, create it at the git repo root (if in a git repo)
-    let base = if specs_dir().exists() {
+    // Templates aren't specs, but a shared name is easy to mix up
+    if let Some(template) = collect_templates()
+        .unwrap_or_default()
+        .into_iter()
+        .find(|t| t.name == name)
+    {
+        report_warning(&format!(
+            "'{name}' is also the name of a {} template ({}); `--template {name}` refers to the template, not this spec.",
+            template.source,
+            template.path.display()
+        ));
+    }
+
+    // If .specs/ doesn't exist yet, create it at the git repo root (if in a git repo),
+    // unless the project config points somewhere explicitly
+    let base = if specs_dir().exists() || configured_specs_dir().is_some() {
         specs_dir()
     } else {
         match discover_git_root() {
@@ -51,10 +159,9 @@ fn new_spec_impl(input: &str, template_name: Option<&str>, fire_hooks: bool) ->
     };
     let dir = match group {
         Some(g) => base.join(g),
-        None => base,
+        None => base.clone(),
     };
-    fs::create_dir_all(&dir)
-        .map_err(|e| format!("Failed to create {} directory: {e}", dir.display()))?;
+    create_dir_within(&dir, &base)?;
 
     // Find a unique timestamp prefix, incrementing by 1 minute on conflict
     let existing_prefixes: Vec<String> = existing
@@ -80,18 +187,7 @@ fn new_spec_impl(input: &str, template_name: Option<&str>, fire_hooks: bool) ->
     let filename = format!("{timestamp}-{name}.md");
     let path = dir.join(&filename);
 
-    // Title-case the kebab-case name
-    let title: String = name
-        .split('-')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(c) => c.to_uppercase().to_string() + chars.as_str(),
-            }
-        })
-        .collect::<Vec<_>>()
-        .join(" ");
+    let title = title_from_name(name);
 
     let date = Local::now().format("%Y-%m-%d").to_string();
 
@@ -116,19 +212,15 @@ fn new_spec_impl(input: &str, template_name: Option<&str>, fire_hooks: bool) ->
                 .map_err(|e| format!("Failed to read template '{}': {e}", t.name))?;
             substitute_variables(&raw, &vars)
         }
-        None => {
-            format!(
-                "\
----
-tinySpec: v0
-title: {title}
-# priority: high        # high | medium | low (default: medium)
-# tags: []              # arbitrary string labels for filtering
-# depends_on: []        # spec names that must complete first
-applications:
-    -
----
-
+        None => match (body, configured_sections()) {
+            (Some(body), _) => format!(
+                "{}\n{}",
+                scaffold_front_matter(&title),
+                body.trim_start_matches(['\n', '\r'])
+            ),
+            (None, Some(sections)) => scaffold_sections(&title, &sections),
+            (None, None) => format!(
+                "{}
 # Background
 
 
@@ -150,14 +242,22 @@ flowchart LR
 
 - [ ] T.1:
 - [ ] T.2:
-"
-            )
-        }
+",
+                scaffold_front_matter(&title)
+            ),
+        },
+    };
+    let content = if applications.is_empty() {
+        content
+    } else {
+        set_applications(&content, applications)?
     };
 
     fs::write(&path, &content).map_err(|e| format!("Failed to write spec file: {e}"))?;
-    format_file(&path)?;
-    println!("Created spec: {filename}");
+    if format {
+        format_file(&path)?;
+    }
+    println!("Created spec: {}", display_path(&path));
 
     if fire_hooks {
         let fm = parse_front_matter(&content);
@@ -178,30 +278,172 @@ flowchart LR
     Ok(())
 }
 
-pub fn list(json: bool, include_archived: bool, tag: Option<&str>) -> Result<(), String> {
+/// Replace the front matter's `applications` list (adding the key if missing)
+/// with `applications`, whatever the scaffold or template put there.
+fn set_applications(content: &str, applications: &[String]) -> Result<String, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let close = front_matter_close(&lines)
+        .ok_or("Cannot set applications: the template has no front matter")?;
+
+    let start = lines[1..close]
+        .iter()
+        .position(|l| l.starts_with("applications:"))
+        .map(|i| i + 1);
+    let (start, end) = match start {
+        Some(start) => {
+            // The key's block: indented lines and `-` items that follow it
+            let len = lines[start + 1..close]
+                .iter()
+                .take_while(|l| l.starts_with([' ', '\t', '-']))
+                .count();
+            (start, start + 1 + len)
+        }
+        None => (close, close),
+    };
+
+    let items: Vec<String> = applications.iter().map(|a| format!("    - {a}")).collect();
+    let block = std::iter::once("applications:").chain(items.iter().map(String::as_str));
+    lines.splice(start..end, block);
+
+    let mut output = lines.join("\n");
+    if content.ends_with('\n') {
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// Title-case a kebab-case spec name: `my-feature` → `My Feature`.
+fn title_from_name(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(c) => c.to_uppercase().to_string() + chars.as_str(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The front matter block every scaffold starts with.
+fn scaffold_front_matter(title: &str) -> String {
+    format!(
+        "\
+---
+tinySpec: {LATEST_VERSION}
+title: {title}
+# priority: high        # high | medium | low (default: medium)
+# tags: []              # arbitrary string labels for filtering
+# depends_on: []        # spec names that must complete first
+applications:
+    -
+---
+"
+    )
+}
+
+/// Build a scaffold with the configured `sections` headings, in order. The plan
+/// sections keep their placeholder tasks so `check` works out of the box.
+fn scaffold_sections(title: &str, sections: &[String]) -> String {
+    let mut content = scaffold_front_matter(title);
+    for section in sections {
+        content.push_str(&format!("\n# {section}\n\n"));
+        match section.as_str() {
+            "Implementation Plan" => content.push_str("- [ ] A:\n"),
+            "Test Plan" => content.push_str("- [ ] T.1:\n- [ ] T.2:\n"),
+            _ => {}
+        }
+    }
+    content
+}
+
+/// What `list` shows and in which order.
+#[derive(Clone, Copy, Default)]
+pub struct ListOptions<'a> {
+    pub json: bool,
+    pub table: bool,
+    pub include_archived: bool,
+    /// Only specs carrying this tag
+    pub tag: Option<&'a str>,
+    pub show_status: bool,
+    /// `date` (the default), `name`, `title`, `status`, or `progress`
+    pub sort: Option<&'a str>,
+    pub reverse: bool,
+    pub by_status: bool,
+    /// Only specs changed in git since this revision
+    pub changed_since: Option<&'a str>,
+}
+
+pub fn list(options: &ListOptions) -> Result<(), String> {
+    let ListOptions {
+        json,
+        table,
+        include_archived,
+        tag,
+        show_status,
+        sort,
+        reverse,
+        by_status,
+        changed_since,
+    } = *options;
     use super::archive::collect_spec_files_with_archived;
-    use super::summary::load_spec_summary;
+    use super::summary::{load_spec_counts, read_spec_summary};
 
     let mut files = if include_archived {
         collect_spec_files_with_archived()?
     } else {
         collect_spec_files()?
     };
+    if let Some(base) = changed_since {
+        match git_changed_files(base)? {
+            Some(changed) => files.retain(|path| {
+                path.canonicalize()
+                    .is_ok_and(|path| changed.contains(&path))
+            }),
+            None => report_warning("not in a git repository; listing all specs"),
+        }
+        if files.is_empty() {
+            println!("{}", if json { "[]" } else { "No changed specs." });
+            return Ok(());
+        }
+    }
 
     if files.is_empty() {
         if json {
             println!("[]");
         } else {
-            println!("No specs found.");
+            println!("{}", no_specs_message());
         }
         return Ok(());
     }
 
+    if by_status {
+        return list_by_status(json, table, tag, show_status);
+    }
+
     // Sort by filename (natural date ordering due to timestamp prefix)
     files.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    if let Some(key) = sort {
+        sort_spec_files(&mut files, key)?;
+    }
+    if reverse {
+        files.reverse();
+    }
+    // `.order` pins come first either way; the stable sort keeps the key order
+    let order = SpecOrder::load();
+    files.sort_by_key(|path| order.rank_path(path));
 
     if json {
-        let mut summaries: Vec<_> = files.iter().filter_map(|p| load_spec_summary(p)).collect();
+        let mut summaries: Vec<_> = files
+            .iter()
+            .filter_map(|p| {
+                read_spec_summary(p)
+                    .map_err(|e| report_warning(&format!("{e}; skipping it")))
+                    .ok()
+                    .flatten()
+            })
+            .collect();
         if let Some(tag_filter) = tag {
             summaries.retain(|s| s.tags.iter().any(|t| t == tag_filter));
         }
@@ -218,9 +460,12 @@ pub fn list(json: bool, include_archived: bool, tag: Option<&str>) -> Result<(),
         std::collections::BTreeMap::new();
 
     for path in &files {
+        let Some(content) = read_spec_or_warn(path) else {
+            continue;
+        };
+
         // Apply tag filter
         if let Some(tag_filter) = tag {
-            let content = fs::read_to_string(path).unwrap_or_default();
             let fm = parse_front_matter(&content);
             let has_tag = fm
                 .map(|f| f.tags.iter().any(|t| t == tag_filter))
@@ -250,27 +495,19 @@ pub fn list(json: bool, include_archived: bool, tag: Option<&str>) -> Result<(),
         .filter(|s| !s.is_empty());
 
     let print_spec = |path: &std::path::Path| {
-        let filename = path
-            .file_name()
-            .unwrap_or_default()
-            .to_string_lossy()
-            .to_string();
-        let spec_name = extract_spec_name(&filename).unwrap_or(&filename);
-        let content = fs::read_to_string(path).unwrap_or_default();
-        let fm = parse_front_matter(&content);
-        let title = fm
-            .as_ref()
-            .and_then(|f| f.title.clone())
-            .unwrap_or_else(|| "(no title)".into());
-        let priority = fm.as_ref().and_then(|f| f.priority).unwrap_or_default();
-        let marker = if focused_spec.as_deref() == Some(spec_name) {
-            "→ "
-        } else {
-            "  "
-        };
-        println!("{marker}[{}] {spec_name:30} {title}", priority.label());
+        print_list_line(path, focused_spec.as_deref(), show_status);
     };
 
+    // Aligned table on a terminal (or when asked for explicitly)
+    if table || (show_status && io::stdout().is_terminal()) {
+        let ordered = ungrouped
+            .iter()
+            .chain(groups.values().flatten())
+            .filter_map(|p| load_spec_summary(p));
+        print_list_table(ordered, focused_spec.as_deref());
+        return Ok(());
+    }
+
     // Print ungrouped specs first
     for path in &ungrouped {
         print_spec(path);
@@ -281,7 +518,16 @@ pub fn list(json: bool, include_archived: bool, tag: Option<&str>) -> Result<(),
         if !ungrouped.is_empty() || groups.len() > 1 {
             println!();
         }
-        println!("{group_name}/");
+        // Aggregate progress shares the status pass, so `--no-status` skips it too
+        let summaries: Vec<_> = if show_status {
+            paths
+                .iter()
+                .filter_map(|p| load_spec_counts(p).ok().flatten())
+                .collect()
+        } else {
+            Vec::new()
+        };
+        print_group_header(group_name, show_status.then_some(&summaries[..]));
         for path in paths {
             print_spec(path);
         }
@@ -290,11 +536,97 @@ pub fn list(json: bool, include_archived: bool, tag: Option<&str>) -> Result<(),
     Ok(())
 }
 
-pub fn view(name: &str, json: bool) -> Result<(), String> {
-    use super::summary::load_spec_summary;
+/// Render specs as a table whose column widths fit the data.
+fn print_list_table(
+    summaries: impl Iterator<Item = super::summary::SpecSummary>,
+    focused: Option<&str>,
+) {
+    let header = ["SPEC", "TITLE", "PRIORITY", "STATUS", "PROGRESS"].map(String::from);
+    let mut rows = vec![(false, header)];
+    for s in summaries {
+        let name = match &s.group {
+            Some(g) => format!("{g}/{}", s.name),
+            None => s.name.clone(),
+        };
+        let progress = format!(
+            "{}/{}",
+            s.checked + s.checked_tests,
+            s.total + s.total_tests
+        );
+        let status = format!("{} {}", s.status_glyph(), s.status.label());
+        rows.push((
+            focused == Some(s.name.as_str()),
+            [
+                name,
+                s.title,
+                s.priority.label().to_string(),
+                status,
+                progress,
+            ],
+        ));
+    }
+
+    let mut widths = [0; 5];
+    for (_, cells) in &rows {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for (is_focused, cells) in &rows {
+        let marker = if *is_focused { "→ " } else { "  " };
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        println!("{marker}{}", line.join("  ").trim_end());
+    }
+}
+
+/// How `view` renders a spec.
+#[derive(Clone, Copy, Default)]
+pub struct ViewOptions {
+    pub json: bool,
+    /// Only the Implementation Plan section (its task tree with `json`)
+    pub plan: bool,
+    /// Only check that the spec's applications resolve
+    pub resolve_check: bool,
+    /// Warn about unmapped applications instead of failing; `None` uses the config
+    pub lenient: Option<bool>,
+    pub front_matter: bool,
+    /// Render application names as links to their repositories
+    pub links: bool,
+}
+
+pub fn view(name: &str, options: &ViewOptions) -> Result<(), String> {
+    let ViewOptions {
+        json,
+        plan,
+        resolve_check,
+        lenient,
+        front_matter,
+        links,
+    } = *options;
+    use super::summary::{load_spec_summary, parse_tasks_from_content};
 
     let path = find_spec(name)?;
-    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read spec: {e}"))?;
+    let content = read_spec(&path)?;
+
+    if resolve_check {
+        let apps = spec_applications(&content);
+        if !apps.is_empty() {
+            resolve_applications(&apps, false)?;
+        }
+        return Ok(());
+    }
+
+    if plan && json {
+        let out = serde_json::to_string_pretty(&parse_tasks_from_content(&content))
+            .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+        println!("{out}");
+        return Ok(());
+    }
 
     if json {
         #[derive(Serialize)]
@@ -303,6 +635,7 @@ pub fn view(name: &str, json: bool) -> Result<(), String> {
             title: Option<String>,
             applications: Vec<String>,
             body: String,
+            sections: Vec<super::summary::Section>,
             tasks: Vec<super::summary::TaskNode>,
         }
 
@@ -320,10 +653,11 @@ pub fn view(name: &str, json: bool) -> Result<(), String> {
         let tasks = summary.map(|s| s.tasks).unwrap_or_default();
 
         let view_json = ViewJson {
-            name: name.to_string(),
+            name: bare_spec_name(name).to_string(),
             title,
             applications,
             body: content.clone(),
+            sections: super::summary::SpecDocument::parse(&content).sections,
             tasks,
         };
 
@@ -333,139 +667,1102 @@ pub fn view(name: &str, json: bool) -> Result<(), String> {
         return Ok(());
     }
 
-    // Parse frontmatter to check for application references
-    let apps: Vec<String> = parse_front_matter(&content)
-        .map(|fm| {
-            fm.applications
-                .into_iter()
-                .filter(|a| !a.is_empty())
-                .collect()
-        })
-        .unwrap_or_default();
+    print!(
+        "{}",
+        render_view_text(name, &content, plan, lenient, front_matter, links)?
+    );
+    Ok(())
+}
 
-    if apps.is_empty() {
-        print!("{content}");
+/// Files changed in git relative to `base`: commits since the merge base with
+/// `base`, uncommitted changes, and untracked files, as canonical paths. In a
+/// repository without commits every tracked and untracked file counts as
+/// changed. `None` outside a git repository.
+fn git_changed_files(
+    base: &str,
+) -> Result<Option<std::collections::HashSet<std::path::PathBuf>>, String> {
+    let Some(root) = discover_git_root() else {
+        return Ok(None);
+    };
+    let git = |args: &[&str]| -> Result<String, String> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&root)
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to run git: {e}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let mut listings = vec![git(&["ls-files", "--others", "--exclude-standard"])?];
+    if git(&["rev-parse", "--verify", "--quiet", "HEAD"]).is_err() {
+        // No commits yet: there is nothing to diff against, so everything is new
+        listings.push(git(&["ls-files"])?);
+    } else {
+        listings.push(git(&["diff", "--name-only", "HEAD"])?);
+        if base != "HEAD" {
+            listings.push(git(&["diff", "--name-only", &format!("{base}...HEAD")])?);
+        }
+    }
+    let changed = listings
+        .iter()
+        .flat_map(|listing| listing.lines())
+        .filter_map(|line| root.join(line).canonicalize().ok())
+        .collect();
+    Ok(Some(changed))
+}
+
+/// `list --by-status`: the dashboard's order (incomplete specs first, then by
+/// priority, group, and age) under the dashboard's section and group headers.
+fn list_by_status(
+    json: bool,
+    table: bool,
+    tag: Option<&str>,
+    show_status: bool,
+) -> Result<(), String> {
+    use super::summary::{load_all_counts, load_all_summaries};
+
+    let mut summaries = if json {
+        load_all_summaries()?
+    } else {
+        load_all_counts()?
+    };
+    if let Some(tag_filter) = tag {
+        summaries.retain(|s| s.tags.iter().any(|t| t == tag_filter));
+    }
+
+    if json {
+        let out = serde_json::to_string_pretty(&summaries)
+            .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+        println!("{out}");
         return Ok(());
     }
 
-    // Resolve application names to folder paths via config
-    let config_path = config_path()?;
-    if !config_path.exists() {
-        return Err(format!(
-            "Spec references applications {:?} but no config file found.\n\
-             Create one with: tinyspec config set <repo-name> <path>",
-            apps
-        ));
+    let focused_spec = fs::read_to_string(focus_file_path())
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+
+    if table || (show_status && io::stdout().is_terminal()) {
+        print_list_table(summaries.into_iter(), focused_spec.as_deref());
+        return Ok(());
     }
 
-    let config = load_config()?;
-    let mut missing: Vec<&str> = Vec::new();
-    let mut replacements: Vec<(&str, &str)> = Vec::new();
+    let mut section: Option<bool> = None;
+    let mut group: Option<&str> = None;
+    for (i, spec) in summaries.iter().enumerate() {
+        let done = spec.status == SpecStatus::Completed;
+        if section != Some(done) {
+            if section.is_some() {
+                println!();
+            }
+            println!("{}", if done { "Completed" } else { "In Progress" });
+            section = Some(done);
+            group = None;
+        }
+        if spec.group.as_deref() != group {
+            if let Some(g) = &spec.group {
+                // Separate the group from specs already listed in this section
+                let section_started =
+                    i > 0 && (summaries[i - 1].status == SpecStatus::Completed) == done;
+                if section_started {
+                    println!();
+                }
+                let members: Vec<_> = summaries[i..]
+                    .iter()
+                    .take_while(|s| {
+                        s.group == spec.group && (s.status == SpecStatus::Completed) == done
+                    })
+                    .cloned()
+                    .collect();
+                print_group_header(g, show_status.then_some(&members[..]));
+            }
+            group = spec.group.as_deref();
+        }
+        print_list_line(&spec.path, focused_spec.as_deref(), show_status);
+    }
+    Ok(())
+}
 
-    for app in &apps {
-        match config.repositories.get(app.as_str()) {
-            Some(folder) => replacements.push((app.as_str(), folder.as_str())),
-            None => missing.push(app.as_str()),
+/// One plain `list` line: focus marker, priority, and (unless `show_status`
+/// is off) status glyph and percent complete, then name and title.
+fn print_list_line(path: &std::path::Path, focused: Option<&str>, show_status: bool) {
+    use super::summary::load_spec_counts;
+
+    let filename = path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    let spec_name = extract_spec_name(&filename).unwrap_or(&filename);
+    let content = fs::read_to_string(path).unwrap_or_default();
+    let fm = parse_front_matter(&content);
+    let title = fm
+        .as_ref()
+        .and_then(|f| f.title.clone())
+        .unwrap_or_else(|| "(no title)".into());
+    let priority = fm.as_ref().and_then(|f| f.priority).unwrap_or_default();
+    let marker = if focused == Some(spec_name) {
+        "→ "
+    } else {
+        "  "
+    };
+    // Progress costs a pass over the task lines, so `--no-status` skips it
+    let progress = show_status
+        .then(|| load_spec_counts(path).ok().flatten())
+        .flatten();
+    match progress {
+        Some(summary) => println!(
+            "{marker}[{}] {} {spec_name:30} {:>3}%  {title}",
+            priority.label(),
+            summary.status_glyph(),
+            summary.percent_complete()
+        ),
+        None => println!("{marker}[{}] {spec_name:30} {title}", priority.label()),
+    }
+}
+
+/// A `group/` header in plain `list` output, with the group's aggregate
+/// progress when `summaries` are given.
+fn print_group_header(name: &str, summaries: Option<&[super::summary::SpecSummary]>) {
+    use super::summary::{group_progress, percent};
+
+    match summaries {
+        Some(summaries) => {
+            let (checked, total) = group_progress(summaries);
+            let pct = percent(checked, total);
+            println!("{name}/  ({checked}/{total}, {pct}%)");
         }
+        None => println!("{name}/"),
     }
+}
 
-    if !missing.is_empty() {
+/// Re-sort date-ordered spec files by `key` for `list --sort`. The sort is
+/// stable, so specs that tie stay in date order.
+fn sort_spec_files(files: &mut [std::path::PathBuf], key: &str) -> Result<(), String> {
+    use super::summary::load_spec_counts;
+
+    if !matches!(key, "date" | "name" | "title" | "status" | "progress") {
         return Err(format!(
-            "Spec references applications not found in config: {}\n\
-             Add them with: tinyspec config set <repo-name> <path>",
-            missing.join(", ")
+            "Invalid sort key '{key}'. Use: date, name, title, status, progress"
         ));
     }
-
-    // Perform find-and-replace of application names with folder paths
-    let mut output = content;
-    for (app_name, folder_path) in replacements {
-        output = output.replace(app_name, folder_path);
+    if key == "date" {
+        return Ok(());
     }
 
-    print!("{output}");
+    files.sort_by_cached_key(|path| {
+        let Some(summary) = load_spec_counts(path).ok().flatten() else {
+            return (None, 0, String::new());
+        };
+        match key {
+            "name" => (None, 0, summary.name),
+            "title" => (None, 0, summary.title.to_lowercase()),
+            "status" => (Some(summary.status), 0, String::new()),
+            _ => (None, summary.percent_complete(), String::new()),
+        }
+    });
     Ok(())
 }
 
-pub fn edit(name: &str) -> Result<(), String> {
-    let path = find_spec(name)?;
-    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".into());
+/// Print every spec in `list` order (ungrouped first, then each group), each
+/// under a `===== name =====` header. Unmapped applications only warn unless
+/// `--strict` is given.
+pub fn view_all(options: &ViewOptions) -> Result<(), String> {
+    let ViewOptions {
+        plan,
+        lenient,
+        front_matter,
+        links,
+        ..
+    } = *options;
+    let mut files = collect_spec_files()?;
+    if files.is_empty() {
+        println!("{}", no_specs_message());
+        return Ok(());
+    }
+    files.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    let order = SpecOrder::load();
+    files.sort_by_key(|path| order.rank_path(path));
 
-    Command::new(&editor)
-        .arg(&path)
-        .status()
-        .map_err(|e| format!("Failed to open editor '{editor}': {e}"))?;
+    let specs_root = specs_dir();
+    let group_of = |path: &std::path::Path| {
+        path.parent()
+            .filter(|parent| *parent != specs_root)
+            .and_then(|parent| parent.file_name())
+            .map(|g| g.to_string_lossy().to_string())
+    };
+    // Stable sort keeps filename order within ungrouped specs and each group
+    files.sort_by_key(|path| group_of(path));
+
+    let lenient = Some(lenient.unwrap_or(true));
+    let mut first = true;
+    for path in &files {
+        let filename = path.file_name().unwrap_or_default().to_string_lossy();
+        let Some(name) = extract_spec_name(&filename) else {
+            continue;
+        };
+        let Some(content) = read_spec_or_warn(path) else {
+            continue;
+        };
+        let text = match render_view_text(name, &content, plan, lenient, front_matter, links) {
+            Ok(text) => text,
+            Err(e) if plan => {
+                report_warning(&format!("skipping {name}: {e}"));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
 
+        if !first {
+            println!();
+        }
+        first = false;
+        let display = match group_of(path) {
+            Some(group) => format!("{group}/{name}"),
+            None => name.to_string(),
+        };
+        println!("===== {display} =====");
+        println!();
+        print!("{text}");
+    }
     Ok(())
 }
 
-pub fn delete(name: &str) -> Result<(), String> {
-    let path = find_spec(name)?;
-    let filename = path.file_name().unwrap().to_string_lossy();
-
-    eprint!("Delete {filename}? [y/N] ");
-    io::stderr().flush().ok();
+/// The text `view` prints for a spec: the whole file (or just its plan), with
+/// application names replaced by their configured folder paths.
+fn render_view_text(
+    name: &str,
+    content: &str,
+    plan: bool,
+    lenient: Option<bool>,
+    front_matter: bool,
+    links: bool,
+) -> Result<String, String> {
+    use super::format::split_front_matter;
+    use super::summary::extract_section;
 
-    let mut input = String::new();
-    io::stdin()
-        .lock()
-        .read_line(&mut input)
-        .map_err(|e| format!("Failed to read input: {e}"))?;
+    // Parse frontmatter to check for application references
+    let apps = spec_applications(content);
 
-    if input.trim().eq_ignore_ascii_case("y") {
-        fs::remove_file(&path).map_err(|e| format!("Failed to delete spec: {e}"))?;
-        println!("Deleted {filename}");
+    let content = if plan {
+        extract_section(content, "Implementation Plan")
+            .ok_or_else(|| format!("Spec '{name}' has no Implementation Plan section"))?
+    } else if front_matter {
+        content.to_string()
     } else {
-        println!("Cancelled.");
+        split_front_matter(content)
+            .1
+            .trim_start_matches('\n')
+            .to_string()
+    };
+
+    if apps.is_empty() {
+        return Ok(content);
     }
 
-    Ok(())
-}
+    // Explicit --lenient/--strict wins; otherwise fall back to the config default
+    let lenient = lenient.unwrap_or_else(|| load_config().is_ok_and(|c| c.lenient_applications));
+    let replacements = resolve_applications(&apps, lenient)?;
 
-pub fn check_task(name: &str, task_id: &str, check: bool) -> Result<(), String> {
-    check_task_impl(name, task_id, check, true)
+    if links {
+        let urls = load_config()?.urls;
+        return Ok(link_applications(&content, &replacements, &urls));
+    }
+    Ok(substitute_applications(&content, &replacements))
 }
 
-pub fn check_task_no_hooks(name: &str, task_id: &str, check: bool) -> Result<(), String> {
-    check_task_impl(name, task_id, check, false)
+/// Replace application names with their folder paths in a single left-to-right
+/// pass, so text produced by one substitution is never rewritten by another.
+/// At each position the longest matching name wins.
+fn substitute_applications(content: &str, replacements: &[(String, String)]) -> String {
+    substitute_applications_with(content, replacements, |_, folder_path, _| {
+        folder_path.to_string()
+    })
 }
 
-fn check_task_impl(name: &str, task_id: &str, check: bool, fire_hooks: bool) -> Result<(), String> {
-    let path = find_spec(name)?;
-    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read spec: {e}"))?;
-
-    // Capture status before change (for transition detection)
-    let status_before = load_spec_summary(&path).map(|s| s.status);
-
-    let target = format!("{task_id}:");
-    let mut found = false;
-    let mut lines: Vec<String> = content.lines().map(String::from).collect();
-
-    for line in &mut lines {
-        let trimmed = line.trim();
-        if check {
-            if let Some(after) = trimmed.strip_prefix("- [ ] ")
-                && after.starts_with(&target)
-            {
-                *line = line.replacen("- [ ] ", "- [x] ", 1);
-                found = true;
-                break;
-            }
-        } else if let Some(after) = trimmed.strip_prefix("- [x] ")
-            && after.starts_with(&target)
-        {
-            *line = line.replacen("- [x] ", "- [ ] ", 1);
-            found = true;
-            break;
+/// `view --render-apps-as-links`: standalone application names in the body
+/// become `[name](url-or-path)`. Front matter, fenced code, and names that are
+/// part of a longer word or path (e.g. `my-app/src`) get the plain path instead,
+/// since a link wouldn't render or would split the word.
+fn link_applications(
+    content: &str,
+    replacements: &[(String, String)],
+    urls: &std::collections::BTreeMap<String, String>,
+) -> String {
+    use super::format::split_front_matter;
+
+    let (front_matter, body) = split_front_matter(content);
+    let mut output = front_matter
+        .map(|fm| substitute_applications(fm, replacements))
+        .unwrap_or_default();
+    let mut fence = CodeFence::default();
+    for line in body.split_inclusive('\n') {
+        if fence.skip(line) {
+            output.push_str(&substitute_applications(line, replacements));
+            continue;
         }
+        output.push_str(&substitute_applications_with(
+            line,
+            replacements,
+            |app_name, folder_path, standalone| {
+                if !standalone {
+                    return folder_path.to_string();
+                }
+                let target = urls.get(app_name).map_or(folder_path, String::as_str);
+                if target.contains(char::is_whitespace) {
+                    format!("[{app_name}](<{target}>)")
+                } else {
+                    format!("[{app_name}]({target})")
+                }
+            },
+        ));
     }
+    output
+}
+
+/// The substitution pass behind `substitute_applications`, rendering each match
+/// with `render(name, folder_path, standalone)`. A match is standalone when the
+/// characters either side of it can't be part of a name or path.
+fn substitute_applications_with(
+    content: &str,
+    replacements: &[(String, String)],
+    render: impl Fn(&str, &str, bool) -> String,
+) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | '/');
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+    let mut prev: Option<char> = None;
+    while let Some(c) = rest.chars().next() {
+        let matched = replacements
+            .iter()
+            .filter(|(app_name, _)| !app_name.is_empty() && rest.starts_with(app_name.as_str()))
+            .max_by_key(|(app_name, _)| app_name.len());
+        match matched {
+            Some((app_name, folder_path)) => {
+                let after = &rest[app_name.len()..];
+                let standalone =
+                    !prev.is_some_and(is_word) && !after.chars().next().is_some_and(is_word);
+                output.push_str(&render(app_name, folder_path, standalone));
+                prev = app_name.chars().last();
+                rest = after;
+            }
+            None => {
+                output.push(c);
+                prev = Some(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    output
+}
+
+/// Non-empty application names listed in a spec's front matter.
+fn spec_applications(content: &str) -> Vec<String> {
+    parse_front_matter(content)
+        .map(|fm| {
+            fm.applications
+                .into_iter()
+                .filter(|a| !a.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Resolve application names to folder paths via config.
+/// Returns `(name, path)` pairs, or an error listing any unmapped applications.
+/// When `lenient`, unmapped applications are reported as a warning on stderr
+/// and only the resolvable ones are returned.
+fn resolve_applications(apps: &[String], lenient: bool) -> Result<Vec<(String, String)>, String> {
+    let config_path = config_path()?;
+    if !config_path.exists() {
+        let message = with_hint(
+            format!("Spec references applications {apps:?} but no config file found"),
+            "map each application with `tinyspec config set <repo-name> <path>`",
+        );
+        if lenient {
+            report_warning(&message);
+            return Ok(Vec::new());
+        }
+        return Err(message);
+    }
+
+    let config = load_config()?;
+    let mut missing: Vec<&str> = Vec::new();
+    let mut replacements: Vec<(String, String)> = Vec::new();
+
+    for app in apps {
+        match config.repositories.get(app.as_str()) {
+            Some(folder) => replacements.push((app.clone(), folder.clone())),
+            None => missing.push(app.as_str()),
+        }
+    }
+
+    if !missing.is_empty() {
+        let message = missing.iter().fold(
+            format!(
+                "Spec references applications not found in config: {}",
+                missing.join(", ")
+            ),
+            |message, app| with_hint(message, &format!("run `tinyspec config set {app} <path>`")),
+        );
+        if lenient {
+            report_warning(&message);
+        } else {
+            return Err(message);
+        }
+    }
+
+    Ok(replacements)
+}
+
+/// Open a spec in the editor. With `section`, jump to that heading when the
+/// editor is one whose line-jump syntax we know; others just open the file.
+pub fn edit(name: &str, section: Option<&str>) -> Result<(), String> {
+    use super::summary::SpecDocument;
+
+    let path = find_spec(name)?;
+    let line = match section {
+        Some(title) => {
+            let content = read_spec(&path)?;
+            let doc = SpecDocument::parse(&content);
+            let section = doc
+                .section(title)
+                .ok_or_else(|| format!("Spec '{name}' has no '{title}' section"))?;
+            Some(section.line)
+        }
+        None => None,
+    };
+
+    let editor = resolve_editor();
+    let words = split_command(&editor);
+    let (program, args) = words
+        .split_first()
+        .ok_or_else(|| format!("Invalid editor command '{editor}'"))?;
+
+    let mut command = Command::new(program);
+    command.args(args);
+    match line.map(|line| (line, jump_style(program))) {
+        Some((line, Some(JumpStyle::PlusLine))) => {
+            command.arg(format!("+{line}")).arg(&path);
+        }
+        Some((line, Some(JumpStyle::Goto))) => {
+            command
+                .arg("--goto")
+                .arg(format!("{}:{line}", path.display()));
+        }
+        Some((line, Some(JumpStyle::Suffix))) => {
+            command.arg(format!("{}:{line}", path.display()));
+        }
+        Some((_, None)) => {
+            eprintln!("Note: don't know how to jump to a line in '{program}'; opening the file");
+            command.arg(&path);
+        }
+        None => {
+            command.arg(&path);
+        }
+    }
+    command
+        .status()
+        .map_err(|e| format!("Failed to open editor '{editor}': {e}"))?;
 
-    if !found {
+    Ok(())
+}
+
+/// How an editor takes a line to open the file at.
+enum JumpStyle {
+    /// `vim +12 file`
+    PlusLine,
+    /// `code --goto file:12`
+    Goto,
+    /// `subl file:12`
+    Suffix,
+}
+
+/// The jump syntax for an editor program, recognised by its file name.
+fn jump_style(program: &str) -> Option<JumpStyle> {
+    let name = std::path::Path::new(program)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(program);
+    match name {
+        "vi" | "vim" | "nvim" | "gvim" | "mvim" | "nano" | "emacs" | "emacsclient" | "micro"
+        | "kak" | "joe" | "mg" => Some(JumpStyle::PlusLine),
+        "code" | "code-insiders" | "codium" | "cursor" | "windsurf" => Some(JumpStyle::Goto),
+        "subl" | "zed" | "hx" | "helix" => Some(JumpStyle::Suffix),
+        _ => None,
+    }
+}
+
+/// The editor command for specs: `$TINYSPEC_EDITOR`, then the config `editor`
+/// key, then `$EDITOR`, then `vi`. Empty values are skipped.
+fn resolve_editor() -> String {
+    let env = |key: &str| std::env::var(key).ok().filter(|v| !v.trim().is_empty());
+    env("TINYSPEC_EDITOR")
+        .or_else(|| {
+            load_config()
+                .ok()
+                .and_then(|c| c.editor)
+                .filter(|v| !v.trim().is_empty())
+        })
+        .or_else(|| env("EDITOR"))
+        .unwrap_or_else(|| "vi".into())
+}
+
+/// Split a command line into words on whitespace, honouring single and double
+/// quotes (e.g. `code --wait` or `"/path with spaces/subl" -w`).
+fn split_command(command: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+
+    for c in command.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => word.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+pub fn delete(name: &str) -> Result<(), String> {
+    let path = find_spec(name)?;
+    let filename = path.file_name().unwrap().to_string_lossy();
+
+    eprint!("Delete {filename}? [y/N] ");
+    io::stderr().flush().ok();
+
+    let mut input = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut input)
+        .map_err(|e| format!("Failed to read input: {e}"))?;
+
+    if input.trim().eq_ignore_ascii_case("y") {
+        fs::remove_file(&path).map_err(|e| format!("Failed to delete spec: {e}"))?;
+        if let Some(parent) = path.parent() {
+            remove_empty_group_dir(parent, &specs_dir());
+        }
+        println!("Deleted {filename}");
+    } else {
+        println!("Cancelled.");
+    }
+
+    Ok(())
+}
+
+/// Rename the `.specs/<old>/` group directory to `.specs/<new>/`. Spec names
+/// don't include the group, so no file contents change and no names can collide.
+pub fn rename_group(old: &str, new: &str) -> Result<(), String> {
+    validate_group_name(new)?;
+    let reserved = ["archive", "templates"];
+    if let Some(name) = [old, new].into_iter().find(|g| reserved.contains(g)) {
+        return Err(format!(
+            "'{name}' is reserved and can't be used as a group name"
+        ));
+    }
+
+    let root = specs_dir();
+    let src = root.join(old);
+    if old.is_empty() || old.contains(['/', '\\']) || !src.is_dir() {
+        return Err(format!("Group '{old}' does not exist"));
+    }
+    let dest = root.join(new);
+    if dest.exists() {
+        return Err(format!("Group '{new}' already exists"));
+    }
+
+    let count = fs::read_dir(&src)
+        .map_err(|e| format!("Failed to read {}: {e}", src.display()))?
+        .flatten()
+        .filter(|entry| is_spec_file(&entry.path()))
+        .count();
+    fs::rename(&src, &dest).map_err(|e| format!("Failed to rename group: {e}"))?;
+
+    println!("Renamed group '{old}' to '{new}' ({count} spec(s))");
+    Ok(())
+}
+
+/// Move a spec into `group` (created if needed), or back to the top of
+/// `.specs/` when `group` is `None`. The group folder it leaves is removed
+/// once empty.
+pub fn move_spec(spec_name: &str, group: Option<&str>) -> Result<(), String> {
+    let root = specs_dir();
+    let dest_dir = match group {
+        Some(group) => {
+            validate_group_name(group)?;
+            if ["archive", "templates"].contains(&group) {
+                return Err(format!(
+                    "'{group}' is reserved and can't be used as a group name"
+                ));
+            }
+            root.join(group)
+        }
+        None => root.clone(),
+    };
+
+    let path = find_spec(spec_name)?;
+    let filename = path.file_name().unwrap_or_default().to_string_lossy();
+    let name = extract_spec_name(&filename).unwrap_or_default();
+    let src_dir = path.parent().unwrap_or(&root).to_path_buf();
+    if src_dir == dest_dir {
+        return Err(match group {
+            Some(group) => format!("Spec '{name}' is already in group '{group}'"),
+            None => format!("Spec '{name}' is not in a group"),
+        });
+    }
+
+    let dest = dest_dir.join(filename.as_ref());
+    let duplicate = collect_spec_files()?.into_iter().find(|p| {
+        *p != path
+            && p.parent() == Some(dest_dir.as_path())
+            && p.file_name()
+                .and_then(|f| f.to_str())
+                .and_then(extract_spec_name)
+                == Some(name)
+    });
+    if let Some(existing) = duplicate.or_else(|| dest.exists().then(|| dest.clone())) {
+        return Err(format!(
+            "A spec named '{name}' already exists there ({})",
+            display_path(&existing)
+        ));
+    }
+
+    create_dir_within(&dest_dir, &root)?;
+    fs::rename(&path, &dest).map_err(|e| format!("Failed to move spec: {e}"))?;
+    remove_empty_group_dir(&src_dir, &root);
+
+    match group {
+        Some(group) => println!("Moved {name} to group '{group}': {}", display_path(&dest)),
+        None => println!("Moved {name} out of its group: {}", display_path(&dest)),
+    }
+    Ok(())
+}
+
+/// Rename a spec, keeping its timestamp prefix and group. References by name
+/// are updated too: other specs' `depends_on` entries, `.specs/.order`, and the
+/// focus file. With `update_title`, the front-matter title becomes the
+/// title-cased new name.
+pub fn rename(old: &str, new: &str, update_title: bool) -> Result<(), String> {
+    use super::archive::collect_spec_files_with_archived;
+
+    validate_kebab_case(new)?;
+    let path = find_spec(old)?;
+    let filename = path.file_name().unwrap_or_default().to_string_lossy();
+    let old_name = extract_spec_name(&filename).unwrap_or_default().to_string();
+    if old_name == new {
+        return Err(format!("Spec is already named '{new}'"));
+    }
+
+    let all_files = collect_spec_files_with_archived()?;
+    if let Some(existing) = all_files.iter().find(|p| {
+        p.file_name()
+            .and_then(|f| f.to_str())
+            .and_then(extract_spec_name)
+            == Some(new)
+    }) {
+        return Err(format!(
+            "A spec named '{new}' already exists ({})",
+            display_path(existing)
+        ));
+    }
+
+    let dest = path.with_file_name(format!("{}{new}.md", &filename[..TIMESTAMP_PREFIX_LEN]));
+    fs::rename(&path, &dest).map_err(|e| format!("Failed to rename spec: {e}"))?;
+    if update_title {
+        let content = read_spec(&dest)?;
+        let content = set_title(&content, &title_from_name(new))?;
+        fs::write(&dest, content).map_err(|e| format!("Failed to write spec: {e}"))?;
+    }
+    println!("Renamed {old_name} to {new}: {}", display_path(&dest));
+
+    let mut dependents = 0;
+    for file in all_files.iter().filter(|p| **p != path) {
+        let Some(content) = read_spec_or_warn(file) else {
+            continue;
+        };
+        if let Some(updated) = rename_dependency(&content, &old_name, new) {
+            fs::write(file, updated)
+                .map_err(|e| format!("Failed to write {}: {e}", file.display()))?;
+            dependents += 1;
+        }
+    }
+    if dependents > 0 {
+        println!("Updated depends_on in {dependents} spec(s)");
+    }
+
+    let order_path = specs_dir().join(ORDER_FILE);
+    if let Ok(order) = fs::read_to_string(&order_path)
+        && order.lines().any(|l| l.trim() == old_name)
+    {
+        let lines: Vec<&str> = order
+            .lines()
+            .map(|l| if l.trim() == old_name { new } else { l })
+            .collect();
+        fs::write(&order_path, lines.join("\n") + "\n")
+            .map_err(|e| format!("Failed to write {}: {e}", order_path.display()))?;
+    }
+
+    let focus_path = focus_file_path();
+    if fs::read_to_string(&focus_path).is_ok_and(|f| f.trim() == old_name) {
+        fs::write(&focus_path, format!("{new}\n"))
+            .map_err(|e| format!("Failed to write focus file: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// Replace `old` with `new` in a spec's front-matter `depends_on` list, in
+/// either block (`- old`) or flow (`[a, old]`) style. `None` if nothing changed.
+fn rename_dependency(content: &str, old: &str, new: &str) -> Option<String> {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let close = front_matter_close(&lines)?;
+    let key = lines[1..close]
+        .iter()
+        .position(|l| l.starts_with("depends_on:"))?
+        + 1;
+
+    let unquote = |s: &str| s.trim().trim_matches(['"', '\'']).to_string();
+    let mut changed = false;
+    let inline = lines[key]["depends_on:".len()..].trim().to_string();
+    if let Some(items) = inline.strip_prefix('[').and_then(|i| i.strip_suffix(']')) {
+        let items: Vec<String> = items
+            .split(',')
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| {
+                if unquote(item) == old {
+                    changed = true;
+                    new.to_string()
+                } else {
+                    item.trim().to_string()
+                }
+            })
+            .collect();
+        lines[key] = format!("depends_on: [{}]", items.join(", "));
+    } else {
+        for line in lines[key + 1..close]
+            .iter_mut()
+            .take_while(|l| l.starts_with([' ', '\t', '-']))
+        {
+            let Some((indent, item)) = line.split_once("- ") else {
+                continue;
+            };
+            if unquote(item) == old {
+                *line = format!("{indent}- {new}");
+                changed = true;
+            }
+        }
+    }
+
+    if !changed {
+        return None;
+    }
+    let mut output = lines.join("\n");
+    if content.ends_with('\n') {
+        output.push('\n');
+    }
+    Some(output)
+}
+
+/// Resolve the spec and task for `check`/`uncheck`: either two arguments, or a
+/// single `spec:task` reference (e.g. `hello-world:A.1`).
+pub fn split_task_ref(spec_arg: &str, task_id: Option<&str>) -> Result<(String, String), String> {
+    if let Some(task_id) = task_id {
+        return Ok((spec_arg.to_string(), task_id.to_string()));
+    }
+    match spec_arg.split_once(':') {
+        Some((name, task_id)) if !name.is_empty() && !task_id.is_empty() => {
+            Ok((name.to_string(), task_id.to_string()))
+        }
+        _ => Err(format!(
+            "Missing task ID. Use `<spec> <task>` or `<spec>:<task>` (got '{spec_arg}')"
+        )),
+    }
+}
+
+/// `check --match`: the ID of the one unchecked task (impl or test, at any
+/// depth) whose description contains `text`, ignoring case. Errors list the
+/// candidates when several match.
+pub fn find_task_by_text(name: &str, text: &str) -> Result<String, String> {
+    let path = find_spec(name)?;
+    let summary =
+        load_spec_summary(&path).ok_or_else(|| format!("Failed to load spec '{name}'"))?;
+    let needle = text.to_lowercase();
+    let matches: Vec<&super::summary::TaskNode> = flatten_tasks(&summary.tasks)
+        .into_iter()
+        .chain(flatten_tasks(&summary.test_tasks))
+        .map(|(t, _)| t)
+        .filter(|t| !t.checked && t.description.to_lowercase().contains(&needle))
+        .collect();
+
+    match matches.as_slice() {
+        [task] => Ok(task.id.clone()),
+        [] => Err(format!(
+            "No unchecked task matching '{text}' in spec '{name}'"
+        )),
+        _ => {
+            let candidates: Vec<String> = matches
+                .iter()
+                .map(|t| format!("  {}: {}", t.id, t.description))
+                .collect();
+            Err(format!(
+                "{} unchecked tasks match '{text}' in spec '{name}'; use a task ID:\n{}",
+                matches.len(),
+                candidates.join("\n")
+            ))
+        }
+    }
+}
+
+/// Which tasks `check`/`uncheck` touch, relative to the named task.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum TaskScope {
+    /// Only the named task (the default)
+    #[default]
+    Task,
+    /// The named task's direct subtasks (A.1, A.2, ...), but not the task itself
+    Subtasks,
+    /// The named task and its direct subtasks
+    All,
+}
+
+/// How `check`/`uncheck` apply a change.
+#[derive(Clone, Copy)]
+pub struct CheckOptions {
+    pub scope: TaskScope,
+    /// Append ` (done YYYY-MM-DD)` when checking
+    pub timestamp: bool,
+    pub fire_hooks: bool,
+    pub format: bool,
+}
+
+impl Default for CheckOptions {
+    fn default() -> Self {
+        Self {
+            scope: TaskScope::Task,
+            timestamp: false,
+            fire_hooks: true,
+            format: true,
+        }
+    }
+}
+
+/// Check or uncheck a task, or its subtasks per `options.scope`. With
+/// `options.timestamp`, checking appends ` (done YYYY-MM-DD)`; unchecking
+/// always strips that annotation.
+pub fn check_task(
+    name: &str,
+    task_id: &str,
+    check: bool,
+    options: &CheckOptions,
+) -> Result<(), String> {
+    let path = find_spec(name)?;
+    let content = read_spec(&path)?;
+
+    // Capture status before change (for transition detection)
+    let summary_before = load_spec_summary(&path);
+
+    // `next` resolves to the next actionable task (check) or the last checked task (uncheck)
+    let task_id = if task_id == "next" {
+        let task = summary_before.as_ref().and_then(|s| {
+            if check {
+                s.next_task()
+            } else {
+                s.last_checked_task()
+            }
+        });
+        task.map(|t| t.id.clone()).ok_or_else(|| {
+            let state = if check { "unchecked" } else { "checked" };
+            format!("No {state} tasks remaining in spec '{name}'")
+        })?
+    } else {
+        validate_task_id(task_id)?;
+        task_id.to_string()
+    };
+
+    let changes: Vec<(String, bool)> = scoped_task_ids(
+        summary_before.as_ref(),
+        name,
+        &task_id,
+        check,
+        options.scope,
+    )?
+    .into_iter()
+    .map(|id| (id, check))
+    .collect();
+
+    apply_task_changes(
+        name,
+        &path,
+        &content,
+        &changes,
+        summary_before.map(|s| s.status),
+        options,
+    )
+}
+
+/// The task IDs `scope` selects around `task_id`. Subtasks already in the
+/// target state are skipped, so `--subtasks` can finish off a partly done group.
+fn scoped_task_ids(
+    summary: Option<&super::summary::SpecSummary>,
+    name: &str,
+    task_id: &str,
+    check: bool,
+    scope: TaskScope,
+) -> Result<Vec<String>, String> {
+    if scope == TaskScope::Task {
+        return Ok(vec![task_id.to_string()]);
+    }
+    let task = summary
+        .into_iter()
+        .flat_map(|s| {
+            flatten_tasks(&s.tasks)
+                .into_iter()
+                .chain(flatten_tasks(&s.test_tasks))
+        })
+        .map(|(t, _)| t)
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| format!("No task '{task_id}' found in spec '{name}'"))?;
+    if task.children.is_empty() {
+        return Err(format!("Task '{task_id}' has no subtasks"));
+    }
+
+    // `--subtasks` takes direct subtasks only; `--all` the task and everything under it
+    let selected: Vec<&super::summary::TaskNode> = match scope {
+        TaskScope::All => flatten_tasks(std::slice::from_ref(task))
+            .into_iter()
+            .map(|(t, _)| t)
+            .collect(),
+        _ => task.children.iter().collect(),
+    };
+    let ids: Vec<String> = selected
+        .into_iter()
+        .filter(|t| t.checked != check)
+        .map(|t| t.id.clone())
+        .collect();
+    if ids.is_empty() {
         let state = if check { "unchecked" } else { "checked" };
         return Err(format!(
-            "No {state} task '{task_id}' found in spec '{name}'"
+            "No {state} tasks under '{task_id}' in spec '{name}'"
         ));
     }
+    Ok(ids)
+}
+
+/// Interactively pick which tasks are checked, then write all changes at once.
+/// Every row is its own task, so `options.scope` doesn't apply.
+pub fn check_interactive(name: &str, options: &CheckOptions) -> Result<(), String> {
+    let path = find_spec(name)?;
+    let content = read_spec(&path)?;
+    let summary =
+        load_spec_summary(&path).ok_or_else(|| format!("Failed to load spec '{name}'"))?;
+
+    let items = super::checklist::items_for(summary.tasks.iter().chain(&summary.test_tasks));
+    if items.is_empty() {
+        return Err(format!("Spec '{name}' has no tasks"));
+    }
+
+    let Some(selected) = super::checklist::run(
+        &format!("{name} — space to toggle, enter to save, esc to cancel"),
+        &items,
+    )?
+    else {
+        println!("Cancelled.");
+        return Ok(());
+    };
+
+    let changes = super::checklist::changes(&items, &selected);
+    if changes.is_empty() {
+        println!("No changes.");
+        return Ok(());
+    }
+
+    apply_task_changes(
+        name,
+        &path,
+        &content,
+        &changes,
+        Some(summary.status),
+        options,
+    )
+}
+
+/// Set each `(task_id, check)` in `content`, write the spec once, and fire hooks.
+fn apply_task_changes(
+    name: &str,
+    path: &std::path::Path,
+    content: &str,
+    changes: &[(String, bool)],
+    status_before: Option<SpecStatus>,
+    options: &CheckOptions,
+) -> Result<(), String> {
+    let CheckOptions {
+        timestamp,
+        fire_hooks,
+        format,
+        ..
+    } = *options;
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+
+    for (task_id, check) in changes {
+        let (from, to) = if *check {
+            ("- [ ] ", "- [x] ")
+        } else {
+            ("- [x] ", "- [ ] ")
+        };
+        // Checkboxes inside code fences document the format; they aren't tasks.
+        // Match IDs the way the parser reads them: up to the first colon, trimmed.
+        let mut fence = CodeFence::default();
+        let line = lines
+            .iter_mut()
+            .filter(|line| !fence.skip(line))
+            .find(|line| {
+                parse_task_line(line)
+                    .is_some_and(|(_, checked, id, _)| id == task_id && checked != *check)
+            });
+        match line {
+            Some(line) => {
+                *line = line.replacen(from, to, 1);
+                let (rest, done) = split_done_annotation(line);
+                if !*check && done.is_some() {
+                    *line = rest.to_string();
+                } else if *check && timestamp && done.is_none() {
+                    line.push_str(&format!(" (done {})", Local::now().format("%Y-%m-%d")));
+                }
+            }
+            None => {
+                let state = if *check { "unchecked" } else { "checked" };
+                return Err(format!(
+                    "No {state} task '{task_id}' found in spec '{name}'"
+                ));
+            }
+        }
+    }
 
     // Preserve trailing newline
     let mut output = lines.join("\n");
@@ -473,15 +1770,19 @@ fn check_task_impl(name: &str, task_id: &str, check: bool, fire_hooks: bool) ->
         output.push('\n');
     }
 
-    fs::write(&path, &output).map_err(|e| format!("Failed to write spec: {e}"))?;
-    format_file(&path)?;
+    fs::write(path, &output).map_err(|e| format!("Failed to write spec: {e}"))?;
+    if format {
+        format_file(path)?;
+    }
 
-    let action = if check { "Checked" } else { "Unchecked" };
-    println!("{action} task {task_id}");
+    for (task_id, check) in changes {
+        let action = if *check { "Checked" } else { "Unchecked" };
+        println!("{action} task {task_id}");
+    }
 
     if fire_hooks {
-        let status_after = load_spec_summary(&path).map(|s| s.status);
-        let fm = parse_front_matter(&content);
+        let status_after = load_spec_summary(path).map(|s| s.status);
+        let fm = parse_front_matter(content);
         let spec_title = fm.and_then(|f| f.title).unwrap_or_else(|| name.to_string());
         let spec_group = path
             .parent()
@@ -495,30 +1796,36 @@ fn check_task_impl(name: &str, task_id: &str, check: bool, fire_hooks: bool) ->
             })
             .unwrap_or_default();
         let spec_path_str = path.to_string_lossy().to_string();
+        let name = bare_spec_name(name);
 
-        let task_event = if check {
-            Event::OnTaskCheck
-        } else {
-            Event::OnTaskUncheck
-        };
-        run_hooks(&HookContext {
-            event: task_event,
-            spec_name: name.to_string(),
-            spec_title: spec_title.clone(),
-            spec_group: spec_group.clone(),
-            task_id: task_id.to_string(),
-            spec_path: spec_path_str.clone(),
-        });
+        for (task_id, check) in changes {
+            let task_event = if *check {
+                Event::OnTaskCheck
+            } else {
+                Event::OnTaskUncheck
+            };
+            run_hooks(&HookContext {
+                event: task_event,
+                spec_name: name.to_string(),
+                spec_title: spec_title.clone(),
+                spec_group: spec_group.clone(),
+                task_id: task_id.clone(),
+                spec_path: spec_path_str.clone(),
+            });
+        }
 
-        // Fire spec-level transition hooks
-        if check && let (Some(before), Some(after)) = (status_before, status_after) {
+        // Fire spec-level transition hooks, attributed to the last checked task
+        let last_checked = changes.iter().rev().find(|(_, check)| *check);
+        if let (Some((task_id, _)), Some(before), Some(after)) =
+            (last_checked, status_before, status_after)
+        {
             if before == SpecStatus::Pending && after == SpecStatus::InProgress {
                 run_hooks(&HookContext {
                     event: Event::OnSpecStart,
                     spec_name: name.to_string(),
                     spec_title: spec_title.clone(),
                     spec_group: spec_group.clone(),
-                    task_id: task_id.to_string(),
+                    task_id: task_id.clone(),
                     spec_path: spec_path_str.clone(),
                 });
             } else if after == SpecStatus::Completed {
@@ -527,7 +1834,7 @@ fn check_task_impl(name: &str, task_id: &str, check: bool, fire_hooks: bool) ->
                     spec_name: name.to_string(),
                     spec_title,
                     spec_group,
-                    task_id: task_id.to_string(),
+                    task_id: task_id.clone(),
                     spec_path: spec_path_str,
                 });
             }
@@ -537,15 +1844,50 @@ fn check_task_impl(name: &str, task_id: &str, check: bool, fire_hooks: bool) ->
     Ok(())
 }
 
-pub fn status(
-    name: Option<&str>,
-    json: bool,
-    include_archived: bool,
-    skip_tests: bool,
-    tag: Option<&str>,
-) -> Result<(), String> {
+/// What `status` counts and how it prints.
+#[derive(Clone, Copy, Default)]
+pub struct StatusOptions<'a> {
+    pub json: bool,
+    pub include_archived: bool,
+    /// Leave Test Plan tasks out of the counts
+    pub skip_tests: bool,
+    /// Count only leaf tasks, not the groups above them
+    pub leaves_only: bool,
+    /// Only specs carrying this tag
+    pub tag: Option<&'a str>,
+    /// `application` to group the overview by referenced application
+    pub group_by: Option<&'a str>,
+    pub tree: bool,
+    /// Only specs with a failing test
+    pub failed: bool,
+    /// Only tasks assigned to this owner
+    pub owner: Option<&'a str>,
+}
+
+pub fn status(name: Option<&str>, options: &StatusOptions) -> Result<(), String> {
+    let StatusOptions {
+        json,
+        include_archived,
+        skip_tests,
+        leaves_only,
+        tag,
+        group_by,
+        tree,
+        failed,
+        owner,
+    } = *options;
     use super::archive::collect_spec_files_with_archived;
-    use super::summary::{load_all_summaries, load_spec_summary};
+    use super::summary::{
+        load_all_summaries, load_counts_of, load_summaries_of, read_spec_summary,
+    };
+
+    if let Some(group_by) = group_by
+        && group_by != "application"
+    {
+        return Err(format!(
+            "Invalid --group-by value '{group_by}'. Use: application"
+        ));
+    }
 
     let format_status = |summary: &super::summary::SpecSummary| -> String {
         let blocked = if summary.blocked { " BLOCKED" } else { "" };
@@ -571,7 +1913,10 @@ pub fn status(
         Some(name) => {
             let path = find_spec(name)?;
             let mut summary =
-                load_spec_summary(&path).ok_or_else(|| format!("Failed to load spec '{name}'"))?;
+                read_spec_summary(&path)?.ok_or_else(|| format!("Failed to load spec '{name}'"))?;
+            if leaves_only {
+                summary.count_leaves_only();
+            }
 
             // Resolve blocked status by checking deps
             if !summary.depends_on.is_empty() {
@@ -593,6 +1938,9 @@ pub fn status(
                 println!("{out}");
             } else {
                 println!("{}", format_status(&summary));
+                if tree {
+                    print_task_tree(&summary, skip_tests);
+                }
             }
         }
         None => {
@@ -606,19 +1954,56 @@ pub fn status(
                 if json {
                     println!("[]");
                 } else {
-                    println!("No specs found.");
+                    println!("{}", no_specs_message());
                 }
                 return Ok(());
             }
 
-            // Use load_all_summaries to get blocked status resolved
-            let mut summaries = load_all_summaries()?;
+            // Use load_all_summaries to get blocked status resolved. Plain-text output
+            // only needs counts, so stream the files instead of building task trees.
+            let mut summaries = if json || leaves_only || failed || owner.is_some() {
+                load_summaries_of(&files)
+            } else {
+                load_counts_of(&files)
+            };
+            if leaves_only {
+                summaries.iter_mut().for_each(|s| s.count_leaves_only());
+            }
 
             // Apply tag filter
             if let Some(tag_filter) = tag {
                 summaries.retain(|s| s.tags.iter().any(|t| t == tag_filter));
             }
 
+            if group_by.is_some() {
+                return print_status_by_application(&summaries, json, skip_tests, format_status);
+            }
+
+            if let Some(owner) = owner {
+                return print_status_by_owner(&summaries, owner, json, skip_tests);
+            }
+
+            if failed {
+                summaries.retain(|s| !s.failing_tests().is_empty());
+                if !json {
+                    if summaries.is_empty() {
+                        println!("No failing tests.");
+                    }
+                    for summary in &summaries {
+                        let failing = summary.failing_tests();
+                        let ids: Vec<&str> = failing.iter().map(|t| t.id.as_str()).collect();
+                        println!(
+                            "[{}] {}: {} failing test(s): {}",
+                            summary.priority.label(),
+                            summary.name,
+                            ids.len(),
+                            ids.join(", ")
+                        );
+                    }
+                    return Ok(());
+                }
+            }
+
             if json {
                 let out = serde_json::to_string_pretty(&summaries)
                     .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
@@ -633,6 +2018,197 @@ pub fn status(
     Ok(())
 }
 
+/// Print a spec's task tree as indented plain text, using the dashboard's
+/// glyphs. Glyphs are coloured per `--color` (by default, only on a terminal).
+fn print_task_tree(summary: &super::summary::SpecSummary, skip_tests: bool) {
+    use super::color::color_enabled;
+    use super::summary::TaskNode;
+    use crossterm::style::Stylize;
+
+    let color = color_enabled(io::stdout().is_terminal());
+    let glyph = |task: &TaskNode| match (task.checked, color) {
+        (true, true) => "✓".green().to_string(),
+        (false, true) => "☐".dark_grey().to_string(),
+        (true, false) => "✓".to_string(),
+        (false, false) => "☐".to_string(),
+    };
+
+    let mut sections = vec![("Implementation Plan", &summary.tasks)];
+    if !skip_tests {
+        sections.push(("Test Plan", &summary.test_tasks));
+    }
+    for (title, tasks) in sections {
+        if tasks.is_empty() {
+            continue;
+        }
+        println!();
+        println!("{title}");
+        for (task, depth) in flatten_tasks(tasks) {
+            let line = format!("{} {}: {}", glyph(task), task.id, task.description);
+            println!("{}{}", "  ".repeat(depth + 1), line.trim_end());
+        }
+    }
+}
+
+/// Progress counting only the tasks assigned to `owner` (via `@owner`), per spec.
+fn print_status_by_owner(
+    summaries: &[super::summary::SpecSummary],
+    owner: &str,
+    json: bool,
+    skip_tests: bool,
+) -> Result<(), String> {
+    use super::summary::TaskNode;
+
+    #[derive(Serialize)]
+    struct OwnerStatus<'a> {
+        name: &'a str,
+        checked: u32,
+        total: u32,
+        tasks: Vec<&'a str>,
+    }
+
+    let owner = owner.trim_start_matches('@');
+    let mut rows = Vec::new();
+    for summary in summaries {
+        let sections = if skip_tests {
+            vec![&summary.tasks]
+        } else {
+            vec![&summary.tasks, &summary.test_tasks]
+        };
+        let owned: Vec<&TaskNode> = sections
+            .into_iter()
+            .flat_map(|tasks| flatten_tasks(tasks))
+            .map(|(t, _)| t)
+            .filter(|t| t.owner.as_deref() == Some(owner))
+            .collect();
+        if owned.is_empty() {
+            continue;
+        }
+        let status = OwnerStatus {
+            name: &summary.name,
+            checked: owned.iter().filter(|t| t.checked).count() as u32,
+            total: owned.len() as u32,
+            tasks: owned.iter().map(|t| t.id.as_str()).collect(),
+        };
+        rows.push((summary.priority.label(), status));
+    }
+
+    if json {
+        let out: Vec<&OwnerStatus> = rows.iter().map(|(_, status)| status).collect();
+        let out = serde_json::to_string_pretty(&out)
+            .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+        println!("{out}");
+        return Ok(());
+    }
+
+    if rows.is_empty() {
+        println!("No tasks assigned to @{owner}.");
+        return Ok(());
+    }
+    let (mut checked, mut total) = (0, 0);
+    for (priority, status) in &rows {
+        println!(
+            "[{priority}] {}: {}/{} tasks complete",
+            status.name, status.checked, status.total
+        );
+        checked += status.checked;
+        total += status.total;
+    }
+    println!();
+    println!(
+        "@{owner}: {checked}/{total} tasks complete across {} spec(s)",
+        rows.len()
+    );
+    Ok(())
+}
+
+/// `status --group-by application`: for each application referenced by a spec,
+/// list those specs with an aggregate progress subtotal. Specs without
+/// applications are listed last under `(no application)`.
+fn print_status_by_application(
+    summaries: &[super::summary::SpecSummary],
+    json: bool,
+    skip_tests: bool,
+    format_status: impl Fn(&super::summary::SpecSummary) -> String,
+) -> Result<(), String> {
+    use super::summary::SpecSummary;
+
+    #[derive(Serialize)]
+    struct ApplicationStatus<'a> {
+        application: &'a str,
+        checked: u32,
+        total: u32,
+        specs: Vec<&'a str>,
+    }
+
+    const NO_APPLICATION: &str = "(no application)";
+
+    let mut by_app: std::collections::BTreeMap<&str, Vec<&SpecSummary>> =
+        std::collections::BTreeMap::new();
+    let mut unassigned = Vec::new();
+    for summary in summaries {
+        if summary.applications.is_empty() {
+            unassigned.push(summary);
+        }
+        for app in &summary.applications {
+            by_app.entry(app).or_default().push(summary);
+        }
+    }
+
+    let counts = |s: &SpecSummary| {
+        if skip_tests {
+            (s.checked, s.total)
+        } else {
+            (s.checked + s.checked_tests, s.total + s.total_tests)
+        }
+    };
+
+    let groups: Vec<(&str, Vec<&SpecSummary>)> = by_app
+        .into_iter()
+        .chain((!unassigned.is_empty()).then_some((NO_APPLICATION, unassigned)))
+        .collect();
+
+    if json {
+        let out: Vec<ApplicationStatus> = groups
+            .iter()
+            .map(|(app, specs)| {
+                let (checked, total) = specs.iter().fold((0, 0), |(c, t), s| {
+                    let (sc, st) = counts(s);
+                    (c + sc, t + st)
+                });
+                ApplicationStatus {
+                    application: app,
+                    checked,
+                    total,
+                    specs: specs.iter().map(|s| s.name.as_str()).collect(),
+                }
+            })
+            .collect();
+        let out = serde_json::to_string_pretty(&out)
+            .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+        println!("{out}");
+        return Ok(());
+    }
+
+    for (i, (app, specs)) in groups.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let (checked, total) = specs.iter().fold((0, 0), |(c, t), s| {
+            let (sc, st) = counts(s);
+            (c + sc, t + st)
+        });
+        println!(
+            "{app}: {checked}/{total} tasks complete across {} spec(s)",
+            specs.len()
+        );
+        for spec in specs {
+            println!("  {}", format_status(spec));
+        }
+    }
+    Ok(())
+}
+
 /// Skill-backed command: suggests Mermaid diagram additions for a spec.
 ///
 /// This command validates the spec exists and prints guidance directing the
@@ -656,6 +2232,62 @@ pub fn diagram(name: &str) -> Result<(), String> {
     Ok(())
 }
 
+#[derive(Serialize)]
+struct NextTask<'a> {
+    spec: &'a str,
+    task: Option<NextTaskItem<'a>>,
+    subtask: Option<NextTaskItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct NextTaskItem<'a> {
+    id: &'a str,
+    description: &'a str,
+}
+
+impl<'a> From<&'a TaskNode> for NextTaskItem<'a> {
+    fn from(task: &'a TaskNode) -> Self {
+        NextTaskItem {
+            id: &task.id,
+            description: &task.description,
+        }
+    }
+}
+
+/// Print the first unchecked top-level task (Implementation Plan, then Test
+/// Plan) and its first unchecked subtask. With `json`, `task` and `subtask`
+/// are `null` when there's nothing left.
+pub fn next(name: &str, json: bool) -> Result<(), String> {
+    let path = find_spec(name)?;
+    let summary =
+        load_spec_summary(&path).ok_or_else(|| format!("Failed to read spec '{name}'"))?;
+    let (task, subtask) = summary.next_step().unzip();
+    let subtask = subtask.flatten();
+
+    if json {
+        let next = NextTask {
+            spec: &summary.name,
+            task: task.map(NextTaskItem::from),
+            subtask: subtask.map(NextTaskItem::from),
+        };
+        let out = serde_json::to_string_pretty(&next)
+            .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+        println!("{out}");
+        return Ok(());
+    }
+
+    match task {
+        Some(task) => {
+            println!("{}: {}", task.id, task.description);
+            if let Some(subtask) = subtask {
+                println!("  {}: {}", subtask.id, subtask.description);
+            }
+        }
+        None => println!("All tasks in spec '{}' are complete.", summary.name),
+    }
+    Ok(())
+}
+
 const FOCUS_FILE: &str = ".tinyspec-focus";
 
 /// Get the path to the focus file (at the git root or cwd).
@@ -669,8 +2301,9 @@ pub(crate) fn focus_file_path() -> std::path::PathBuf {
 pub fn focus(spec_name: Option<&str>) -> Result<(), String> {
     match spec_name {
         Some(name) => {
-            // Validate spec exists
+            // Validate spec exists; the focus file holds the bare name
             find_spec(name)?;
+            let name = bare_spec_name(name);
             let path = focus_file_path();
             fs::write(&path, format!("{name}\n"))
                 .map_err(|e| format!("Failed to write focus file: {e}"))?;
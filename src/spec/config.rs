@@ -1,29 +1,109 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
+use clap_complete::engine::CompletionCandidate;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Default)]
 pub struct Config {
     #[serde(default)]
     pub repositories: std::collections::BTreeMap<String, String>,
+    /// Optional link targets (e.g. repository URLs) for `view --render-apps-as-links`,
+    /// keyed by repository name; repositories without one link to their path.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub urls: std::collections::BTreeMap<String, String>,
     /// Map of event name → list of shell commands to run.
     #[serde(default)]
     pub hooks: HashMap<String, Vec<String>>,
+    /// When true, `view` leaves unmapped applications as-is with a warning
+    /// instead of failing (override per invocation with `--strict`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub lenient_applications: bool,
+    /// Project-level only: where the specs live, possibly outside the repo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub specs_dir: Option<String>,
+    /// Project-level only: extra template directories (relative to the project
+    /// root), searched after `.specs/templates/` and before the user's templates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub template_dirs: Vec<String>,
+    /// Dashboard appearance overrides.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dashboard: Option<DashboardConfig>,
+    /// Editor command for `edit`, used when `TINYSPEC_EDITOR` is unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+    /// Template URLs or git repositories fetched by `templates sync`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub template_sources: Vec<String>,
+    /// Section headings scaffolded by `new` when no template applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sections: Option<Vec<String>>,
+    /// Columns a tab stands for when measuring task indentation (default 4).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tab_width: Option<usize>,
 }
 
-pub(crate) fn config_path() -> Result<PathBuf, String> {
-    if let Ok(dir) = std::env::var("TINYSPEC_HOME") {
-        return Ok(PathBuf::from(dir).join("config.yaml"));
+/// Dashboard appearance settings. Unset keys fall back to the built-in look.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DashboardConfig {
+    /// Progress bar width in cells.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar_width: Option<usize>,
+    /// Glyph for the completed part of a progress bar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar_filled: Option<String>,
+    /// Glyph for the remaining part of a progress bar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar_empty: Option<String>,
+    /// How often the dashboard polls for input and changes, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_ms: Option<u64>,
+}
+
+/// Dashboard UI state remembered between sessions. Kept out of `config.yaml`
+/// because the dashboard rewrites it on every exit.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct DashboardState {
+    /// Whether completed specs are hidden (toggled with `h`).
+    #[serde(default)]
+    pub hide_completed: bool,
+}
+
+/// `$TINYSPEC_HOME`, or `~/.tinyspec`.
+fn user_dir() -> Result<PathBuf, String> {
+    match std::env::var("TINYSPEC_HOME") {
+        Ok(dir) => Ok(PathBuf::from(dir)),
+        Err(_) => {
+            let home = std::env::var("HOME").map_err(|_| {
+                "HOME environment variable not set (set HOME or TINYSPEC_HOME)".to_string()
+            })?;
+            Ok(PathBuf::from(home).join(".tinyspec"))
+        }
     }
-    let home =
-        std::env::var("HOME").map_err(|_| "HOME environment variable not set".to_string())?;
-    Ok(PathBuf::from(home).join(".tinyspec").join("config.yaml"))
 }
 
+/// The user config file: `config.yaml` in `$TINYSPEC_HOME` (or `~/.tinyspec`),
+/// or an existing `config.yml` there when there is no `config.yaml`. Reads and
+/// writes both go through this, so a `.yml` file keeps being used.
+pub(crate) fn config_path() -> Result<PathBuf, String> {
+    let dir = user_dir()?;
+    let yaml = dir.join("config.yaml");
+    let yml = dir.join("config.yml");
+    Ok(if !yaml.exists() && yml.exists() {
+        yml
+    } else {
+        yaml
+    })
+}
+
+/// Load the user config. Without `HOME` (or `TINYSPEC_HOME`) there is no user
+/// config to read, so it loads as empty; only writing it requires a location.
 pub(crate) fn load_config() -> Result<Config, String> {
-    let path = config_path()?;
+    let Ok(path) = config_path() else {
+        return Ok(Config::default());
+    };
     if !path.exists() {
         return Ok(Config::default());
     }
@@ -46,13 +126,21 @@ fn save_config(config: &Config) -> Result<(), String> {
     Ok(())
 }
 
-pub fn config_set(name: &str, path: &str) -> Result<(), String> {
+/// Map `name` to `path`, and to `url` for links when given. Re-setting a path
+/// without `url` keeps any URL already recorded.
+pub fn config_set(name: &str, path: &str, url: Option<&str>) -> Result<(), String> {
     let mut config = load_config()?;
     config
         .repositories
         .insert(name.to_string(), path.to_string());
+    if let Some(url) = url {
+        config.urls.insert(name.to_string(), url.to_string());
+    }
     save_config(&config)?;
-    println!("Set {name} = {path}");
+    match url {
+        Some(url) => println!("Set {name} = {path} ({url})"),
+        None => println!("Set {name} = {path}"),
+    }
     Ok(())
 }
 
@@ -64,32 +152,156 @@ pub fn config_list() -> Result<(), String> {
         return Ok(());
     }
     for (name, path) in &config.repositories {
-        println!("{name}: {path}");
+        match config.urls.get(name) {
+            Some(url) => println!("{name}: {path} ({url})"),
+            None => println!("{name}: {path}"),
+        }
     }
     Ok(())
 }
 
-/// Load hooks from the project-level `.tinyspec.yaml` if it exists.
-pub(crate) fn load_project_hooks() -> Result<HashMap<String, Vec<String>>, String> {
+type ProjectConfig = Option<(PathBuf, Config)>;
+
+static PROJECT_CONFIG: OnceLock<Result<ProjectConfig, String>> = OnceLock::new();
+
+/// The project-level `.tinyspec.yaml` (its directory and contents), read once
+/// per run and shared by every lookup after that.
+fn load_project_config() -> Result<Option<&'static (PathBuf, Config)>, String> {
+    PROJECT_CONFIG
+        .get_or_init(read_project_config)
+        .as_ref()
+        .map(Option::as_ref)
+        .map_err(Clone::clone)
+}
+
+/// Fail early on a `.tinyspec.yaml` that can't be read or parsed, before any
+/// command resolves specs through it. Lookups that can't return an error
+/// (such as `specs_dir`) would otherwise fall back to the defaults.
+pub fn check_project_config() -> Result<(), String> {
+    load_project_config().map(|_| ())
+}
+
+fn read_project_config() -> Result<ProjectConfig, String> {
     // Walk up to find the project root (same heuristic as specs_dir)
     let mut dir = std::env::current_dir().map_err(|e| format!("Cannot get cwd: {e}"))?;
     loop {
         let candidate = dir.join(".tinyspec.yaml");
         if candidate.exists() {
             let content = fs::read_to_string(&candidate)
-                .map_err(|e| format!("Failed to read .tinyspec.yaml: {e}"))?;
+                .map_err(|e| format!("Failed to read {}: {e}", candidate.display()))?;
             if content.trim().is_empty() {
-                return Ok(HashMap::new());
+                return Ok(Some((dir, Config::default())));
             }
             let cfg: Config = serde_yaml::from_str(&content)
-                .map_err(|e| format!("Failed to parse .tinyspec.yaml: {e}"))?;
-            return Ok(cfg.hooks);
+                .map_err(|e| format!("Failed to parse {}: {e}", candidate.display()))?;
+            return Ok(Some((dir, cfg)));
         }
         if dir.join(".specs").is_dir() || !dir.pop() {
             break;
         }
     }
-    Ok(HashMap::new())
+    Ok(None)
+}
+
+/// Load hooks from the project-level `.tinyspec.yaml` if it exists.
+pub(crate) fn load_project_hooks() -> Result<HashMap<String, Vec<String>>, String> {
+    Ok(load_project_config()?
+        .map(|(_, cfg)| cfg.hooks.clone())
+        .unwrap_or_default())
+}
+
+/// Dashboard settings, with project-level keys taking precedence over user-level ones.
+pub(crate) fn load_dashboard_config() -> DashboardConfig {
+    let project = load_project_config()
+        .ok()
+        .flatten()
+        .and_then(|(_, cfg)| cfg.dashboard.clone())
+        .unwrap_or_default();
+    let user = load_config()
+        .ok()
+        .and_then(|cfg| cfg.dashboard)
+        .unwrap_or_default();
+    project.or(user)
+}
+
+impl DashboardConfig {
+    /// Keys set here, falling back to `other` for the rest.
+    fn or(self, other: DashboardConfig) -> DashboardConfig {
+        DashboardConfig {
+            bar_width: self.bar_width.or(other.bar_width),
+            bar_filled: self.bar_filled.or(other.bar_filled),
+            bar_empty: self.bar_empty.or(other.bar_empty),
+            refresh_ms: self.refresh_ms.or(other.refresh_ms),
+        }
+    }
+}
+
+/// The saved dashboard state from `dashboard.yaml` next to the user config.
+/// A missing or unreadable file gives the defaults rather than an error.
+pub(crate) fn load_dashboard_state() -> DashboardState {
+    user_dir()
+        .map(|dir| read_dashboard_state(&dir))
+        .unwrap_or_default()
+}
+
+fn read_dashboard_state(dir: &Path) -> DashboardState {
+    fs::read_to_string(dir.join("dashboard.yaml"))
+        .ok()
+        .and_then(|content| serde_yaml::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_dashboard_state(state: &DashboardState) -> Result<(), String> {
+    write_dashboard_state(&user_dir()?, state)
+}
+
+fn write_dashboard_state(dir: &Path, state: &DashboardState) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create config directory: {e}"))?;
+    let yaml = serde_yaml::to_string(state)
+        .map_err(|e| format!("Failed to serialize dashboard state: {e}"))?;
+    fs::write(dir.join("dashboard.yaml"), yaml)
+        .map_err(|e| format!("Failed to write dashboard state: {e}"))
+}
+
+/// The section list for new specs: the project's `sections`, else the user's.
+/// `None` (or an empty list) means the built-in scaffold.
+pub(crate) fn configured_sections() -> Option<Vec<String>> {
+    let project = load_project_config()
+        .ok()
+        .flatten()
+        .and_then(|(_, cfg)| cfg.sections.clone());
+    project
+        .or_else(|| load_config().ok().and_then(|cfg| cfg.sections))
+        .filter(|sections| !sections.is_empty())
+}
+
+/// The `specs_dir` set in the project-level `.tinyspec.yaml`, if any.
+/// Relative paths are resolved against the directory holding the config file.
+/// A config that fails to parse was already reported by `check_project_config`.
+pub(crate) fn configured_specs_dir() -> Option<PathBuf> {
+    let (root, cfg) = load_project_config().ok()??;
+    cfg.specs_dir.as_ref().map(|dir| root.join(dir))
+}
+
+/// Tab width for task indentation: the project's `tab_width`, else the user's,
+/// else 4. Zero is treated as 1.
+pub(crate) fn configured_tab_width() -> usize {
+    let project = load_project_config()
+        .ok()
+        .flatten()
+        .and_then(|(_, cfg)| cfg.tab_width);
+    project
+        .or_else(|| load_config().ok().and_then(|cfg| cfg.tab_width))
+        .unwrap_or(4)
+        .max(1)
+}
+
+/// The project's `template_dirs`, resolved against the project root, in order.
+pub(crate) fn configured_template_dirs() -> Vec<PathBuf> {
+    let Some((root, cfg)) = load_project_config().ok().flatten() else {
+        return Vec::new();
+    };
+    cfg.template_dirs.iter().map(|dir| root.join(dir)).collect()
 }
 
 /// Load merged hooks: project-level hooks first, then user-level hooks appended.
@@ -105,12 +317,105 @@ pub(crate) fn load_merged_hooks() -> Result<HashMap<String, Vec<String>>, String
     Ok(merged)
 }
 
+/// Print the resolved config file location and whether it exists.
+pub fn config_show_path() -> Result<(), String> {
+    let path = config_path()?;
+    let state = if path.exists() { "exists" } else { "not found" };
+    println!("{} ({state})", path.display());
+    Ok(())
+}
+
+/// Shell completion for configured repository names.
+pub fn complete_repo_names(current: &std::ffi::OsStr) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+
+    let Ok(config) = load_config() else {
+        return Vec::new();
+    };
+
+    config
+        .repositories
+        .into_keys()
+        .filter(|name| name.starts_with(current.as_ref()))
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
 pub fn config_remove(name: &str) -> Result<(), String> {
     let mut config = load_config()?;
     if config.repositories.remove(name).is_none() {
         return Err(format!("Repository '{name}' not found in config"));
     }
+    config.urls.remove(name);
     save_config(&config)?;
     println!("Removed {name}");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_dashboard_keys_win_over_user_keys() {
+        let project: DashboardConfig =
+            serde_yaml::from_str("bar_width: 20\nbar_filled: '#'\n").unwrap();
+        let user: DashboardConfig =
+            serde_yaml::from_str("bar_width: 5\nbar_empty: '.'\nrefresh_ms: 100\n").unwrap();
+        let merged = project.or(user);
+        assert_eq!(merged.bar_width, Some(20));
+        assert_eq!(merged.bar_filled.as_deref(), Some("#"));
+        assert_eq!(merged.bar_empty.as_deref(), Some("."));
+        assert_eq!(merged.refresh_ms, Some(100));
+    }
+
+    #[test]
+    fn missing_dashboard_state_loads_defaults() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(!read_dashboard_state(dir.path()).hide_completed);
+        assert!(!read_dashboard_state(&dir.path().join("no-such-dir")).hide_completed);
+    }
+
+    #[test]
+    fn dashboard_state_round_trips() {
+        let dir = tempfile::TempDir::new().unwrap();
+        // Creates the directory on first save
+        let home = dir.path().join("home");
+        write_dashboard_state(
+            &home,
+            &DashboardState {
+                hide_completed: true,
+            },
+        )
+        .unwrap();
+        assert!(read_dashboard_state(&home).hide_completed);
+
+        write_dashboard_state(
+            &home,
+            &DashboardState {
+                hide_completed: false,
+            },
+        )
+        .unwrap();
+        assert!(!read_dashboard_state(&home).hide_completed);
+    }
+
+    #[test]
+    fn corrupt_dashboard_state_loads_defaults() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("dashboard.yaml");
+        for garbage in [
+            "hide_completed: [1, 2\n",
+            "hide_completed: maybe\n",
+            "\u{0}\u{1}:::",
+        ] {
+            fs::write(&path, garbage).unwrap();
+            assert!(
+                !read_dashboard_state(dir.path()).hide_completed,
+                "{garbage:?}"
+            );
+        }
+        fs::write(&path, [0xff, 0xfe, 0x00]).unwrap();
+        assert!(!read_dashboard_state(dir.path()).hide_completed);
+    }
+}
@@ -2,7 +2,8 @@ use std::collections::HashSet;
 use std::io;
 use std::io::IsTerminal;
 use std::sync::mpsc;
-use std::time::Duration;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use crossterm::execute;
@@ -13,8 +14,16 @@ use ratatui::prelude::*;
 use ratatui::widgets::*;
 
 use super::archive::collect_spec_files_with_archived;
-use super::specs_dir;
-use super::summary::{SpecStatus, SpecSummary, load_all_summaries, load_spec_summary};
+use super::color::color_enabled;
+use super::config::{
+    DashboardState, load_config, load_dashboard_config, load_dashboard_state, save_dashboard_state,
+};
+use super::errors::strip_hints;
+use super::summary::{
+    SpecStatus, SpecSummary, flatten_tasks, group_progress, load_all_summaries_quiet,
+    load_spec_summary, percent,
+};
+use super::{SpecOrder, find_spec, read_spec, set_title, specs_dir};
 
 // ---------------------------------------------------------------------------
 // Display model
@@ -46,6 +55,120 @@ struct DetailState {
     collapsed: HashSet<usize>, // indices of collapsed impl top-level tasks
     collapsed_tests: HashSet<usize>, // indices of collapsed test top-level tasks
     selected: usize,           // index into visible detail rows
+    repos: Vec<(String, RepoState)>, // git state of the spec's application repos
+}
+
+/// The inline title editor opened with `r` in the detail view.
+struct TitleEdit {
+    buffer: String,
+    error: Option<String>,
+}
+
+/// Working-tree state of an application repo, shown in the detail view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RepoState {
+    /// Still waiting for `git status` on the background thread
+    Checking,
+    Clean,
+    Dirty,
+    /// Not mapped in config, not a git repo, or git unavailable
+    Unknown,
+}
+
+impl RepoState {
+    /// Interpret `git status --porcelain`: no output means a clean tree.
+    fn from_porcelain(success: bool, stdout: &[u8]) -> Self {
+        match (success, stdout.iter().all(u8::is_ascii_whitespace)) {
+            (false, _) => RepoState::Unknown,
+            (true, true) => RepoState::Clean,
+            (true, false) => RepoState::Dirty,
+        }
+    }
+}
+
+/// Repo states computed off the UI thread for the detail view of `spec_index`.
+struct RepoCheck {
+    spec_index: usize,
+    repos: Vec<(String, RepoState)>,
+}
+
+/// How often the open detail view re-checks its application repos.
+const REPO_REFRESH: Duration = Duration::from_secs(5);
+
+/// Look up each application's mapped folder and ask git whether it has
+/// uncommitted changes. Failures degrade to `Unknown` rather than erroring.
+fn application_repo_states(applications: &[String]) -> Vec<(String, RepoState)> {
+    let config = load_config().unwrap_or_default();
+    applications
+        .iter()
+        .map(|app| {
+            let state = match config.repositories.get(app) {
+                Some(path) => git_repo_state(path),
+                None => RepoState::Unknown,
+            };
+            (app.clone(), state)
+        })
+        .collect()
+}
+
+fn git_repo_state(path: &str) -> RepoState {
+    let output = std::process::Command::new("git")
+        .args(["-C", path, "status", "--porcelain"])
+        .stderr(std::process::Stdio::null())
+        .output();
+    match output {
+        Ok(out) => RepoState::from_porcelain(out.status.success(), &out.stdout),
+        Err(_) => RepoState::Unknown,
+    }
+}
+
+/// Progress bar appearance, from the `dashboard` config keys.
+struct BarStyle {
+    width: usize,
+    filled: String,
+    empty: String,
+}
+
+impl BarStyle {
+    fn load() -> Self {
+        let config = load_dashboard_config();
+        // A glyph that takes no columns can't draw a bar
+        let glyph = |value: Option<String>, default: &str| {
+            value
+                .filter(|g| columns(g) > 0)
+                .unwrap_or_else(|| default.into())
+        };
+        BarStyle {
+            width: config.bar_width.unwrap_or(10),
+            filled: glyph(config.bar_filled, "█"),
+            empty: glyph(config.bar_empty, "░"),
+        }
+    }
+
+    /// The filled and empty segments of a bar showing `checked` out of `total`.
+    /// `width` counts terminal columns, so wide glyphs (emoji, CJK, or several
+    /// characters) repeat fewer times; any leftover columns are padded with spaces.
+    fn segments(&self, checked: u32, total: u32) -> (String, String) {
+        let filled_columns = if total > 0 {
+            ((checked as f64 / total as f64 * self.width as f64).round() as usize).min(self.width)
+        } else {
+            0
+        };
+        let filled_width = columns(&self.filled).max(1);
+        let empty_width = columns(&self.empty).max(1);
+        let filled = filled_columns / filled_width;
+        let empty_columns = self.width - filled * filled_width;
+        let empty = empty_columns / empty_width;
+        (
+            self.filled.repeat(filled),
+            self.empty.repeat(empty) + &" ".repeat(empty_columns - empty * empty_width),
+        )
+    }
+}
+
+/// Display width of `text` in terminal columns.
+fn columns(text: &str) -> usize {
+    Span::raw(text).width()
 }
 
 struct App {
@@ -58,11 +181,35 @@ struct App {
     detail: DetailState,
     should_quit: bool,
     include_archived: bool,
+    /// Hide the "Completed" section (toggled with `h`).
+    hide_completed: bool,
+    /// Open while renaming the detail view's spec title.
+    title_edit: Option<TitleEdit>,
+    /// One-off message shown in the help bar until the next key press.
+    notice: Option<String>,
+    bar: BarStyle,
+    /// Results of background repo checks, and how many are still running.
+    repo_tx: mpsc::Sender<RepoCheck>,
+    repo_rx: mpsc::Receiver<RepoCheck>,
+    repo_checks_running: usize,
+    repos_checked_at: Option<Instant>,
 }
 
 impl App {
     fn new(include_archived: bool) -> Self {
-        let mut app = App {
+        let mut app = App::empty(
+            include_archived,
+            load_dashboard_state().hide_completed,
+            BarStyle::load(),
+        );
+        app.reload();
+        app
+    }
+
+    /// An app with no specs loaded yet.
+    fn empty(include_archived: bool, hide_completed: bool, bar: BarStyle) -> Self {
+        let (repo_tx, repo_rx) = mpsc::channel();
+        App {
             specs: Vec::new(),
             display_items: Vec::new(),
             selectable: Vec::new(),
@@ -73,12 +220,19 @@ impl App {
                 collapsed: HashSet::new(),
                 collapsed_tests: HashSet::new(),
                 selected: 0,
+                repos: Vec::new(),
             },
             should_quit: false,
             include_archived,
-        };
-        app.reload();
-        app
+            hide_completed,
+            title_edit: None,
+            notice: None,
+            bar,
+            repo_tx,
+            repo_rx,
+            repo_checks_running: 0,
+            repos_checked_at: None,
+        }
     }
 
     fn reload(&mut self) {
@@ -86,11 +240,13 @@ impl App {
             let files = collect_spec_files_with_archived().unwrap_or_default();
             let mut summaries: Vec<SpecSummary> =
                 files.iter().filter_map(|p| load_spec_summary(p)).collect();
+            let order = SpecOrder::load();
             summaries.sort_by(|a, b| {
                 let a_done = a.status == SpecStatus::Completed;
                 let b_done = b.status == SpecStatus::Completed;
                 a_done
                     .cmp(&b_done)
+                    .then_with(|| order.rank(&a.name).cmp(&order.rank(&b.name)))
                     .then_with(|| a.group.cmp(&b.group))
                     .then_with(|| {
                         if a_done && b_done {
@@ -102,11 +258,13 @@ impl App {
             });
             summaries
         } else {
-            load_all_summaries().unwrap_or_default()
+            load_all_summaries_quiet().unwrap_or_default()
         };
         self.build_display_items();
+        self.clamp_selection();
+    }
 
-        // Clamp list selection
+    fn clamp_selection(&mut self) {
         if !self.selectable.is_empty() {
             self.selected = self.selected.min(self.selectable.len() - 1);
         } else {
@@ -114,12 +272,31 @@ impl App {
         }
     }
 
+    /// Show or hide completed specs, keeping the current spec selected if it
+    /// is still visible.
+    fn toggle_hide_completed(&mut self) {
+        let current = self.selected_spec_index();
+        self.hide_completed = !self.hide_completed;
+        self.build_display_items();
+
+        let position = current.and_then(|idx| {
+            self.selectable.iter().position(
+                |&flat| matches!(self.display_items[flat], DisplayItem::Spec(i) if i == idx),
+            )
+        });
+        match position {
+            Some(position) => self.selected = position,
+            None => self.clamp_selection(),
+        }
+    }
+
     fn build_display_items(&mut self) {
         self.display_items.clear();
         self.selectable.clear();
 
         let has_incomplete = self.specs.iter().any(|s| s.status != SpecStatus::Completed);
-        let has_completed = self.specs.iter().any(|s| s.status == SpecStatus::Completed);
+        let has_completed =
+            !self.hide_completed && self.specs.iter().any(|s| s.status == SpecStatus::Completed);
 
         // "In Progress" section — all non-completed specs
         if has_incomplete {
@@ -153,11 +330,11 @@ impl App {
             // Emit group header on group change
             if spec.group.as_deref() != current_group {
                 if let Some(ref g) = spec.group {
-                    let (gc, gt) = self
-                        .specs
-                        .iter()
-                        .filter(|s| s.group.as_deref() == Some(g.as_str()) && filter(s))
-                        .fold((0u32, 0u32), |(c, t), s| (c + s.checked, t + s.total));
+                    let (gc, gt) = group_progress(
+                        self.specs
+                            .iter()
+                            .filter(|s| s.group.as_deref() == Some(g.as_str()) && filter(s)),
+                    );
                     self.display_items.push(DisplayItem::GroupHeader {
                         name: g.clone(),
                         checked: gc,
@@ -172,6 +349,68 @@ impl App {
         }
     }
 
+    /// Switch to the detail view for `self.specs[idx]`, selecting it in the
+    /// list so Esc returns to it.
+    fn open_detail(&mut self, idx: usize) {
+        if let Some(position) = self
+            .selectable
+            .iter()
+            .position(|&flat| matches!(self.display_items[flat], DisplayItem::Spec(i) if i == idx))
+        {
+            self.selected = position;
+        }
+        self.detail = DetailState {
+            spec_index: idx,
+            collapsed: HashSet::new(),
+            collapsed_tests: HashSet::new(),
+            selected: 0,
+            repos: self.specs[idx]
+                .applications
+                .iter()
+                .map(|app| (app.clone(), RepoState::Checking))
+                .collect(),
+        };
+        self.mode = Mode::Detail;
+        self.check_repos();
+    }
+
+    /// Start a background `git status` for the detail view's applications;
+    /// `poll_repos` picks up the result.
+    fn check_repos(&mut self) {
+        let spec_index = self.detail.spec_index;
+        let Some(spec) = self.specs.get(spec_index) else {
+            return;
+        };
+        if spec.applications.is_empty() {
+            return;
+        }
+        let applications = spec.applications.clone();
+        let tx = self.repo_tx.clone();
+        self.repo_checks_running += 1;
+        thread::spawn(move || {
+            let repos = application_repo_states(&applications);
+            tx.send(RepoCheck { spec_index, repos }).ok();
+        });
+    }
+
+    /// Apply finished repo checks, and start a new one when the open detail
+    /// view's states are older than `REPO_REFRESH`.
+    fn poll_repos(&mut self) {
+        while let Ok(check) = self.repo_rx.try_recv() {
+            self.repo_checks_running = self.repo_checks_running.saturating_sub(1);
+            if matches!(self.mode, Mode::Detail) && check.spec_index == self.detail.spec_index {
+                self.detail.repos = check.repos;
+                self.repos_checked_at = Some(Instant::now());
+            }
+        }
+        let stale = self
+            .repos_checked_at
+            .is_none_or(|at| at.elapsed() >= REPO_REFRESH);
+        if matches!(self.mode, Mode::Detail) && self.repo_checks_running == 0 && stale {
+            self.check_repos();
+        }
+    }
+
     /// The spec index pointed to by the current list selection.
     fn selected_spec_index(&self) -> Option<usize> {
         let flat = *self.selectable.get(self.selected)?;
@@ -196,7 +435,7 @@ impl App {
                     let expanded = !self.detail.collapsed.contains(&i);
                     rows.push(DetailRow::TopLevel { index: i, expanded });
                     if expanded {
-                        for j in 0..task.children.len() {
+                        for j in 0..flatten_tasks(&task.children).len() {
                             rows.push(DetailRow::SubTask {
                                 parent: i,
                                 child: j,
@@ -218,7 +457,7 @@ impl App {
                     let expanded = !self.detail.collapsed.contains(&i);
                     rows.push(DetailRow::TopLevel { index: i, expanded });
                     if expanded {
-                        for j in 0..task.children.len() {
+                        for j in 0..flatten_tasks(&task.children).len() {
                             rows.push(DetailRow::SubTask {
                                 parent: i,
                                 child: j,
@@ -242,7 +481,7 @@ impl App {
                     let expanded = !self.detail.collapsed_tests.contains(&i);
                     rows.push(DetailRow::TestTopLevel { index: i, expanded });
                     if expanded {
-                        for j in 0..task.children.len() {
+                        for j in 0..flatten_tasks(&task.children).len() {
                             rows.push(DetailRow::TestSubTask {
                                 parent: i,
                                 child: j,
@@ -264,7 +503,7 @@ impl App {
                     let expanded = !self.detail.collapsed_tests.contains(&i);
                     rows.push(DetailRow::TestTopLevel { index: i, expanded });
                     if expanded {
-                        for j in 0..task.children.len() {
+                        for j in 0..flatten_tasks(&task.children).len() {
                             rows.push(DetailRow::TestSubTask {
                                 parent: i,
                                 child: j,
@@ -282,10 +521,23 @@ impl App {
 #[derive(Clone)]
 enum DetailRow {
     SectionLabel(String),
-    TopLevel { index: usize, expanded: bool },
-    SubTask { parent: usize, child: usize },
-    TestTopLevel { index: usize, expanded: bool },
-    TestSubTask { parent: usize, child: usize },
+    TopLevel {
+        index: usize,
+        expanded: bool,
+    },
+    /// `child` indexes the parent's subtasks at every depth, as `flatten_tasks` lists them.
+    SubTask {
+        parent: usize,
+        child: usize,
+    },
+    TestTopLevel {
+        index: usize,
+        expanded: bool,
+    },
+    TestSubTask {
+        parent: usize,
+        child: usize,
+    },
     Separator,
 }
 
@@ -293,7 +545,29 @@ enum DetailRow {
 // Entry point
 // ---------------------------------------------------------------------------
 
-pub fn run(include_archived: bool) -> Result<(), String> {
+/// Default and allowed range for the refresh interval, in milliseconds.
+const DEFAULT_REFRESH_MS: u64 = 250;
+const REFRESH_MS_RANGE: std::ops::RangeInclusive<u64> = 50..=5000;
+
+/// `interval_ms` (from `--interval`) wins over the `dashboard.refresh_ms` config key.
+/// With `spec_name`, start in that spec's detail view; if it can't be found the
+/// list opens instead, with the error shown in the help bar.
+pub fn run(
+    spec_name: Option<&str>,
+    include_archived: bool,
+    interval_ms: Option<u64>,
+) -> Result<(), String> {
+    let refresh_ms = interval_ms
+        .or_else(|| load_dashboard_config().refresh_ms)
+        .unwrap_or(DEFAULT_REFRESH_MS);
+    if !REFRESH_MS_RANGE.contains(&refresh_ms) {
+        return Err(format!(
+            "Dashboard refresh interval must be between {} and {} ms (got {refresh_ms})",
+            REFRESH_MS_RANGE.start(),
+            REFRESH_MS_RANGE.end()
+        ));
+    }
+
     if !io::stdout().is_terminal() {
         return Err("Dashboard requires an interactive terminal".into());
     }
@@ -309,13 +583,36 @@ pub fn run(include_archived: bool) -> Result<(), String> {
     let mut _watcher = setup_watcher(tx);
 
     let mut app = App::new(include_archived);
-    let result = main_loop(&mut terminal, &mut app, &rx);
+    if let Some(name) = spec_name {
+        let found = find_spec(name).and_then(|path| {
+            app.specs
+                .iter()
+                .position(|s| s.path == path)
+                .ok_or_else(|| format!("Spec '{name}' is not shown in the dashboard"))
+        });
+        match found {
+            Ok(idx) => app.open_detail(idx),
+            Err(e) => app.notice = Some(strip_hints(&e).to_string()),
+        }
+    }
+    let result = main_loop(
+        &mut terminal,
+        &mut app,
+        &rx,
+        Duration::from_millis(refresh_ms),
+    );
 
     // Restore terminal
     disable_raw_mode().ok();
     execute!(terminal.backend_mut(), LeaveAlternateScreen).ok();
     terminal.show_cursor().ok();
 
+    // Remembering the view is best-effort; it shouldn't turn a clean exit into an error.
+    save_dashboard_state(&DashboardState {
+        hide_completed: app.hide_completed,
+    })
+    .ok();
+
     result
 }
 
@@ -342,6 +639,7 @@ fn main_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
     fs_rx: &mpsc::Receiver<notify::Result<notify::Event>>,
+    refresh: Duration,
 ) -> Result<(), String> {
     loop {
         terminal
@@ -355,13 +653,17 @@ fn main_loop(
         }
         if needs_reload {
             app.reload();
+            app.repos_checked_at = None;
         }
+        app.poll_repos();
 
-        if event::poll(Duration::from_millis(250)).map_err(|e| e.to_string())?
+        if event::poll(refresh).map_err(|e| e.to_string())?
             && let Event::Key(key) = event::read().map_err(|e| e.to_string())?
             && key.kind == KeyEventKind::Press
         {
+            app.notice = None;
             match app.mode {
+                _ if app.title_edit.is_some() => handle_title_edit_key(app, key.code),
                 Mode::List => handle_list_key(app, key.code),
                 Mode::Detail => handle_detail_key(app, key.code),
             }
@@ -380,25 +682,18 @@ fn main_loop(
 fn handle_list_key(app: &mut App, code: KeyCode) {
     match code {
         KeyCode::Char('q') => app.should_quit = true,
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected > 0 {
-                app.selected -= 1;
-            }
+        KeyCode::Up | KeyCode::Char('k') if app.selected > 0 => {
+            app.selected -= 1;
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if !app.selectable.is_empty() && app.selected < app.selectable.len() - 1 {
-                app.selected += 1;
-            }
+        KeyCode::Down | KeyCode::Char('j')
+            if !app.selectable.is_empty() && app.selected < app.selectable.len() - 1 =>
+        {
+            app.selected += 1;
         }
+        KeyCode::Char('h') => app.toggle_hide_completed(),
         KeyCode::Enter => {
             if let Some(idx) = app.selected_spec_index() {
-                app.detail = DetailState {
-                    spec_index: idx,
-                    collapsed: HashSet::new(),
-                    collapsed_tests: HashSet::new(),
-                    selected: 0,
-                };
-                app.mode = Mode::Detail;
+                app.open_detail(idx);
             }
         }
         _ => {}
@@ -410,15 +705,19 @@ fn handle_detail_key(app: &mut App, code: KeyCode) {
     match code {
         KeyCode::Char('q') => app.should_quit = true,
         KeyCode::Esc => app.mode = Mode::List,
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.detail.selected > 0 {
-                app.detail.selected -= 1;
-            }
+        KeyCode::Char('r') => {
+            app.title_edit = Some(TitleEdit {
+                buffer: app.specs[app.detail.spec_index].title.clone(),
+                error: None,
+            });
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if row_count > 0 && app.detail.selected < row_count - 1 {
-                app.detail.selected += 1;
-            }
+        KeyCode::Up | KeyCode::Char('k') if app.detail.selected > 0 => {
+            app.detail.selected -= 1;
+        }
+        KeyCode::Down | KeyCode::Char('j')
+            if row_count > 0 && app.detail.selected < row_count - 1 =>
+        {
+            app.detail.selected += 1;
         }
         KeyCode::Enter | KeyCode::Char(' ') => {
             let rows = app.detail_rows();
@@ -446,12 +745,65 @@ fn handle_detail_key(app: &mut App, code: KeyCode) {
     }
 }
 
+fn handle_title_edit_key(app: &mut App, code: KeyCode) {
+    let Some(edit) = app.title_edit.as_mut() else {
+        return;
+    };
+    match code {
+        KeyCode::Esc => app.title_edit = None,
+        KeyCode::Enter => match save_title(app) {
+            Ok(()) => app.title_edit = None,
+            Err(e) => {
+                if let Some(edit) = app.title_edit.as_mut() {
+                    edit.error = Some(e);
+                }
+            }
+        },
+        KeyCode::Backspace => {
+            edit.buffer.pop();
+        }
+        KeyCode::Char(c) => edit.buffer.push(c),
+        _ => {}
+    }
+}
+
+/// Write the edited title into the spec's front matter, touching only that line.
+fn save_title(app: &mut App) -> Result<(), String> {
+    let title = app
+        .title_edit
+        .as_ref()
+        .map(|edit| edit.buffer.trim().to_string())
+        .unwrap_or_default();
+    if title.is_empty() {
+        return Err("Title cannot be empty".into());
+    }
+
+    let spec = &mut app.specs[app.detail.spec_index];
+    let content = read_spec(&spec.path)?;
+    let updated = set_title(&content, &title)?;
+    std::fs::write(&spec.path, updated).map_err(|e| format!("Failed to write spec: {e}"))?;
+    spec.title = title;
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Rendering
 // ---------------------------------------------------------------------------
 
+/// Rows needed for the title bar, at least one content row, and the help bar.
+const MIN_HEIGHT: u16 = 3;
+
 fn ui(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
+    // Resizing can briefly shrink the terminal below the fixed rows; say so
+    // rather than laying out widgets that don't fit.
+    if area.height < MIN_HEIGHT {
+        frame.render_widget(
+            Paragraph::new(" terminal too small").style(Style::default().fg(Color::DarkGray)),
+            area,
+        );
+        return;
+    }
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -500,6 +852,10 @@ fn ui(frame: &mut Frame, app: &mut App) {
                         .fg(Color::Cyan)
                         .add_modifier(Modifier::BOLD),
                 ),
+                Span::styled(
+                    format!("  {}", spec.title),
+                    Style::default().fg(Color::White),
+                ),
                 Span::raw(sections),
             ])
         }
@@ -515,17 +871,68 @@ fn ui(frame: &mut Frame, app: &mut App) {
         Mode::Detail => render_detail(frame, app, chunks[1]),
     }
 
+    if let Some(edit) = &app.title_edit {
+        render_title_edit(frame, edit, area);
+    }
+
     // Help bar
     let help = match app.mode {
-        Mode::List => " ↑↓/jk navigate  Enter detail  q quit",
-        Mode::Detail => " ↑↓/jk navigate  Enter toggle  Esc back  q quit",
+        _ if app.title_edit.is_some() => " Enter save  Esc cancel",
+        Mode::List if app.hide_completed => {
+            " ↑↓/jk navigate  Enter detail  h show completed  q quit"
+        }
+        Mode::List => " ↑↓/jk navigate  Enter detail  h hide completed  q quit",
+        Mode::Detail => " ↑↓/jk navigate  Enter toggle  r edit title  Esc back  q quit",
+    };
+    let help = match &app.notice {
+        Some(notice) => Span::styled(format!(" {notice}"), Style::default().fg(Color::Red)),
+        None => Span::styled(help, Style::default().fg(Color::DarkGray)),
+    };
+    frame.render_widget(Paragraph::new(Line::from(help)), chunks[2]);
+
+    if !color_enabled(true) {
+        strip_colors(frame.buffer_mut());
+    }
+}
+
+/// `--color never`: drop every colour, drawing cells that had a background
+/// (title bar, selected row) in reverse video so they still stand out.
+fn strip_colors(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if cell.bg != Color::Reset {
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+/// A small centred input box over the detail view.
+fn render_title_edit(frame: &mut Frame, edit: &TitleEdit, area: Rect) {
+    let width = area.width.saturating_sub(4).min(60);
+    let height = if edit.error.is_some() { 4 } else { 3 };
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height: height.min(area.height),
     };
+
+    let mut lines = vec![Line::raw(format!("{}▏", edit.buffer))];
+    if let Some(error) = &edit.error {
+        lines.push(Line::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    frame.render_widget(Clear, popup);
     frame.render_widget(
-        Paragraph::new(Line::from(Span::styled(
-            help,
-            Style::default().fg(Color::DarkGray),
-        ))),
-        chunks[2],
+        Paragraph::new(lines).block(
+            Block::bordered()
+                .title(" Edit title ")
+                .border_style(Style::default().fg(Color::Cyan)),
+        ),
+        popup,
     );
 }
 
@@ -536,8 +943,12 @@ fn render_list(frame: &mut Frame, app: &mut App, area: Rect) {
         frame.render_widget(msg, area);
         return;
     }
-
-    let bar_width = 10usize;
+    if app.display_items.is_empty() {
+        let msg = Paragraph::new("\n  All specs are completed. Press h to show them.")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(msg, area);
+        return;
+    }
 
     let items: Vec<ListItem> = app
         .display_items
@@ -558,11 +969,7 @@ fn render_list(frame: &mut Frame, app: &mut App, area: Rect) {
                 checked,
                 total,
             } => {
-                let pct = if *total > 0 {
-                    *checked as f64 / *total as f64 * 100.0
-                } else {
-                    0.0
-                };
+                let pct = percent(*checked, *total);
                 ListItem::new(Line::from(vec![
                     Span::raw("  "),
                     Span::styled(
@@ -571,34 +978,24 @@ fn render_list(frame: &mut Frame, app: &mut App, area: Rect) {
                             .fg(Color::Blue)
                             .add_modifier(Modifier::BOLD),
                     ),
-                    Span::styled(format!("  {pct:.0}%"), Style::default().fg(Color::DarkGray)),
+                    Span::styled(format!("  {pct}%"), Style::default().fg(Color::DarkGray)),
                 ]))
             }
             DisplayItem::Spec(idx) => {
                 let spec = &app.specs[*idx];
 
-                // Distinguish "impl done, tests pending" from fully complete
+                // Distinguish "impl done, tests pending" (cyan) from fully complete
                 let impl_done = spec.total == 0 || spec.checked == spec.total;
                 let tests_done = spec.total_tests == 0 || spec.checked_tests == spec.total_tests;
-                let (icon, icon_color) = if impl_done && tests_done {
-                    ("✓", Color::Green)
-                } else if impl_done && !tests_done {
-                    // Impl complete but tests pending — use cyan to distinguish
-                    ("◑", Color::Cyan)
-                } else {
-                    match spec.status {
-                        SpecStatus::InProgress => ("●", Color::Yellow),
-                        SpecStatus::Pending => ("○", Color::DarkGray),
-                        SpecStatus::Completed => ("✓", Color::Green),
-                    }
+                let icon = spec.status_glyph();
+                let icon_color = match icon {
+                    "✓" => Color::Green,
+                    "◑" => Color::Cyan,
+                    "●" => Color::Yellow,
+                    _ => Color::DarkGray,
                 };
 
-                let filled = if spec.total > 0 {
-                    (spec.checked as f64 / spec.total as f64 * bar_width as f64).round() as usize
-                } else {
-                    0
-                };
-                let empty = bar_width - filled;
+                let (filled, empty) = app.bar.segments(spec.checked, spec.total);
 
                 let bar_color = if impl_done && tests_done {
                     Color::Green
@@ -627,8 +1024,8 @@ fn render_list(frame: &mut Frame, app: &mut App, area: Rect) {
                         Style::default().fg(Color::DarkGray),
                     ),
                     Span::raw(format!("{:<24}", spec.name)),
-                    Span::styled("█".repeat(filled), Style::default().fg(bar_color)),
-                    Span::styled("░".repeat(empty), Style::default().fg(Color::DarkGray)),
+                    Span::styled(filled, Style::default().fg(bar_color)),
+                    Span::styled(empty, Style::default().fg(Color::DarkGray)),
                     Span::raw(counter),
                 ]))
             }
@@ -676,7 +1073,9 @@ fn render_task_top_level<'a>(task: &'a super::summary::TaskNode, expanded: bool)
     ]))
 }
 
-fn render_task_subtask<'a>(task: &'a super::summary::TaskNode) -> ListItem<'a> {
+/// A subtask row of `parent`, indented by its depth below it.
+fn render_task_subtask(parent: &super::summary::TaskNode, child: usize) -> ListItem<'_> {
+    let (task, depth) = flatten_tasks(&parent.children)[child];
     let check = if task.checked { "✓" } else { "☐" };
     let check_color = if task.checked {
         Color::Green
@@ -684,7 +1083,7 @@ fn render_task_subtask<'a>(task: &'a super::summary::TaskNode) -> ListItem<'a> {
         Color::default()
     };
     ListItem::new(Line::from(vec![
-        Span::raw("      "),
+        Span::raw(format!("      {}", "  ".repeat(depth))),
         Span::styled(check, Style::default().fg(check_color)),
         Span::raw(format!(" {}: {}", task.id, task.description)),
     ]))
@@ -694,6 +1093,32 @@ fn render_detail(frame: &mut Frame, app: &mut App, area: Rect) {
     let spec = &app.specs[app.detail.spec_index];
     let rows = app.detail_rows();
 
+    // Application repo git state, one line above the task tree
+    let area = if app.detail.repos.is_empty() {
+        area
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        let mut spans = vec![Span::raw("  ")];
+        for (name, state) in &app.detail.repos {
+            let (icon, label, color) = match state {
+                RepoState::Checking => ("…", "checking", Color::DarkGray),
+                RepoState::Clean => ("✓", "clean", Color::Green),
+                RepoState::Dirty => ("●", "dirty", Color::Yellow),
+                RepoState::Unknown => ("?", "unknown", Color::DarkGray),
+            };
+            spans.push(Span::raw(format!("{name} ")));
+            spans.push(Span::styled(
+                format!("{icon} {label}   "),
+                Style::default().fg(color),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), chunks[0]);
+        chunks[1]
+    };
+
     let items: Vec<ListItem> = rows
         .iter()
         .map(|row| match row {
@@ -711,13 +1136,13 @@ fn render_detail(frame: &mut Frame, app: &mut App, area: Rect) {
                 render_task_top_level(&spec.tasks[*index], *expanded)
             }
             DetailRow::SubTask { parent, child } => {
-                render_task_subtask(&spec.tasks[*parent].children[*child])
+                render_task_subtask(&spec.tasks[*parent], *child)
             }
             DetailRow::TestTopLevel { index, expanded } => {
                 render_task_top_level(&spec.test_tasks[*index], *expanded)
             }
             DetailRow::TestSubTask { parent, child } => {
-                render_task_subtask(&spec.test_tasks[*parent].children[*child])
+                render_task_subtask(&spec.test_tasks[*parent], *child)
             }
         })
         .collect();
@@ -732,3 +1157,222 @@ fn render_detail(frame: &mut Frame, app: &mut App, area: Rect) {
 
     frame.render_stateful_widget(list, area, &mut list_state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn render(app: &mut App, width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| ui(frame, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>()
+    }
+
+    /// A spec summary with `checked` of `total` implementation tasks done.
+    fn summary(name: &str, checked: u32, total: u32, applications: &[&str]) -> SpecSummary {
+        let status = match checked {
+            0 => SpecStatus::Pending,
+            c if c == total => SpecStatus::Completed,
+            _ => SpecStatus::InProgress,
+        };
+        SpecSummary {
+            name: name.into(),
+            path: format!("/nonexistent/2025-01-01-00-00-{name}.md").into(),
+            title: name.into(),
+            group: None,
+            timestamp: "2025-01-01 00:00".into(),
+            total,
+            checked,
+            total_tests: 0,
+            checked_tests: 0,
+            status,
+            priority: Default::default(),
+            tags: Vec::new(),
+            applications: applications.iter().map(|a| a.to_string()).collect(),
+            depends_on: Vec::new(),
+            blocked: false,
+            tasks: Vec::new(),
+            test_tasks: Vec::new(),
+        }
+    }
+
+    /// An app over fixed specs, without touching the filesystem or config.
+    fn app_with(specs: Vec<SpecSummary>) -> App {
+        let bar = BarStyle {
+            width: 10,
+            filled: "█".into(),
+            empty: "░".into(),
+        };
+        let mut app = App::empty(false, false, bar);
+        app.specs = specs;
+        app.build_display_items();
+        app
+    }
+
+    #[test]
+    fn bar_segments_fill_exactly_the_width() {
+        let bar = |filled: &str, empty: &str| BarStyle {
+            width: 10,
+            filled: filled.into(),
+            empty: empty.into(),
+        };
+        let total = |(filled, empty): (String, String)| columns(&filled) + columns(&empty);
+
+        let default = bar("█", "░");
+        assert_eq!(default.segments(5, 10), ("█".repeat(5), "░".repeat(5)));
+        assert_eq!(default.segments(0, 0), (String::new(), "░".repeat(10)));
+        assert_eq!(default.segments(3, 3), ("█".repeat(10), String::new()));
+
+        // Two-column glyphs repeat half as often; odd leftovers become spaces
+        let wide = bar("🟩", "⬜");
+        assert_eq!(wide.segments(1, 2), ("🟩".repeat(2), "⬜".repeat(3)));
+        assert_eq!(
+            bar("=", "⬜").segments(1, 2),
+            ("=".repeat(5), "⬜".repeat(2) + " ")
+        );
+        let multi = bar("=>", "-");
+        assert_eq!(multi.segments(1, 3), ("=>".to_string(), "-".repeat(8)));
+        for (checked, total_tasks) in [(0, 7), (1, 7), (3, 7), (6, 7), (7, 7)] {
+            assert_eq!(total(wide.segments(checked, total_tasks)), 10);
+            assert_eq!(total(multi.segments(checked, total_tasks)), 10);
+        }
+    }
+
+    #[test]
+    fn repo_state_from_porcelain() {
+        assert_eq!(RepoState::from_porcelain(true, b""), RepoState::Clean);
+        assert_eq!(RepoState::from_porcelain(true, b"\n"), RepoState::Clean);
+        assert_eq!(
+            RepoState::from_porcelain(true, b" M src/main.rs\n?? notes.md\n"),
+            RepoState::Dirty
+        );
+        assert_eq!(RepoState::from_porcelain(false, b""), RepoState::Unknown);
+    }
+
+    #[test]
+    fn repo_states_arrive_off_the_ui_thread() {
+        let mut app = app_with(vec![summary("a", 0, 1, &["tinyspec-test-unmapped-app"])]);
+        app.open_detail(0);
+        assert_eq!(app.detail.repos[0].1, RepoState::Checking);
+        assert!(render(&mut app, 80, 6).contains("checking"));
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while app.detail.repos[0].1 == RepoState::Checking && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+            app.poll_repos();
+        }
+        assert_eq!(app.detail.repos[0].1, RepoState::Unknown);
+        assert!(render(&mut app, 80, 6).contains("unknown"));
+    }
+
+    #[test]
+    fn hiding_completed_reclamps_the_selection() {
+        let mut app = app_with(vec![
+            summary("open-one", 0, 2, &[]),
+            summary("open-two", 1, 2, &[]),
+            summary("done-one", 2, 2, &[]),
+            summary("done-two", 3, 3, &[]),
+        ]);
+        for _ in 0..3 {
+            handle_list_key(&mut app, KeyCode::Char('j'));
+        }
+        let name = |app: &App| app.selected_spec_index().map(|i| app.specs[i].name.clone());
+        assert_eq!(name(&app).as_deref(), Some("done-two"));
+
+        handle_list_key(&mut app, KeyCode::Char('h'));
+        assert_eq!(app.selected, app.selectable.len() - 1);
+        assert_eq!(name(&app).as_deref(), Some("open-two"));
+        let screen = render(&mut app, 80, 12);
+        assert!(!screen.contains("done-two"), "{screen}");
+        assert!(screen.contains("h show completed"), "{screen}");
+
+        // An in-progress selection survives showing them again
+        handle_list_key(&mut app, KeyCode::Char('h'));
+        assert_eq!(name(&app).as_deref(), Some("open-two"));
+        assert!(render(&mut app, 80, 12).contains("done-two"));
+
+        let mut app = app_with(vec![summary("done-one", 1, 1, &[])]);
+        handle_list_key(&mut app, KeyCode::Char('h'));
+        assert_eq!((app.selected, app.selected_spec_index()), (0, None));
+        assert!(render(&mut app, 80, 12).contains("All specs are completed"));
+    }
+
+    #[test]
+    fn title_edit_keys_cancel_and_save() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("2025-01-01-00-00-a.md");
+        let original = "---\ntinySpec: v0\ntitle: Old\n---\n\n# Background\n";
+        std::fs::write(&path, original).unwrap();
+        let mut spec = summary("a", 0, 1, &[]);
+        spec.path = path.clone();
+        spec.title = "Old".into();
+        let mut app = app_with(vec![spec]);
+        app.open_detail(0);
+        let type_keys = |app: &mut App, text: &str| {
+            for c in text.chars() {
+                handle_title_edit_key(app, KeyCode::Char(c));
+            }
+        };
+
+        // Esc discards the edit without writing
+        handle_detail_key(&mut app, KeyCode::Char('r'));
+        type_keys(&mut app, "er");
+        assert_eq!(app.title_edit.as_ref().unwrap().buffer, "Older");
+        handle_title_edit_key(&mut app, KeyCode::Esc);
+        assert!(app.title_edit.is_none());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+
+        // An empty title is refused and the editor stays open
+        handle_detail_key(&mut app, KeyCode::Char('r'));
+        for _ in 0.."Old".len() {
+            handle_title_edit_key(&mut app, KeyCode::Backspace);
+        }
+        handle_title_edit_key(&mut app, KeyCode::Enter);
+        let edit = app.title_edit.as_ref().unwrap();
+        assert_eq!(edit.error.as_deref(), Some("Title cannot be empty"));
+
+        // Enter saves only the title line
+        type_keys(&mut app, "New: title");
+        handle_title_edit_key(&mut app, KeyCode::Enter);
+        assert!(app.title_edit.is_none());
+        assert_eq!(app.specs[0].title, "New: title");
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "---\ntinySpec: v0\ntitle: 'New: title'\n---\n\n# Background\n"
+        );
+    }
+
+    #[test]
+    fn tiny_terminal_renders_notice() {
+        let mut app = App::new(false);
+        for (width, height) in [(0, 0), (40, 0), (40, 1), (40, 2), (3, 1)] {
+            let screen = render(&mut app, width, height);
+            assert!(!screen.contains("dashboard"), "{width}x{height}: {screen}");
+        }
+        assert!(render(&mut app, 40, 1).contains("terminal too small"));
+        assert!(render(&mut app, 40, 3).contains("tinyspec dashboard"));
+    }
+
+    #[test]
+    fn strip_colors_keeps_highlights_visible() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer[(0, 0)].set_fg(Color::Cyan).set_bg(Color::DarkGray);
+        buffer[(1, 0)].set_fg(Color::Green);
+        strip_colors(&mut buffer);
+
+        let (highlighted, plain) = (&buffer[(0, 0)], &buffer[(1, 0)]);
+        assert_eq!(
+            (highlighted.fg, highlighted.bg),
+            (Color::Reset, Color::Reset)
+        );
+        assert!(highlighted.modifier.contains(Modifier::REVERSED));
+        assert_eq!(plain.fg, Color::Reset);
+        assert!(!plain.modifier.contains(Modifier::REVERSED));
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{
+    TRASH_DIR, collect_spec_files, create_dir_within, display_path, extract_spec_name,
+    remove_empty_group_dir, specs_dir,
+};
+
+/// Report spec names that have more than one file. With `apply`, keep the newest
+/// file of each (the one `find_spec` resolves to) and move the rest to
+/// `.specs/.trash/`, keeping their group folder, so they can be restored.
+pub fn dedupe(apply: bool) -> Result<(), String> {
+    let root = specs_dir();
+    let duplicates = find_duplicates(collect_spec_files()?);
+
+    if duplicates.is_empty() {
+        println!("No duplicate specs found.");
+        return Ok(());
+    }
+
+    let mut removed = 0;
+    for (name, files) in &duplicates {
+        let (keep, stale) = files.split_last().expect("duplicates have 2+ files");
+        println!("{name}: {} files", files.len());
+        println!("  keep    {}", relative(keep, &root));
+        for path in stale {
+            if apply {
+                let dest = trash(path, &root)?;
+                println!(
+                    "  trashed {} -> {}",
+                    relative(path, &root),
+                    relative(&dest, &root)
+                );
+                removed += 1;
+            } else {
+                println!("  stale   {}", relative(path, &root));
+            }
+        }
+    }
+
+    println!();
+    if apply {
+        println!(
+            "Moved {removed} stale file(s) to {}.",
+            display_path(&root.join(TRASH_DIR))
+        );
+    } else {
+        println!(
+            "{} spec name(s) have duplicates. Run `tinyspec dedupe --apply` to keep the newest of each and move the rest to .specs/.trash/.",
+            duplicates.len()
+        );
+    }
+    Ok(())
+}
+
+/// Group spec files by name, keeping only names with several files. Each list is
+/// ordered oldest to newest by filename timestamp.
+fn find_duplicates(files: Vec<PathBuf>) -> BTreeMap<String, Vec<PathBuf>> {
+    let mut by_name: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for path in files {
+        let name = path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .and_then(extract_spec_name)
+            .map(String::from);
+        if let Some(name) = name {
+            by_name.entry(name).or_default().push(path);
+        }
+    }
+
+    by_name.retain(|_, files| files.len() > 1);
+    for files in by_name.values_mut() {
+        files.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    }
+    by_name
+}
+
+/// Move a spec file to the same relative location under `.specs/.trash/`,
+/// refusing to overwrite an earlier trashed copy.
+fn trash(path: &Path, root: &Path) -> Result<PathBuf, String> {
+    let dest = root
+        .join(TRASH_DIR)
+        .join(path.strip_prefix(root).unwrap_or(path));
+    if dest.exists() {
+        return Err(format!(
+            "{} is already in the trash; remove it first",
+            relative(&dest, root)
+        ));
+    }
+    if let Some(parent) = dest.parent() {
+        create_dir_within(parent, root)?;
+    }
+    fs::rename(path, &dest).map_err(|e| format!("Failed to move {}: {e}", path.display()))?;
+    if let Some(parent) = path.parent() {
+        remove_empty_group_dir(parent, root);
+    }
+    Ok(dest)
+}
+
+fn relative(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use super::summary::{SpecStatus, SpecSummary, load_all_summaries};
+use super::{bare_spec_name, find_spec};
+
+/// Print the dependency chain for a spec, flagging unmet and missing dependencies.
+/// Exits non-zero if a referenced spec does not exist or a cycle is found.
+pub fn deps(name: &str) -> Result<(), String> {
+    // Validate the spec exists
+    find_spec(name)?;
+    let name = bare_spec_name(name);
+
+    let summaries = load_all_summaries()?;
+    let by_name: HashMap<&str, &SpecSummary> =
+        summaries.iter().map(|s| (s.name.as_str(), s)).collect();
+    let spec = by_name
+        .get(name)
+        .ok_or_else(|| format!("Failed to load spec '{name}'"))?;
+
+    println!("{name} ({})", spec.status.label());
+    if spec.depends_on.is_empty() {
+        println!("  (no dependencies)");
+        return Ok(());
+    }
+
+    let mut report = DepsReport::default();
+    let mut stack = vec![name.to_string()];
+    print_deps(spec, &by_name, 1, &mut stack, &mut report);
+
+    println!();
+    if report.unmet.is_empty() {
+        println!("All dependencies are complete.");
+    } else {
+        println!(
+            "{} unmet dependenc{}: {}",
+            report.unmet.len(),
+            if report.unmet.len() == 1 { "y" } else { "ies" },
+            report.unmet.join(", ")
+        );
+    }
+
+    let mut errors = Vec::new();
+    if !report.missing.is_empty() {
+        errors.push(format!("unknown specs: {}", report.missing.join(", ")));
+    }
+    if report.cycle {
+        errors.push("circular dependency detected".to_string());
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Dependency errors: {}", errors.join("; ")))
+    }
+}
+
+#[derive(Default)]
+struct DepsReport {
+    unmet: Vec<String>,
+    missing: Vec<String>,
+    cycle: bool,
+}
+
+fn print_deps(
+    spec: &SpecSummary,
+    by_name: &HashMap<&str, &SpecSummary>,
+    depth: usize,
+    stack: &mut Vec<String>,
+    report: &mut DepsReport,
+) {
+    let indent = "  ".repeat(depth);
+    for dep in &spec.depends_on {
+        let Some(dep_spec) = by_name.get(dep.as_str()) else {
+            println!("{indent}? {dep} (not found)");
+            if !report.missing.contains(dep) {
+                report.missing.push(dep.clone());
+            }
+            continue;
+        };
+
+        if stack.contains(dep) {
+            println!("{indent}↻ {dep} (cycle)");
+            report.cycle = true;
+            continue;
+        }
+
+        let done = dep_spec.status == SpecStatus::Completed;
+        let mark = if done { "✓" } else { "✗" };
+        println!("{indent}{mark} {dep} ({})", dep_spec.status.label());
+        if !done && !report.unmet.contains(dep) {
+            report.unmet.push(dep.clone());
+        }
+
+        stack.push(dep.clone());
+        print_deps(dep_spec, by_name, depth + 1, stack, report);
+        stack.pop();
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::{self, IsTerminal};
+
+use crossterm::style::Stylize;
+
+use super::color::color_enabled;
+
+/// Separates a message from the remediation hints attached by `with_hint`.
+const HINT: &str = "\nhint: ";
+
+/// Attach a suggested fix to an error (or warning) message where it is
+/// constructed; `report_error` prints each hint on its own line.
+pub(crate) fn with_hint(message: impl Into<String>, hint: &str) -> String {
+    message.into() + HINT + hint
+}
+
+/// The message without its hints, for places that show a single line.
+pub(crate) fn strip_hints(message: &str) -> &str {
+    message.split(HINT).next().unwrap_or_default()
+}
+
+/// Print a command's error to stderr, followed by any hints attached with
+/// `with_hint`. Colour follows `--color` (by default, terminals only and never
+/// with `NO_COLOR`).
+pub fn report_error(message: &str) {
+    print_with_hints("Error:", message, |s| s.red().bold().to_string());
+}
+
+/// Like `report_error`, for problems that don't stop the command.
+pub(crate) fn report_warning(message: &str) {
+    print_with_hints("Warning:", message, |s| s.yellow().bold().to_string());
+}
+
+fn print_with_hints(label: &str, message: &str, paint: impl Fn(&str) -> String) {
+    let color = use_color();
+    let label = if color {
+        paint(label)
+    } else {
+        label.to_string()
+    };
+    eprintln!("{label} {}", strip_hints(message));
+    for hint in message.split(HINT).skip(1) {
+        if color {
+            eprintln!("  {} {hint}", "hint:".cyan());
+        } else {
+            eprintln!("  hint: {hint}");
+        }
+    }
+}
+
+fn use_color() -> bool {
+    color_enabled(io::stderr().is_terminal())
+}
@@ -4,18 +4,18 @@ use std::path::Path;
 use pulldown_cmark::{Options, Parser};
 use pulldown_cmark_to_cmark::cmark_with_options;
 
-use super::{collect_spec_files, find_spec, specs_dir};
+use super::{
+    collect_spec_files, find_spec, front_matter_bounds, no_specs_message, read_spec,
+    read_spec_or_warn, specs_dir,
+};
 
 /// Split YAML front matter from the Markdown body.
 /// Returns (front_matter_block_including_delimiters, body).
-fn split_front_matter(content: &str) -> (Option<&str>, &str) {
-    if let Some(rest) = content.strip_prefix("---\n")
-        && let Some(end) = rest.find("\n---\n")
-    {
-        let split = "---\n".len() + end + "\n---\n".len();
-        return (Some(&content[..split]), &content[split..]);
+pub(crate) fn split_front_matter(content: &str) -> (Option<&str>, &str) {
+    match front_matter_bounds(content) {
+        Some((_, end)) => (Some(&content[..end]), &content[end..]),
+        None => (None, content),
     }
-    (None, content)
 }
 
 /// Format a Markdown string by parsing it through pulldown-cmark and rendering
@@ -30,33 +30,43 @@ pub fn format_markdown(content: &str) -> Result<String, String> {
 
     let parser = Parser::new_ext(body, opts);
 
-    let mut cmark_opts = pulldown_cmark_to_cmark::Options::default();
-    cmark_opts.newlines_after_headline = 2;
-    cmark_opts.newlines_after_paragraph = 2;
-    cmark_opts.newlines_after_codeblock = 2;
-    cmark_opts.newlines_after_table = 2;
-    cmark_opts.newlines_after_rule = 2;
-    cmark_opts.newlines_after_list = 2;
-    cmark_opts.newlines_after_blockquote = 2;
-    cmark_opts.newlines_after_rest = 1;
-    cmark_opts.code_block_token_count = 3;
-    cmark_opts.list_token = '-';
+    let cmark_opts = pulldown_cmark_to_cmark::Options {
+        newlines_after_headline: 2,
+        newlines_after_paragraph: 2,
+        newlines_after_codeblock: 2,
+        newlines_after_table: 2,
+        newlines_after_rule: 2,
+        newlines_after_list: 2,
+        newlines_after_blockquote: 2,
+        newlines_after_rest: 1,
+        code_block_token_count: 3,
+        list_token: '-',
+        // Keep procedural steps numbered 1., 2., 3. rather than collapsing to 1.
+        increment_ordered_list_bullets: true,
+        ..Default::default()
+    };
 
     let mut formatted_body = String::with_capacity(body.len());
     cmark_with_options(parser, &mut formatted_body, cmark_opts)
         .map_err(|e| format!("Failed to format markdown: {e}"))?;
 
+    // Exactly one blank line between front matter and body, and exactly one
+    // trailing newline, however many blank lines the input had at either edge
+    let body = formatted_body.trim_start_matches('\n').trim_end();
     let mut result = String::with_capacity(content.len());
     if let Some(fm) = front_matter {
-        result.push_str(fm);
-        // Ensure blank line between front matter and body
-        if !formatted_body.starts_with('\n') {
+        result.push_str(fm.trim_end_matches('\n'));
+        result.push('\n');
+        if !body.is_empty() {
             result.push('\n');
         }
     }
-    result.push_str(&formatted_body);
+    if !body.is_empty() {
+        result.push_str(body);
+        result.push('\n');
+    }
 
-    // Ensure trailing newline
+    // Ensure trailing newline (even for empty input)
     if !result.ends_with('\n') {
         result.push('\n');
     }
@@ -66,7 +76,7 @@ pub fn format_markdown(content: &str) -> Result<String, String> {
 
 /// Format a spec file at the given path in place (no output).
 pub(crate) fn format_file(path: &Path) -> Result<(), String> {
-    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read spec: {e}"))?;
+    let content = read_spec(path)?;
     let formatted = format_markdown(&content)?;
     fs::write(path, &formatted).map_err(|e| format!("Failed to write spec: {e}"))?;
     Ok(())
@@ -75,33 +85,70 @@ pub(crate) fn format_file(path: &Path) -> Result<(), String> {
 /// Format a single spec file in place.
 pub fn format_spec(name: &str) -> Result<(), String> {
     let path = find_spec(name)?;
-    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read spec: {e}"))?;
+    let content = read_spec(&path)?;
     let formatted = format_markdown(&content)?;
     fs::write(&path, &formatted).map_err(|e| format!("Failed to write spec: {e}"))?;
     println!("Formatted {}", path.file_name().unwrap().to_string_lossy());
     Ok(())
 }
 
-/// Format all spec files in the `.specs/` directory and its subdirectories.
-pub fn format_all_specs() -> Result<(), String> {
+/// Format all spec files in the `.specs/` directory and its subdirectories, or
+/// only those in `group`. With `check`, nothing is written: files that would
+/// change are listed and the command fails if there are any.
+pub fn format_all_specs(group: Option<&str>, check: bool) -> Result<(), String> {
     let mut files = collect_spec_files()?;
+    let specs_root = specs_dir();
+
+    if let Some(group) = group {
+        let dir = specs_root.join(group);
+        if group.is_empty() || group.contains(['/', '\\']) || !dir.is_dir() {
+            return Err(format!("Group '{group}' does not exist"));
+        }
+        files.retain(|path| path.parent() == Some(dir.as_path()));
+        if files.is_empty() {
+            println!("No specs found in group '{group}'.");
+            return Ok(());
+        }
+    }
 
     if files.is_empty() {
-        println!("No specs found.");
+        println!("{}", no_specs_message());
         return Ok(());
     }
 
-    files.sort();
-    let specs_root = specs_dir();
-
+    let mut total = 0;
+    let mut changed = 0;
     for path in &files {
-        let content = fs::read_to_string(path).map_err(|e| format!("Failed to read spec: {e}"))?;
+        let Some(content) = read_spec_or_warn(path) else {
+            continue;
+        };
         let formatted = format_markdown(&content)?;
-        fs::write(path, &formatted).map_err(|e| format!("Failed to write spec: {e}"))?;
+        total += 1;
 
         // Show path relative to .specs/ for grouped specs
         let display = path.strip_prefix(&specs_root).unwrap_or(path).display();
-        println!("Formatted {display}");
+        if formatted == content {
+            if !check {
+                println!("Formatted {display}");
+            }
+            continue;
+        }
+        changed += 1;
+        if check {
+            println!("Would reformat {display}");
+        } else {
+            fs::write(path, &formatted).map_err(|e| format!("Failed to write spec: {e}"))?;
+            println!("Formatted {display}");
+        }
+    }
+
+    if check {
+        println!("{changed} of {total} spec(s) would be reformatted");
+        if changed > 0 {
+            return Err(format!("{changed} spec(s) need formatting"));
+        }
+    } else {
+        println!("Formatted {total} spec(s) ({changed} changed)");
     }
 
     Ok(())
@@ -0,0 +1,133 @@
+use std::fs;
+
+use super::find_spec;
+use super::summary::{SpecSummary, TaskNode, read_spec_summary};
+
+/// Emit a Mermaid flowchart of a spec's task tree, to stdout or to `out`.
+pub fn graph(name: &str, out: Option<&str>) -> Result<(), String> {
+    let path = find_spec(name)?;
+    let summary =
+        read_spec_summary(&path)?.ok_or_else(|| format!("Failed to load spec '{name}'"))?;
+
+    let mermaid = render_mermaid(&summary);
+
+    match out {
+        Some(file) => {
+            fs::write(file, &mermaid).map_err(|e| format!("Failed to write '{file}': {e}"))?;
+            println!("Wrote graph to {file}");
+        }
+        None => print!("{mermaid}"),
+    }
+    Ok(())
+}
+
+/// Render the task tree as a top-down Mermaid flowchart. Checked tasks are
+/// styled with the `done` class and unchecked tasks with `pending`.
+fn render_mermaid(summary: &SpecSummary) -> String {
+    let mut lines = vec![
+        "flowchart TD".to_string(),
+        format!("    spec[\"{}\"]", escape_label(&summary.title)),
+    ];
+    let mut done = Vec::new();
+    let mut pending = Vec::new();
+
+    for (section_id, label, tasks) in [
+        ("impl", "Implementation Plan", &summary.tasks),
+        ("test", "Test Plan", &summary.test_tasks),
+    ] {
+        if tasks.is_empty() {
+            continue;
+        }
+        lines.push(format!("    {section_id}[\"{label}\"]"));
+        lines.push(format!("    spec --> {section_id}"));
+        for (i, task) in tasks.iter().enumerate() {
+            let node = format!("{section_id}_{i}");
+            push_task(&mut lines, section_id, &node, task, &mut done, &mut pending);
+        }
+    }
+
+    lines.push("    classDef done fill:#d3f9d8,stroke:#2b8a3e".to_string());
+    lines.push("    classDef pending fill:#f1f3f5,stroke:#868e96".to_string());
+    if !done.is_empty() {
+        lines.push(format!("    class {} done", done.join(",")));
+    }
+    if !pending.is_empty() {
+        lines.push(format!("    class {} pending", pending.join(",")));
+    }
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    output
+}
+
+/// Add a task node linked to its parent, followed by its subtasks.
+fn push_task(
+    lines: &mut Vec<String>,
+    parent: &str,
+    node: &str,
+    task: &TaskNode,
+    done: &mut Vec<String>,
+    pending: &mut Vec<String>,
+) {
+    let label = escape_label(&format!("{}: {}", task.id, task.description));
+    lines.push(format!("    {node}[\"{label}\"]"));
+    lines.push(format!("    {parent} --> {node}"));
+    if task.checked {
+        done.push(node.to_string());
+    } else {
+        pending.push(node.to_string());
+    }
+    for (j, child) in task.children.iter().enumerate() {
+        let child_node = format!("{node}_{j}");
+        push_task(lines, node, &child_node, child, done, pending);
+    }
+}
+
+/// Escape characters that would terminate a quoted Mermaid label.
+fn escape_label(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::summary::parse_tasks_from_content;
+
+    #[test]
+    fn renders_task_tree_with_classes() {
+        let content = "\
+# Implementation Plan
+
+- [ ] A: Build the \"thing\"
+  - [x] A.1: Sub
+";
+        let tasks = parse_tasks_from_content(content);
+        let summary = SpecSummary {
+            name: "demo".into(),
+            path: "2026-01-01-00-00-demo.md".into(),
+            title: "Demo".into(),
+            group: None,
+            timestamp: String::new(),
+            total: 2,
+            checked: 1,
+            total_tests: 0,
+            checked_tests: 0,
+            status: crate::spec::summary::SpecStatus::InProgress,
+            priority: Default::default(),
+            tags: Vec::new(),
+            applications: Vec::new(),
+            depends_on: Vec::new(),
+            blocked: false,
+            tasks,
+            test_tasks: Vec::new(),
+        };
+
+        let mermaid = render_mermaid(&summary);
+        assert!(mermaid.starts_with("flowchart TD\n    spec[\"Demo\"]\n"));
+        assert!(mermaid.contains("    impl_0[\"A: Build the #quot;thing#quot;\"]\n"));
+        assert!(mermaid.contains("    impl_0 --> impl_0_0\n"));
+        assert!(mermaid.contains("    class impl_0_0 done\n"));
+        assert!(mermaid.contains("    class impl_0 pending\n"));
+        assert!(!mermaid.contains("test["));
+    }
+}
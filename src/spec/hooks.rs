@@ -2,9 +2,11 @@ use std::collections::HashMap;
 use std::process::Command;
 
 use super::config::load_merged_hooks;
+use super::errors::report_warning;
 
 /// All lifecycle events that can trigger hooks.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::enum_variant_names)]
 pub enum Event {
     OnTaskCheck,
     OnTaskUncheck,
@@ -76,7 +78,7 @@ pub fn run_hooks(context: &HookContext) {
     let hooks = match load_merged_hooks() {
         Ok(h) => h,
         Err(e) => {
-            eprintln!("Warning: failed to load hook config: {e}");
+            report_warning(&format!("failed to load hook config: {e}"));
             return;
         }
     };
@@ -98,13 +100,13 @@ pub fn run_hooks(context: &HookContext) {
         match status {
             Ok(s) if s.success() => {}
             Ok(s) => {
-                eprintln!(
-                    "Warning: hook command exited with status {}: {cmd}",
+                report_warning(&format!(
+                    "hook command exited with status {}: {cmd}",
                     s.code().unwrap_or(-1)
-                );
+                ));
             }
             Err(e) => {
-                eprintln!("Warning: failed to run hook command '{cmd}': {e}");
+                report_warning(&format!("failed to run hook command '{cmd}': {e}"));
             }
         }
     }
@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::errors::with_hint;
+use super::format::format_markdown;
+use super::specs_dir;
+use super::summary::load_all_summaries;
+
+const INDEX_FILE: &str = "_index.md";
+
+/// Returns the `.specs/_index.md` path.
+fn index_path() -> PathBuf {
+    specs_dir().join(INDEX_FILE)
+}
+
+/// Print the curated `.specs/_index.md` overview.
+pub fn print_index() -> Result<(), String> {
+    let path = index_path();
+    if !path.exists() {
+        return Err(format!(
+            "No {} found. Generate one with: tinyspec reindex",
+            path.display()
+        ));
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read index: {e}"))?;
+    print!("{content}");
+    Ok(())
+}
+
+/// Regenerate `.specs/_index.md` as a table of every spec's name, title, and status.
+pub fn reindex() -> Result<(), String> {
+    let dir = specs_dir();
+    if !dir.is_dir() {
+        return Err(with_hint(
+            "No .specs/ directory found",
+            "run `tinyspec init` to set up this project, or cd into one that has a .specs directory",
+        ));
+    }
+
+    let summaries = load_all_summaries()?;
+
+    let mut content = String::from(
+        "# Spec Index\n\n\
+         <!-- Generated by `tinyspec reindex`. Edits will be overwritten. -->\n\n",
+    );
+    if summaries.is_empty() {
+        content.push_str("No specs yet.\n");
+    } else {
+        content.push_str("| Spec | Title | Status | Progress |\n");
+        content.push_str("| --- | --- | --- | --- |\n");
+        for s in &summaries {
+            let name = match &s.group {
+                Some(g) => format!("{g}/{}", s.name),
+                None => s.name.clone(),
+            };
+            content.push_str(&format!(
+                "| {} | {} | {} | {}/{} |\n",
+                escape_cell(&name),
+                escape_cell(&s.title),
+                s.status.label(),
+                s.checked + s.checked_tests,
+                s.total + s.total_tests
+            ));
+        }
+    }
+
+    let formatted = format_markdown(&content)?;
+    let path = index_path();
+    fs::write(&path, formatted).map_err(|e| format!("Failed to write index: {e}"))?;
+    println!("Wrote {} ({} spec(s))", path.display(), summaries.len());
+    Ok(())
+}
+
+/// Escape pipe characters so they don't split a table cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
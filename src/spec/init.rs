@@ -9,6 +9,49 @@ const TINYSPEC_NEW_SKILL: &str = include_str!("../skills/tinyspec-new.md");
 const TINYSPEC_CHAT_SKILL: &str = include_str!("../skills/tinyspec-chat.md");
 const TINYSPEC_DIAGRAM_SKILL: &str = include_str!("../skills/tinyspec-diagram.md");
 
+/// Embedded skills installed by `init`, as `(name, SKILL.md content)`.
+const SKILLS: &[(&str, &str)] = &[
+    ("tinyspec-new", TINYSPEC_NEW_SKILL),
+    ("tinyspec-refine", TINYSPEC_REFINE_SKILL),
+    ("tinyspec-chat", TINYSPEC_CHAT_SKILL),
+    ("tinyspec-do", TINYSPEC_DO_SKILL),
+    ("tinyspec-task", TINYSPEC_TASK_SKILL),
+    ("tinyspec-oneshot", TINYSPEC_ONESHOT_SKILL),
+    ("tinyspec-diagram", TINYSPEC_DIAGRAM_SKILL),
+];
+
+/// The `description:` line from a skill's front matter.
+fn skill_description(content: &str) -> &str {
+    content
+        .lines()
+        .skip(1)
+        .take_while(|line| *line != "---")
+        .find_map(|line| line.strip_prefix("description:"))
+        .map(str::trim)
+        .unwrap_or_default()
+}
+
+/// Print the embedded skills `init` would install, without touching the filesystem.
+pub fn list_skills() -> Result<(), String> {
+    for (name, content) in SKILLS {
+        println!("{name:18} {}", skill_description(content));
+    }
+    Ok(())
+}
+
+/// Print the full content of one embedded skill.
+pub fn print_skill(name: &str) -> Result<(), String> {
+    let (_, content) = SKILLS
+        .iter()
+        .find(|(skill, _)| *skill == name)
+        .ok_or_else(|| {
+            let names: Vec<&str> = SKILLS.iter().map(|(skill, _)| *skill).collect();
+            format!("Unknown skill '{name}'. Available: {}", names.join(", "))
+        })?;
+    print!("{content}");
+    Ok(())
+}
+
 fn remove_matching_entries(
     dir: &Path,
     label: &str,
@@ -48,23 +91,18 @@ pub fn init(force: bool) -> Result<(), String> {
             |path| fs::remove_dir_all(path),
         );
     }
-    let skills: &[(&str, &str)] = &[
-        ("tinyspec-new", TINYSPEC_NEW_SKILL),
-        ("tinyspec-refine", TINYSPEC_REFINE_SKILL),
-        ("tinyspec-chat", TINYSPEC_CHAT_SKILL),
-        ("tinyspec-do", TINYSPEC_DO_SKILL),
-        ("tinyspec-task", TINYSPEC_TASK_SKILL),
-        ("tinyspec-oneshot", TINYSPEC_ONESHOT_SKILL),
-        ("tinyspec-diagram", TINYSPEC_DIAGRAM_SKILL),
-    ];
-
-    for (skill_name, content) in skills {
+    for (skill_name, content) in SKILLS {
         let dir = skills_dir.join(skill_name);
         fs::create_dir_all(&dir)
             .map_err(|e| format!("Failed to create .claude/skills/{skill_name}/ directory: {e}"))?;
         let path = dir.join("SKILL.md");
         if !force && path.exists() {
-            println!("Skipped {skill_name}/SKILL.md (already exists)");
+            let current = fs::read_to_string(&path).unwrap_or_default();
+            if current == *content {
+                println!("Skipped {skill_name}/SKILL.md (up to date)");
+            } else {
+                println!("Skipped {skill_name}/SKILL.md (differs — use --force to update)");
+            }
         } else {
             fs::write(&path, content)
                 .map_err(|e| format!("Failed to write {skill_name}/SKILL.md: {e}"))?;
@@ -86,17 +124,38 @@ pub fn init(force: bool) -> Result<(), String> {
     println!();
     println!("Shell completion setup:");
 
+    let (rc_file, line) = completion_setup(&detect_shell())?;
+    println!("  Add this to your {rc_file}:");
+    println!("  {line}");
+
+    Ok(())
+}
+
+/// The user's shell from `$SHELL`, falling back to bash.
+fn detect_shell() -> String {
     let shell = std::env::var("SHELL").unwrap_or_default();
-    if shell.contains("zsh") {
-        println!("  Add this to your ~/.zshrc:");
-        println!("  source <(COMPLETE=zsh tinyspec)");
-    } else if shell.contains("fish") {
-        println!("  Add this to your fish config:");
-        println!("  COMPLETE=fish tinyspec | source");
-    } else {
-        println!("  Add this to your ~/.bashrc:");
-        println!("  source <(COMPLETE=bash tinyspec)");
+    ["zsh", "fish"]
+        .into_iter()
+        .find(|name| shell.contains(name))
+        .unwrap_or("bash")
+        .to_string()
+}
+
+/// The startup file and the line that enables completion for `shell`.
+fn completion_setup(shell: &str) -> Result<(&'static str, &'static str), String> {
+    match shell {
+        "bash" => Ok(("~/.bashrc", "source <(COMPLETE=bash tinyspec)")),
+        "zsh" => Ok(("~/.zshrc", "source <(COMPLETE=zsh tinyspec)")),
+        "fish" => Ok(("fish config", "COMPLETE=fish tinyspec | source")),
+        _ => Err(format!("Unsupported shell '{shell}'. Use: bash, zsh, fish")),
     }
+}
 
+/// `init --print-completion`: print only the completion line for `shell` (or
+/// the one detected from `$SHELL`), ready to append to a startup file.
+pub fn print_completion(shell: Option<&str>) -> Result<(), String> {
+    let shell = shell.map_or_else(detect_shell, String::from);
+    let (_, line) = completion_setup(&shell)?;
+    println!("{line}");
     Ok(())
 }
@@ -2,8 +2,14 @@ use std::fs;
 use std::path::Path;
 
 use super::config::load_config;
-use super::summary::{detect_dependency_cycles, load_all_summaries, parse_tasks_from_content};
-use super::{collect_spec_files, find_spec, parse_front_matter};
+use super::schema::{Violation, validate_front_matter};
+use super::summary::{
+    SpecDocument, TaskNode, detect_dependency_cycles, flatten_tasks, load_all_summaries,
+    parse_tasks_from_content, parse_test_tasks_from_content,
+};
+use super::{
+    collect_spec_files, find_spec, front_matter_yaml, no_specs_message, parse_front_matter,
+};
 
 #[derive(Debug)]
 pub enum Severity {
@@ -65,36 +71,27 @@ pub fn lint_file(path: &Path) -> Vec<LintIssue> {
         }
     }
 
-    // Check for empty sections
-    let mut current_heading_line: Option<(usize, &str)> = None;
-    let mut section_has_content = false;
-
-    for (i, line) in content.lines().enumerate() {
-        let trimmed = line.trim();
-        if trimmed.starts_with("# ") && !trimmed.starts_with("## ") {
-            // Finish previous section check
-            if let Some((heading_line, heading)) = current_heading_line
-                && !section_has_content
-            {
-                issues.push(LintIssue::error_at(
-                    format!("Section '{heading}' is empty"),
-                    heading_line + 1,
-                ));
-            }
-            current_heading_line = Some((i, trimmed));
-            section_has_content = false;
-        } else if current_heading_line.is_some() && !trimmed.is_empty() {
-            section_has_content = true;
+    // Check front matter against the schema
+    if let Some(yaml) = front_matter_yaml(&content) {
+        for violation in validate_front_matter(yaml) {
+            issues.push(match violation {
+                Violation::Invalid(message) => LintIssue::error(message),
+                Violation::Unknown(message) => LintIssue::warning(message),
+                Violation::Missing(key) => {
+                    LintIssue::warning(format!("Front matter is missing '{key}'"))
+                }
+            });
         }
     }
-    // Check last section
-    if let Some((heading_line, heading)) = current_heading_line
-        && !section_has_content
-    {
-        issues.push(LintIssue::error_at(
-            format!("Section '{heading}' is empty"),
-            heading_line + 1,
-        ));
+
+    // Check for empty sections
+    for section in SpecDocument::parse(&content).sections {
+        if section.level == 1 && section.is_empty() {
+            issues.push(LintIssue::error_at(
+                format!("Section '# {}' is empty", section.title),
+                section.line,
+            ));
+        }
     }
 
     // Check task IDs are sequential
@@ -114,15 +111,23 @@ pub fn lint_file(path: &Path) -> Vec<LintIssue> {
                 )));
             }
 
-            // Validate subtask IDs
-            for (sub_idx, child) in task.children.iter().enumerate() {
-                let expected_sub = format!("{}.{}", task.id, sub_idx + 1);
-                if child.id != expected_sub {
-                    issues.push(LintIssue::error(format!(
-                        "Non-sequential subtask ID: expected '{expected_sub}', found '{}'",
-                        child.id
-                    )));
-                }
+            check_subtask_ids(task, &mut issues);
+        }
+    }
+
+    // Check Test Plan references point at tasks that exist in the Implementation Plan
+    let impl_ids: Vec<&str> = flatten_tasks(&tasks)
+        .into_iter()
+        .map(|(t, _)| t.id.as_str())
+        .collect();
+    let test_tasks = parse_test_tasks_from_content(&content);
+    for (test_task, _) in flatten_tasks(&test_tasks) {
+        for reference in referenced_task_ids(&test_task.description) {
+            if !impl_ids.contains(&reference) {
+                issues.push(LintIssue::warning(format!(
+                    "Test task '{}' references '{reference}', which is not in the Implementation Plan",
+                    test_task.id
+                )));
             }
         }
     }
@@ -152,15 +157,53 @@ pub fn lint_file(path: &Path) -> Vec<LintIssue> {
     issues
 }
 
+/// Subtask IDs must number their parent's ID in order (`A.1`, `A.2`, ...), at
+/// every depth.
+fn check_subtask_ids(task: &TaskNode, issues: &mut Vec<LintIssue>) {
+    for (sub_idx, child) in task.children.iter().enumerate() {
+        let expected_sub = format!("{}.{}", task.id, sub_idx + 1);
+        if child.id != expected_sub {
+            issues.push(LintIssue::error(format!(
+                "Non-sequential subtask ID: expected '{expected_sub}', found '{}'",
+                child.id
+            )));
+        }
+        check_subtask_ids(child, issues);
+    }
+}
+
+/// Extract implementation task IDs (e.g. `A.2`, `B.1`) mentioned in free text.
+/// Only dotted IDs are matched so that prose like "A user" is not mistaken for task `A`.
+fn referenced_task_ids(text: &str) -> Vec<&str> {
+    let mut ids: Vec<&str> = Vec::new();
+    for word in text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '.')) {
+        let word = word.trim_end_matches('.');
+        let mut parts = word.split('.');
+        let Some(letter) = parts.next() else {
+            continue;
+        };
+        let is_group =
+            letter.len() == 1 && letter.chars().all(|c| c.is_ascii_uppercase()) && letter != "T";
+        let rest: Vec<&str> = parts.collect();
+        let is_numbered = !rest.is_empty()
+            && rest
+                .iter()
+                .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()));
+        if is_group && is_numbered && !ids.contains(&word) {
+            ids.push(word);
+        }
+    }
+    ids
+}
+
 pub fn lint(spec_name: Option<&str>, all: bool) -> Result<(), String> {
-    let files = if all || spec_name.is_none() {
-        collect_spec_files()?
-    } else {
-        vec![find_spec(spec_name.unwrap())?]
+    let files = match spec_name {
+        Some(name) if !all => vec![find_spec(name)?],
+        _ => collect_spec_files()?,
     };
 
     if files.is_empty() {
-        println!("No specs found.");
+        println!("{}", no_specs_message());
         return Ok(());
     }
 
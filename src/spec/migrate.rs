@@ -0,0 +1,219 @@
+use std::fs;
+
+use super::errors::report_warning;
+use super::summary::CodeFence;
+use super::{collect_spec_files, extract_spec_name, front_matter_close, read_spec_or_warn};
+
+/// The newest spec format version; `new` scaffolds it and `schema` accepts it.
+pub(crate) const LATEST_VERSION: &str = "v0";
+
+/// One format upgrade, applied to specs whose `tinySpec:` is `from`.
+struct Migration {
+    from: &'static str,
+    to: &'static str,
+    /// Top-level `# Heading`s renamed by this step, as `(old, new)`
+    rename_sections: &'static [(&'static str, &'static str)],
+    /// Front-matter lines added when the key is missing, as `(key, default value)`
+    add_fields: &'static [(&'static str, &'static str)],
+}
+
+/// Every upgrade step, oldest first. `migrate` follows the chain from a spec's
+/// version to `LATEST_VERSION`, so the last step's `to` must be it.
+const MIGRATIONS: &[Migration] = &[];
+
+/// Upgrade every spec to `LATEST_VERSION`. Specs without a `tinySpec:` key are
+/// treated as `v0` and get one. With `dry_run`, report what would change and
+/// write nothing.
+pub fn migrate(dry_run: bool) -> Result<(), String> {
+    let mut changed = 0;
+    for path in collect_spec_files()? {
+        let filename = path.file_name().unwrap_or_default().to_string_lossy();
+        let Some(name) = extract_spec_name(&filename) else {
+            continue;
+        };
+        let Some(content) = read_spec_or_warn(&path) else {
+            continue;
+        };
+        let (output, steps) = match migrate_content(&content, MIGRATIONS, LATEST_VERSION) {
+            Ok(result) => result,
+            Err(e) => {
+                report_warning(&format!("skipping {name}: {e}"));
+                continue;
+            }
+        };
+        if steps.is_empty() {
+            continue;
+        }
+
+        if !dry_run {
+            fs::write(&path, &output)
+                .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+        }
+        println!("{name}: {}", steps.join("; "));
+        changed += 1;
+    }
+
+    match (changed, dry_run) {
+        (0, _) => println!("All specs are up to date ({LATEST_VERSION})."),
+        (n, true) => println!("\n{n} spec(s) would be migrated. Run without --dry-run to apply."),
+        (n, false) => println!("\nMigrated {n} spec(s) to {LATEST_VERSION}."),
+    }
+    Ok(())
+}
+
+/// Apply `migrations` to one spec until it reaches `latest`, returning the new
+/// content and a description of each change (empty when the spec is already
+/// current). Only the lines a step touches are rewritten.
+fn migrate_content(
+    content: &str,
+    migrations: &[Migration],
+    latest: &str,
+) -> Result<(String, Vec<String>), String> {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let mut steps = Vec::new();
+
+    let mut close = match front_matter_close(&lines) {
+        Some(close) => close,
+        None => {
+            lines.splice(0..0, ["---".to_string(), "---".to_string()]);
+            steps.push("add front matter".to_string());
+            1
+        }
+    };
+
+    let version_line = lines[1..close]
+        .iter()
+        .position(|l| l.starts_with("tinySpec:"))
+        .map(|i| i + 1);
+    let mut version = match version_line {
+        Some(i) => lines[i]["tinySpec:".len()..].trim().to_string(),
+        None => {
+            lines.insert(1, "tinySpec: v0".to_string());
+            close += 1;
+            steps.push("add tinySpec: v0".to_string());
+            "v0".to_string()
+        }
+    };
+
+    while version != latest {
+        let Some(step) = migrations.iter().find(|m| m.from == version) else {
+            return Err(format!(
+                "unknown format version '{version}' (latest is {latest})"
+            ));
+        };
+        for &(key, value) in step.add_fields {
+            let prefix = format!("{key}:");
+            if !lines[1..close].iter().any(|l| l.starts_with(&prefix)) {
+                lines.insert(close, format!("{key}: {value}"));
+                close += 1;
+                steps.push(format!("add {key}"));
+            }
+        }
+
+        let mut fence = CodeFence::default();
+        for line in lines[close + 1..].iter_mut() {
+            if fence.skip(line) {
+                continue;
+            }
+            let renamed = line.strip_prefix("# ").and_then(|title| {
+                step.rename_sections
+                    .iter()
+                    .find(|(old, _)| *old == title.trim())
+            });
+            if let Some((old, new)) = renamed {
+                *line = format!("# {new}");
+                steps.push(format!("rename '# {old}' to '# {new}'"));
+            }
+        }
+
+        let at = lines[1..close]
+            .iter()
+            .position(|l| l.starts_with("tinySpec:"))
+            .map_or(1, |i| i + 1);
+        lines[at] = format!("tinySpec: {}", step.to);
+        steps.push(format!("{} → {}", step.from, step.to));
+        version = step.to.to_string();
+    }
+
+    let mut output = lines.join("\n");
+    if content.ends_with('\n') || content.is_empty() {
+        output.push('\n');
+    }
+    Ok((output, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEPS: &[Migration] = &[
+        Migration {
+            from: "v0",
+            to: "v1",
+            rename_sections: &[("Proposal", "Design")],
+            add_fields: &[("status", "draft")],
+        },
+        Migration {
+            from: "v1",
+            to: "v2",
+            rename_sections: &[],
+            add_fields: &[],
+        },
+    ];
+
+    #[test]
+    fn migrates_along_the_chain_once() {
+        let content = "\
+---
+tinySpec: v0
+title: Hi
+---
+
+# Proposal
+
+```
+# Proposal
+```
+";
+        let (output, steps) = migrate_content(content, STEPS, "v2").unwrap();
+        assert_eq!(
+            output,
+            "\
+---
+tinySpec: v2
+title: Hi
+status: draft
+---
+
+# Design
+
+```
+# Proposal
+```
+"
+        );
+        assert_eq!(
+            steps,
+            [
+                "add status",
+                "rename '# Proposal' to '# Design'",
+                "v0 → v1",
+                "v1 → v2"
+            ]
+        );
+    }
+
+    #[test]
+    fn current_spec_is_untouched() {
+        let content = "---\ntinySpec: v0\n---\n\n# Background\n";
+        let (output, steps) = migrate_content(content, STEPS, "v0").unwrap();
+        assert_eq!(output, content);
+        assert!(steps.is_empty());
+    }
+
+    #[test]
+    fn unknown_version_is_an_error() {
+        let content = "---\ntinySpec: v9\n---\n";
+        assert!(migrate_content(content, STEPS, "v2").is_err());
+    }
+}
@@ -1,40 +1,83 @@
 pub(crate) mod archive;
+mod checklist;
+mod color;
 mod commands;
 mod config;
 pub(crate) mod dashboard;
+mod dedupe;
+mod deps;
+mod errors;
 mod format;
+mod graph;
 pub(crate) mod hooks;
+mod index;
 mod init;
 mod lint;
+mod migrate;
+#[cfg(feature = "remote-templates")]
+mod remote;
+mod schema;
 mod search;
 pub(crate) mod summary;
+mod tasks;
 pub(crate) mod templates;
+mod velocity;
 
 // Re-export public API (keeps `spec::function_name` working from main.rs)
 pub use archive::{archive_all_completed, archive_spec, unarchive_spec};
+pub use color::set_color_mode;
 pub use commands::{
-    check_task, check_task_no_hooks, delete, diagram, edit, focus, list, new_spec,
-    new_spec_with_hooks, status, unfocus, view,
+    CheckOptions, ListOptions, StatusOptions, TaskScope, ViewOptions, check_interactive,
+    check_task, delete, diagram, edit, find_task_by_text, focus, list, move_spec, new_spec,
+    new_spec_with_hooks, new_specs_from_file, next, rename, rename_group, split_task_ref, status,
+    unfocus, view, view_all, with_group,
 };
-pub use config::{config_list, config_remove, config_set};
+pub use config::{
+    check_project_config, complete_repo_names, config_list, config_remove, config_set,
+    config_show_path,
+};
+pub use dedupe::dedupe;
+pub use deps::deps;
+pub use errors::report_error;
 pub use format::{format_all_specs, format_spec};
+pub use graph::graph;
 pub use hooks::test_hook as hooks_test;
-pub use init::init;
+pub use index::{print_index, reindex};
+pub use init::{init, list_skills, print_completion, print_skill};
 pub use lint::lint;
+pub use migrate::migrate;
+#[cfg(feature = "remote-templates")]
+pub use remote::{template_add, template_sync};
+pub use schema::schema;
 pub use search::search;
+pub use tasks::{
+    add_task, clone_tasks, complete_check_task_ids, complete_uncheck_task_ids, reflow_ids, reset,
+};
 pub use templates::list_templates;
+pub use velocity::velocity;
 
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, BufRead};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
 use clap_complete::engine::CompletionCandidate;
 use serde::{Deserialize, Serialize};
 
+use errors::{report_warning, with_hint};
+
 const SPECS_DIR: &str = ".specs";
 const TIMESTAMP_PREFIX_LEN: usize = 17; // "YYYY-MM-DD-HH-MM-"
+const ORDER_FILE: &str = ".order";
+/// Where `dedupe --apply` moves stale copies; hidden folders are never groups.
+const TRASH_DIR: &str = ".trash";
 
-/// Walk up from the current directory looking for a `.specs/` directory.
+/// Use the project's configured `specs_dir` if set, otherwise walk up from the
+/// current directory looking for a `.specs/` directory.
 fn discover_specs_dir() -> Option<PathBuf> {
+    if let Some(dir) = config::configured_specs_dir() {
+        return Some(dir);
+    }
     let mut dir = std::env::current_dir().ok()?;
     loop {
         let candidate = dir.join(SPECS_DIR);
@@ -64,6 +107,72 @@ pub(crate) fn specs_dir() -> PathBuf {
     discover_specs_dir().unwrap_or_else(|| PathBuf::from(SPECS_DIR))
 }
 
+/// `path` relative to the current directory when it lies inside it, otherwise
+/// as-is (absolute when the specs were found in a parent or via `specs_dir`).
+pub(crate) fn display_path(path: &Path) -> String {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// What read commands print when there are no specs, telling a missing specs
+/// directory (usually the wrong working directory) apart from an empty one.
+pub(crate) fn no_specs_message() -> String {
+    match discover_specs_dir() {
+        None => "No specs found. There is no .specs directory here or in any parent directory.\n\
+             Run `tinyspec init` to set up this project and `tinyspec new <name>` to create a spec."
+            .to_string(),
+        Some(dir) if !dir.is_dir() => format!(
+            "No specs found. The configured specs_dir {} does not exist.\n\
+             Create a spec with `tinyspec new <name>`, or fix `specs_dir` in .tinyspec.yaml.",
+            dir.display()
+        ),
+        Some(dir) => format!(
+            "No specs found in {}. Create one with `tinyspec new <name>`.",
+            dir.display()
+        ),
+    }
+}
+
+/// Manual ordering from `.specs/.order`: spec names one per line (blank lines
+/// and `#` comments ignored). Listed specs sort first, in file order.
+#[derive(Default)]
+pub(crate) struct SpecOrder(std::collections::HashMap<String, usize>);
+
+impl SpecOrder {
+    pub(crate) fn load() -> Self {
+        let Ok(content) = fs::read_to_string(specs_dir().join(ORDER_FILE)) else {
+            return Self::default();
+        };
+        let mut ranks = std::collections::HashMap::new();
+        let names = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        for name in names {
+            let rank = ranks.len();
+            ranks.entry(name.to_string()).or_insert(rank);
+        }
+        SpecOrder(ranks)
+    }
+
+    /// Position of `name` in the file; unlisted specs rank after every listed one.
+    pub(crate) fn rank(&self, name: &str) -> usize {
+        self.0.get(name).copied().unwrap_or(usize::MAX)
+    }
+
+    /// Rank for a spec file path (by its extracted name).
+    pub(crate) fn rank_path(&self, path: &Path) -> usize {
+        path.file_name()
+            .and_then(|f| f.to_str())
+            .and_then(extract_spec_name)
+            .map_or(usize::MAX, |name| self.rank(name))
+    }
+}
+
 /// Extract spec name from a filename like `2025-02-17-09-36-hello-world.md`
 pub(crate) fn extract_spec_name(filename: &str) -> Option<&str> {
     if filename.len() > TIMESTAMP_PREFIX_LEN + 3 && filename.ends_with(".md") {
@@ -73,9 +182,19 @@ pub(crate) fn extract_spec_name(filename: &str) -> Option<&str> {
     }
 }
 
-/// Collect all spec .md file paths from `.specs/` and its immediate subdirectories.
+/// Fail early with a clear message when `.specs` exists but is not a directory.
+fn ensure_specs_dir_is_dir(dir: &Path) -> Result<(), String> {
+    if dir.exists() && !dir.is_dir() {
+        return Err(format!("{} exists but is not a directory", dir.display()));
+    }
+    Ok(())
+}
+
+/// Collect all spec .md file paths from `.specs/` and its immediate subdirectories,
+/// in `sort_spec_paths` order, the same on every platform.
 pub(crate) fn collect_spec_files() -> Result<Vec<PathBuf>, String> {
     let dir = specs_dir();
+    ensure_specs_dir_is_dir(&dir)?;
     if !dir.exists() {
         return Ok(Vec::new());
     }
@@ -88,38 +207,137 @@ pub(crate) fn collect_spec_files() -> Result<Vec<PathBuf>, String> {
         let entry = entry.map_err(|e| format!("Failed to read directory entry: {e}"))?;
         let path = entry.path();
         if path.is_dir() {
-            // Skip the templates and archive directories
-            if path
-                .file_name()
-                .is_some_and(|n| n == "templates" || n == "archive")
-            {
+            // Skip the templates and archive directories, and hidden ones like .trash
+            if path.file_name().is_some_and(|n| {
+                n == "templates" || n == "archive" || n.to_string_lossy().starts_with('.')
+            }) {
                 continue;
             }
             // One level of subdirectories
             if let Ok(sub_entries) = fs::read_dir(&path) {
                 for sub_entry in sub_entries.flatten() {
                     let sub_path = sub_entry.path();
-                    if sub_path.extension().is_some_and(|ext| ext == "md") {
+                    if is_spec_file(&sub_path) {
                         files.push(sub_path);
                     }
                 }
             }
-        } else if path.extension().is_some_and(|ext| ext == "md") {
+        } else if is_spec_file(&path) {
             files.push(path);
         }
     }
 
+    sort_spec_paths(&mut files, &dir);
     Ok(files)
 }
 
-/// Find the spec file matching the given name (exact match on the name portion).
-/// Searches `.specs/` and its immediate subdirectories.
-pub(crate) fn find_spec(name: &str) -> Result<PathBuf, String> {
+/// Sort spec paths under `root`: ungrouped specs first, then each group in name
+/// order, with each one's specs by file name (so by timestamp). Sorting on the
+/// group explicitly keeps a group like `0-legacy` after the ungrouped specs.
+pub(crate) fn sort_spec_paths(files: &mut [PathBuf], root: &Path) {
+    files.sort_by_cached_key(|path| {
+        let rel = path.strip_prefix(root).unwrap_or(path);
+        let group = rel
+            .parent()
+            .filter(|group| !group.as_os_str().is_empty())
+            .map(Path::to_path_buf);
+        (group, rel.file_name().map(ToOwned::to_owned))
+    });
+}
+
+/// Whether a path looks like a spec file: a `.md` file not starting with `_`.
+/// Underscore-prefixed files (e.g. `_index.md`) are reserved for tinyspec metadata.
+pub(crate) fn is_spec_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "md")
+        && !path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .is_some_and(|f| f.starts_with('_'))
+}
+
+/// Read a spec file, with a clear error when it isn't valid UTF-8.
+pub(crate) fn read_spec(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| {
+        if e.kind() == io::ErrorKind::InvalidData {
+            format!("{} is not valid UTF-8", path.display())
+        } else {
+            format!("Failed to read {}: {e}", path.display())
+        }
+    })
+}
+
+/// Read a spec file during a batch operation: on failure, print a warning
+/// (the error names the file) and return `None` so the caller can skip it.
+pub(crate) fn read_spec_or_warn(path: &Path) -> Option<String> {
+    read_spec(path)
+        .map_err(|e| report_warning(&format!("{e}; skipping it")))
+        .ok()
+}
+
+/// Create `dir` for a spec file to be written into, first making sure it really
+/// lies inside `root`: no `..` or absolute components, and (once it exists) no
+/// symlink leading out of it.
+pub(crate) fn create_dir_within(dir: &Path, root: &Path) -> Result<(), String> {
+    let outside = || {
+        format!(
+            "Refusing to write outside the specs directory: {}",
+            dir.display()
+        )
+    };
+    let relative = dir.strip_prefix(root).map_err(|_| outside())?;
+    if !relative
+        .components()
+        .all(|c| matches!(c, std::path::Component::Normal(_)))
+    {
+        return Err(outside());
+    }
+
+    fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create {} directory: {e}", dir.display()))?;
+
+    let real_root = root
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {e}", root.display()))?;
+    let real_dir = dir
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {e}", dir.display()))?;
+    if !real_dir.starts_with(&real_root) {
+        return Err(outside());
+    }
+    Ok(())
+}
+
+/// Remove a group directory left empty after a spec moved out of it.
+/// Never removes `root` itself or the `templates` directory.
+pub(crate) fn remove_empty_group_dir(dir: &Path, root: &Path) {
+    if dir == root || dir.file_name().is_some_and(|name| name == "templates") {
+        return;
+    }
+    if fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_none()) {
+        fs::remove_dir(dir).ok();
+    }
+}
+
+/// Find the spec file matching the given name (exact match on the name portion),
+/// searching `.specs/` and its immediate subdirectories. A group-qualified name
+/// (`v1/my-feature`, as accepted by `new`) only matches inside that group.
+pub(crate) fn find_spec(input: &str) -> Result<PathBuf, String> {
     let dir = specs_dir();
+    ensure_specs_dir_is_dir(&dir)?;
     if !dir.exists() {
-        return Err("No .specs/ directory found".into());
+        return Err(with_hint(
+            "No .specs/ directory found",
+            "run `tinyspec init` to set up this project, or cd into one that has a .specs directory",
+        ));
     }
 
+    let (group, name) = if input.contains('/') {
+        parse_spec_input(input)?
+    } else {
+        (None, input)
+    };
+    let group_dir = group.map(|g| dir.join(g));
+
     let files = collect_spec_files()?;
     let mut matches: Vec<PathBuf> = files
         .into_iter()
@@ -129,28 +347,98 @@ pub(crate) fn find_spec(name: &str) -> Result<PathBuf, String> {
                 .and_then(|f| extract_spec_name(f))
                 == Some(name)
         })
+        .filter(|path| {
+            group_dir
+                .as_ref()
+                .is_none_or(|g| path.parent() == Some(g.as_path()))
+        })
         .collect();
 
     match matches.len() {
-        0 => Err(format!("No spec found matching '{name}'")),
+        0 => Err(with_hint(
+            format!("No spec found matching '{input}'"),
+            "run `tinyspec list` to see spec names",
+        )),
         1 => Ok(matches.into_iter().next().unwrap()),
         _ => {
-            // Multiple files with same name but different timestamps — use the most recent
-            matches.sort();
+            // Multiple files with same name but different timestamps — use the most
+            // recent (by filename, so a copy in another group can't skew the order)
+            matches.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
             Ok(matches.into_iter().last().unwrap())
         }
     }
 }
 
-/// Provide spec name completions for shell tab completion.
+/// The spec name without any `group/` qualifier (`v1/my-feature` → `my-feature`).
+pub(crate) fn bare_spec_name(input: &str) -> &str {
+    input.rsplit_once('/').map_or(input, |(_, name)| name)
+}
+
+/// Resolve a spec name argument, reading the name from stdin when given as `-`.
+pub fn read_spec_name_arg(input: &str) -> Result<String, String> {
+    if input != "-" {
+        return Ok(input.to_string());
+    }
+
+    let mut line = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|e| format!("Failed to read spec name from stdin: {e}"))?;
+
+    let name = line.trim();
+    if name.is_empty() {
+        return Err("No spec name provided on stdin".into());
+    }
+    Ok(name.to_string())
+}
+
+/// Read a spec body for `new --body-from`: from stdin for `-`, else from a file.
+pub fn read_body_arg(source: &str) -> Result<String, String> {
+    let body = if source == "-" {
+        io::read_to_string(io::stdin()).map_err(|e| format!("Failed to read stdin: {e}"))?
+    } else {
+        fs::read_to_string(source).map_err(|e| format!("Failed to read '{source}': {e}"))?
+    };
+    if body.trim().is_empty() {
+        return Err(format!(
+            "No spec body provided{}",
+            if source == "-" { " on stdin" } else { "" }
+        ));
+    }
+    Ok(body)
+}
+
+/// Provide spec name completions for shell tab completion: spec names, then
+/// group folders as `group/`; after a `/`, the qualified names in that group.
 pub fn complete_spec_names(current: &std::ffi::OsStr) -> Vec<CompletionCandidate> {
     let current = current.to_string_lossy();
 
     let Ok(files) = collect_spec_files() else {
         return Vec::new();
     };
+    let root = specs_dir();
+    let group_of = |path: &Path| {
+        path.parent()
+            .filter(|parent| *parent != root)
+            .and_then(|parent| parent.file_name())
+            .and_then(|g| g.to_str())
+            .map(String::from)
+    };
+
+    // `group/prefix` completes to qualified names within that group
+    if let Some((group, prefix)) = current.split_once('/') {
+        return files
+            .iter()
+            .filter(|path| group_of(path).as_deref() == Some(group))
+            .filter_map(|path| path.file_name().and_then(|f| f.to_str()))
+            .filter_map(extract_spec_name)
+            .filter(|name| name.starts_with(prefix))
+            .map(|name| CompletionCandidate::new(format!("{group}/{name}")))
+            .collect();
+    }
 
-    files
+    let names = files
         .iter()
         .filter_map(|path| {
             path.file_name()
@@ -159,8 +447,34 @@ pub fn complete_spec_names(current: &std::ffi::OsStr) -> Vec<CompletionCandidate
                 .map(|name| name.to_string())
         })
         .filter(|name| name.starts_with(current.as_ref()))
-        .map(CompletionCandidate::new)
-        .collect()
+        .map(CompletionCandidate::new);
+    let groups = complete_group_names(std::ffi::OsStr::new(current.as_ref()))
+        .into_iter()
+        .map(|group| {
+            let name = group.get_value().to_string_lossy().into_owned();
+            CompletionCandidate::new(format!("{name}/")).help(Some("group".into()))
+        });
+    names.chain(groups).collect()
+}
+
+/// Shell completion for existing group folders (excluding `templates`, `archive`,
+/// and hidden folders).
+pub fn complete_group_names(current: &std::ffi::OsStr) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+
+    let Ok(entries) = fs::read_dir(specs_dir()) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(String::from))
+        .filter(|name| name != "templates" && name != "archive" && !name.starts_with('.'))
+        .filter(|name| name.starts_with(current.as_ref()))
+        .collect();
+    names.sort();
+    names.into_iter().map(CompletionCandidate::new).collect()
 }
 
 // ---------------------------------------------------------------------------
@@ -201,10 +515,62 @@ pub(crate) struct FrontMatter {
 }
 
 pub(crate) fn parse_front_matter(content: &str) -> Option<FrontMatter> {
-    let content = content.strip_prefix("---\n")?;
-    let end = content.find("\n---")?;
-    let yaml = &content[..end];
-    serde_yaml::from_str(yaml).ok()
+    serde_yaml::from_str(front_matter_yaml(content)?).ok()
+}
+
+/// Index of the front matter's closing `---` line, if the spec has front matter.
+pub(crate) fn front_matter_close<S: AsRef<str>>(lines: &[S]) -> Option<usize> {
+    (lines.first()?.as_ref() == "---")
+        .then(|| lines.iter().skip(1).position(|l| l.as_ref() == "---"))
+        .flatten()
+        .map(|i| i + 1)
+}
+
+/// Byte offsets of a spec's front matter, found by `front_matter_close`: the
+/// YAML between the fences, and the end of the closing `---` line (newline included).
+pub(crate) fn front_matter_bounds(content: &str) -> Option<(Range<usize>, usize)> {
+    let close = front_matter_close(&content.lines().collect::<Vec<_>>())?;
+    let line_ends: Vec<usize> = content
+        .split_inclusive('\n')
+        .scan(0, |pos, line| {
+            *pos += line.len();
+            Some(*pos)
+        })
+        .collect();
+    Some((line_ends[0]..line_ends[close - 1], line_ends[close]))
+}
+
+/// The YAML between a spec's leading `---` fences, if it has front matter.
+pub(crate) fn front_matter_yaml(content: &str) -> Option<&str> {
+    front_matter_bounds(content).map(|(yaml, _)| &content[yaml])
+}
+
+/// Replace the front matter's `title:` line (adding one after `tinySpec:` if
+/// missing), leaving every other line untouched.
+pub(crate) fn set_title(content: &str, title: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let close =
+        front_matter_close(&lines).ok_or("Cannot set the title: the spec has no front matter")?;
+
+    // Let YAML decide on quoting, e.g. for titles containing `: ` or `#`
+    let value = serde_yaml::to_string(title).map_err(|e| format!("Invalid title: {e}"))?;
+    let line = format!("title: {}", value.trim_end());
+    match lines[1..close].iter().position(|l| l.starts_with("title:")) {
+        Some(i) => lines[i + 1] = &line,
+        None => {
+            let at = lines[1..close]
+                .iter()
+                .position(|l| l.starts_with("tinySpec:"))
+                .map_or(1, |i| i + 2);
+            lines.insert(at, &line);
+        }
+    }
+
+    let mut output = lines.join("\n");
+    if content.ends_with('\n') {
+        output.push('\n');
+    }
+    Ok(output)
 }
 
 // ---------------------------------------------------------------------------
@@ -233,6 +599,15 @@ pub(crate) fn validate_kebab_case(name: &str) -> Result<(), String> {
     Ok(())
 }
 
+pub(crate) fn validate_group_name(group: &str) -> Result<(), String> {
+    validate_kebab_case(group).map_err(|_| {
+        format!(
+            "Invalid group name '{group}'. Group names must be kebab-case \
+             (lowercase letters, numbers, and single hyphens)."
+        )
+    })
+}
+
 /// Parse a spec input that may include a group prefix (e.g. `v1/feature`).
 /// Returns (group, name) where group is None for ungrouped specs.
 /// Only single-level grouping is supported.
@@ -244,12 +619,7 @@ pub(crate) fn parse_spec_input(input: &str) -> Result<(Option<&str>, &str), Stri
                     .into(),
             );
         }
-        validate_kebab_case(group).map_err(|_| {
-            format!(
-                "Invalid group name '{group}'. Group names must be kebab-case \
-                 (lowercase letters, numbers, and single hyphens)."
-            )
-        })?;
+        validate_group_name(group)?;
         validate_kebab_case(name)?;
         Ok((Some(group), name))
     } else {
@@ -257,3 +627,45 @@ pub(crate) fn parse_spec_input(input: &str) -> Result<(Option<&str>, &str), Stri
         Ok((None, input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_title_rewrites_only_the_title_line() {
+        let content = "---\ntinySpec: v0\ntitle: Old\ntags: [a]\n---\n\n# Background\n";
+        assert_eq!(
+            set_title(content, "New: improved").unwrap(),
+            "---\ntinySpec: v0\ntitle: 'New: improved'\ntags: [a]\n---\n\n# Background\n"
+        );
+        assert_eq!(
+            set_title("---\ntinySpec: v0\n---\n", "Added").unwrap(),
+            "---\ntinySpec: v0\ntitle: Added\n---\n"
+        );
+        assert!(set_title("# No front matter\n", "X").is_err());
+    }
+
+    #[test]
+    fn front_matter_ends_only_at_a_bare_fence_line() {
+        let content = "---\ntitle: X\nnote: |\n  ----\n  ---x\n---\n# Body\n";
+        assert_eq!(
+            front_matter_yaml(content),
+            Some("title: X\nnote: |\n  ----\n  ---x\n")
+        );
+        assert_eq!(
+            format::split_front_matter(content),
+            (
+                Some("---\ntitle: X\nnote: |\n  ----\n  ---x\n---\n"),
+                "# Body\n"
+            )
+        );
+
+        // Closing fence at the very end, or never closed
+        assert_eq!(
+            format::split_front_matter("---\ntitle: X\n---"),
+            (Some("---\ntitle: X\n---"), "")
+        );
+        assert_eq!(front_matter_yaml("---\ntitle: X\n---x\n"), None);
+    }
+}
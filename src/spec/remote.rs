@@ -0,0 +1,198 @@
+// Downloads shell out to `curl` and `git`, so no network stack is linked in;
+// the `remote-templates` feature decides whether `templates add`/`sync` exist.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::config::load_config;
+use super::templates::user_templates_dir;
+
+/// Fetch templates from `source` into `~/.config/tinyspec/templates/`.
+/// A `.md` URL is saved as a single template (named after the file unless
+/// `name` is given); a git source contributes every template it contains.
+pub fn template_add(source: &str, name: Option<&str>, force: bool) -> Result<(), String> {
+    let added = fetch_source(source, name, force)?;
+    for name in &added {
+        println!("Added template '{name}' from {source}");
+    }
+    Ok(())
+}
+
+/// Re-fetch every source listed under `template_sources` in the user config,
+/// overwriting previously fetched copies.
+pub fn template_sync() -> Result<(), String> {
+    let sources = load_config()?.template_sources;
+    if sources.is_empty() {
+        println!("No template_sources configured.");
+        return Ok(());
+    }
+
+    let mut count = 0;
+    for source in &sources {
+        let added = fetch_source(source, None, true)?;
+        count += added.len();
+    }
+    println!(
+        "Synced {count} template(s) from {} source(s).",
+        sources.len()
+    );
+    Ok(())
+}
+
+/// Fetch one source, returning the names of the templates written.
+fn fetch_source(source: &str, name: Option<&str>, force: bool) -> Result<Vec<String>, String> {
+    let dest_dir = user_templates_dir()?;
+    fs::create_dir_all(&dest_dir)
+        .map_err(|e| format!("Failed to create {}: {e}", dest_dir.display()))?;
+
+    if is_git_source(source) {
+        if name.is_some() {
+            return Err("--name only applies to single-file template URLs".into());
+        }
+        fetch_git(source, &dest_dir, force)
+    } else if source.starts_with("http://") {
+        Err(format!(
+            "Refusing to fetch '{source}' over plain http://. Use an https:// URL"
+        ))
+    } else if ["https://", "file://"]
+        .iter()
+        .any(|scheme| source.starts_with(scheme))
+    {
+        fetch_file(source, name, &dest_dir, force).map(|name| vec![name])
+    } else {
+        Err(format!(
+            "Unsupported template source '{source}'. Use an https:// URL to a .md file or a git repository"
+        ))
+    }
+}
+
+/// `git@host:repo`, `git://`, `ssh://`, `git+<url>`, or any URL ending in `.git`.
+fn is_git_source(source: &str) -> bool {
+    source.starts_with("git@")
+        || source.starts_with("git://")
+        || source.starts_with("ssh://")
+        || source.starts_with("git+")
+        || source.trim_end_matches('/').ends_with(".git")
+}
+
+fn fetch_file(
+    url: &str,
+    name: Option<&str>,
+    dest_dir: &Path,
+    force: bool,
+) -> Result<String, String> {
+    let file = url
+        .split(['?', '#'])
+        .next()
+        .and_then(|u| u.rsplit('/').next())
+        .unwrap_or_default();
+    let name = match name {
+        Some(name) => name.strip_suffix(".md").unwrap_or(name),
+        None => file
+            .strip_suffix(".md")
+            .ok_or_else(|| format!("Template URL must point to a .md file (got '{url}')"))?,
+    };
+    let dest = destination(dest_dir, name, force)?;
+
+    let partial = dest.with_extension("md.part");
+    let output = Command::new("curl")
+        .args(["-fsSL", "-o"])
+        .arg(&partial)
+        .arg(url)
+        .output()
+        .map_err(|e| format!("Failed to run curl: {e}"))?;
+    if !output.status.success() {
+        fs::remove_file(&partial).ok();
+        return Err(format!(
+            "Failed to download {url}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    fs::rename(&partial, &dest).map_err(|e| format!("Failed to save template: {e}"))?;
+    Ok(name.to_string())
+}
+
+/// Shallow-clone the repository and copy its templates: the `.md` files in a
+/// top-level `templates/` directory if there is one, else at the root
+/// (README excluded).
+fn fetch_git(source: &str, dest_dir: &Path, force: bool) -> Result<Vec<String>, String> {
+    let url = source.strip_prefix("git+").unwrap_or(source);
+    let checkout = std::env::temp_dir().join(format!("tinyspec-templates-{}", std::process::id()));
+    fs::remove_dir_all(&checkout).ok();
+
+    let output = Command::new("git")
+        .args(["clone", "--quiet", "--depth", "1", url])
+        .arg(&checkout)
+        .output()
+        .map_err(|e| format!("Failed to run git: {e}"))?;
+    if !output.status.success() {
+        fs::remove_dir_all(&checkout).ok();
+        return Err(format!(
+            "Failed to clone {url}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let result = copy_templates(&checkout, dest_dir, force);
+    fs::remove_dir_all(&checkout).ok();
+    let added = result?;
+    if added.is_empty() {
+        return Err(format!("No .md templates found in {url}"));
+    }
+    Ok(added)
+}
+
+fn copy_templates(checkout: &Path, dest_dir: &Path, force: bool) -> Result<Vec<String>, String> {
+    let nested = checkout.join("templates");
+    let src_dir = if nested.is_dir() {
+        nested
+    } else {
+        checkout.to_path_buf()
+    };
+
+    let mut files: Vec<PathBuf> = fs::read_dir(&src_dir)
+        .map_err(|e| format!("Failed to read {}: {e}", src_dir.display()))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+        .filter(|path| {
+            !path
+                .file_stem()
+                .is_some_and(|stem| stem.eq_ignore_ascii_case("readme"))
+        })
+        .collect();
+    files.sort();
+
+    // Check every destination first so a conflict doesn't leave a partial copy
+    let mut copies = Vec::new();
+    for file in files {
+        let Some(name) = file.file_stem().and_then(|s| s.to_str()).map(String::from) else {
+            continue;
+        };
+        let dest = destination(dest_dir, &name, force)?;
+        copies.push((file, dest, name));
+    }
+
+    let mut added = Vec::new();
+    for (file, dest, name) in copies {
+        fs::copy(&file, &dest).map_err(|e| format!("Failed to copy template '{name}': {e}"))?;
+        added.push(name);
+    }
+    Ok(added)
+}
+
+/// Path for template `name` in `dest_dir`, refusing to overwrite unless `force`.
+fn destination(dest_dir: &Path, name: &str, force: bool) -> Result<PathBuf, String> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return Err(format!("Invalid template name '{name}'"));
+    }
+    let dest = dest_dir.join(format!("{name}.md"));
+    if dest.exists() && !force {
+        return Err(format!(
+            "Template '{name}' already exists at {}. Use --force to overwrite",
+            dest.display()
+        ));
+    }
+    Ok(dest)
+}
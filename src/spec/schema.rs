@@ -0,0 +1,203 @@
+use serde_json::{Value, json};
+
+use super::migrate::LATEST_VERSION;
+
+/// The shape of a front-matter value.
+#[derive(Clone, Copy)]
+enum Kind {
+    String,
+    StringList,
+    OneOf(&'static [&'static str]),
+}
+
+/// Every front-matter key tinyspec understands. `schema` and `lint` both read
+/// this table, so the printed schema and the validation can't drift apart.
+const FIELDS: &[(&str, Kind, &str)] = &[
+    (
+        "tinySpec",
+        Kind::OneOf(&[LATEST_VERSION]),
+        "Spec format version",
+    ),
+    ("title", Kind::String, "Human-readable spec title"),
+    (
+        "applications",
+        Kind::StringList,
+        "Repository names resolved to folders via `tinyspec config`",
+    ),
+    (
+        "priority",
+        Kind::OneOf(&["high", "medium", "low"]),
+        "Ordering hint for list and dashboard (default medium)",
+    ),
+    (
+        "tags",
+        Kind::StringList,
+        "Free-form labels for `list --tag`",
+    ),
+    (
+        "depends_on",
+        Kind::StringList,
+        "Names of specs that must be completed first",
+    ),
+    (
+        "status",
+        Kind::String,
+        "Free-form workflow status; `reset` sets it to `draft`",
+    ),
+];
+
+const REQUIRED: &[&str] = &["tinySpec"];
+
+/// Print the JSON Schema for spec front matter.
+pub fn schema() -> Result<(), String> {
+    let out = serde_json::to_string_pretty(&front_matter_schema())
+        .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+    println!("{out}");
+    Ok(())
+}
+
+fn front_matter_schema() -> Value {
+    let properties: serde_json::Map<String, Value> = FIELDS
+        .iter()
+        .map(|&(key, kind, description)| {
+            let mut property = match kind {
+                Kind::String => json!({ "type": "string" }),
+                Kind::StringList => json!({ "type": "array", "items": { "type": "string" } }),
+                Kind::OneOf(values) => json!({ "type": "string", "enum": values }),
+            };
+            property["description"] = json!(description);
+            (key.to_string(), property)
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "tinyspec front matter",
+        "type": "object",
+        "properties": properties,
+        "required": REQUIRED,
+        // Templates may add their own keys; `lint` only warns about them
+        "additionalProperties": true,
+    })
+}
+
+/// A front-matter problem found by `validate_front_matter`.
+pub(crate) enum Violation {
+    /// Malformed YAML or a value of the wrong type
+    Invalid(String),
+    /// A key the schema doesn't define (templates may add their own)
+    Unknown(String),
+    /// A required key is absent
+    Missing(&'static str),
+}
+
+/// Check the YAML between the `---` fences against the front-matter schema.
+pub(crate) fn validate_front_matter(yaml: &str) -> Vec<Violation> {
+    let value: serde_yaml::Value = match serde_yaml::from_str(yaml) {
+        Ok(value) => value,
+        Err(e) => {
+            return vec![Violation::Invalid(format!(
+                "Front matter is not valid YAML: {e}"
+            ))];
+        }
+    };
+    let Some(map) = value.as_mapping() else {
+        return vec![Violation::Invalid(
+            "Front matter must be a mapping of keys to values".into(),
+        )];
+    };
+
+    let mut violations = Vec::new();
+    for (key, value) in map {
+        let key = key
+            .as_str()
+            .map_or_else(|| format!("{key:?}"), String::from);
+        let Some(&(_, kind, _)) = FIELDS.iter().find(|(name, _, _)| *name == key) else {
+            violations.push(Violation::Unknown(format!(
+                "Unknown front matter key '{key}'"
+            )));
+            continue;
+        };
+        if let Some(problem) = check_kind(kind, value) {
+            violations.push(Violation::Invalid(format!(
+                "Front matter '{key}' {problem}"
+            )));
+        }
+    }
+    for &key in REQUIRED {
+        if !map.contains_key(key) {
+            violations.push(Violation::Missing(key));
+        }
+    }
+    violations
+}
+
+fn check_kind(kind: Kind, value: &serde_yaml::Value) -> Option<String> {
+    match kind {
+        Kind::String if value.is_string() || value.is_null() => None,
+        Kind::String => Some("must be a string".into()),
+        Kind::StringList => match value.as_sequence() {
+            Some(items) if items.iter().all(|v| v.is_string() || v.is_null()) => None,
+            _ => Some("must be a list of strings".into()),
+        },
+        Kind::OneOf(values) => match value.as_str() {
+            Some(s) if values.contains(&s) => None,
+            _ => Some(format!("must be one of: {}", values.join(", "))),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::{self, Deserialize, Deserializer, Visitor};
+
+    use super::*;
+    use crate::spec::FrontMatter;
+
+    /// A deserializer that only records the field names a struct asks for.
+    struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for FieldNames<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("expected a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            fields: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("fields recorded"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    #[test]
+    fn schema_covers_every_front_matter_field() {
+        let mut fields: &[&str] = &[];
+        assert!(FrontMatter::deserialize(FieldNames(&mut fields)).is_err());
+        assert!(!fields.is_empty());
+        for field in fields {
+            assert!(
+                FIELDS.iter().any(|(key, _, _)| key == field),
+                "front matter field '{field}' is missing from the schema"
+            );
+        }
+    }
+
+    #[test]
+    fn schema_allows_keys_lint_only_warns_about() {
+        let violations = validate_front_matter("tinySpec: v0\nteam: payments\n");
+        assert!(matches!(violations.as_slice(), [Violation::Unknown(_)]));
+        assert_eq!(front_matter_schema()["additionalProperties"], json!(true));
+    }
+}
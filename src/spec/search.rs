@@ -1,24 +1,44 @@
-use super::summary::{SpecStatus, load_spec_summary};
-use super::{collect_spec_files, extract_spec_name, parse_front_matter, specs_dir};
-use std::fs;
-
+use super::color::color_enabled;
+use super::summary::{CodeFence, SpecStatus, load_spec_summary};
+use super::{
+    collect_spec_files, extract_spec_name, no_specs_message, parse_front_matter, read_spec_or_warn,
+    specs_dir,
+};
+use crossterm::style::Stylize;
+use regex::{Regex, RegexBuilder};
+use std::io::{self, IsTerminal};
+
+/// Search spec titles and bodies, case-insensitively. Each matching line is
+/// printed under the heading of the section it's in, with the match
+/// highlighted when colour is on. `query` is a literal unless `regex` is set;
+/// `title_only` skips the body.
 pub fn search(
     query: &str,
     group_filter: Option<&str>,
     status_filter: Option<&str>,
+    title_only: bool,
+    regex: bool,
 ) -> Result<(), String> {
-    let mut files = collect_spec_files()?;
+    let pattern = if regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    let matcher = RegexBuilder::new(&pattern)
+        .case_insensitive(true)
+        .build()
+        .map_err(|e| format!("Invalid regex '{query}': {e}"))?;
+    let color = color_enabled(io::stdout().is_terminal());
+
+    // Already sorted by group, then file name
+    let files = collect_spec_files()?;
 
     if files.is_empty() {
-        println!("No specs found.");
+        println!("{}", no_specs_message());
         return Ok(());
     }
 
-    // Sort for consistent output
-    files.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
-
     let specs_root = specs_dir();
-    let query_lower = query.to_lowercase();
 
     let mut found_any = false;
 
@@ -55,9 +75,8 @@ pub fn search(
             }
         }
 
-        let content = match fs::read_to_string(path) {
-            Ok(c) => c,
-            Err(_) => continue,
+        let Some(content) = read_spec_or_warn(path) else {
+            continue;
         };
 
         let filename = path
@@ -67,12 +86,17 @@ pub fn search(
             .to_string();
         let spec_name = extract_spec_name(&filename).unwrap_or(&filename);
 
-        // Collect matching lines (skip front matter delimiter lines)
-        let mut matching_lines: Vec<(usize, &str)> = Vec::new();
+        // Collect matching lines with their section heading (skipping front matter)
+        let mut matching_lines: Vec<(usize, &str, &str)> = Vec::new();
         let mut in_front_matter = false;
         let mut front_matter_count = 0;
+        let mut fence = CodeFence::default();
+        let mut section = "";
 
         for (i, line) in content.lines().enumerate() {
+            if title_only {
+                break;
+            }
             if line.trim() == "---" {
                 front_matter_count += 1;
                 if front_matter_count <= 2 {
@@ -84,8 +108,11 @@ pub fn search(
                 continue;
             }
 
-            if line.to_lowercase().contains(&query_lower) {
-                matching_lines.push((i + 1, line));
+            if !fence.skip(line) && line.starts_with('#') {
+                section = line.trim_start_matches('#').trim();
+            }
+            if matcher.is_match(line) {
+                matching_lines.push((i + 1, section, line));
             }
         }
 
@@ -94,7 +121,7 @@ pub fn search(
             .and_then(|fm| fm.title)
             .unwrap_or_default();
 
-        if matching_lines.is_empty() && !title.to_lowercase().contains(&query_lower) {
+        if matching_lines.is_empty() && !matcher.is_match(&title) {
             continue;
         }
 
@@ -115,18 +142,27 @@ pub fn search(
             }
         };
 
-        println!("{display_name}  {title}");
+        println!("{display_name}  {}", highlight(&title, &matcher, color));
 
-        // Print matching lines as snippets
-        for (line_num, line) in &matching_lines {
+        // Print matching lines as snippets, under their section heading
+        let mut current_section = None;
+        for (line_num, section, line) in &matching_lines {
+            if current_section != Some(section) {
+                if !section.is_empty() {
+                    println!("  # {section}");
+                }
+                current_section = Some(section);
+            }
             let trimmed = line.trim();
             // Truncate long lines
-            let snippet = if trimmed.len() > 120 {
-                &trimmed[..120]
-            } else {
-                trimmed
+            let snippet = match trimmed.char_indices().nth(120) {
+                Some((end, _)) => &trimmed[..end],
+                None => trimmed,
             };
-            println!("  line {line_num}: {snippet}");
+            println!(
+                "    line {line_num}: {}",
+                highlight(snippet, &matcher, color)
+            );
         }
 
         println!();
@@ -138,3 +174,19 @@ pub fn search(
 
     Ok(())
 }
+
+/// Wrap each match of `matcher` in `text` in bold yellow when `color` is on.
+fn highlight(text: &str, matcher: &Regex, color: bool) -> String {
+    if !color {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut last = 0;
+    for m in matcher.find_iter(text).filter(|m| !m.is_empty()) {
+        out.push_str(&text[last..m.start()]);
+        out.push_str(&m.as_str().yellow().bold().to_string());
+        last = m.end();
+    }
+    out.push_str(&text[last..]);
+    out
+}
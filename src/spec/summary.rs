@@ -1,16 +1,27 @@
 use std::cmp::Ordering;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde::Serialize;
 
-use super::{Priority, collect_spec_files, extract_spec_name, parse_front_matter, specs_dir};
+use super::errors::report_warning;
+use super::{
+    FrontMatter, Priority, SpecOrder, collect_spec_files, extract_spec_name, parse_front_matter,
+    read_spec, specs_dir,
+};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct TaskNode {
     pub id: String,
     pub description: String,
     pub checked: bool,
+    /// Assignee from a leading `@owner` token in the description (kept there too).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Completion date from a trailing `(done YYYY-MM-DD)` annotation, which is
+    /// stripped from the description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done: Option<String>,
     pub children: Vec<TaskNode>,
 }
 
@@ -22,6 +33,15 @@ pub enum SpecStatus {
 }
 
 impl SpecStatus {
+    /// Human-readable label used in plain-text output.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SpecStatus::InProgress => "in progress",
+            SpecStatus::Pending => "pending",
+            SpecStatus::Completed => "completed",
+        }
+    }
+
     fn sort_key(&self) -> u8 {
         match self {
             SpecStatus::InProgress => 0,
@@ -46,6 +66,9 @@ impl PartialOrd for SpecStatus {
 #[derive(Debug, Clone, Serialize)]
 pub struct SpecSummary {
     pub name: String,
+    /// Location of the spec file; serialized relative to the specs root.
+    #[serde(serialize_with = "serialize_spec_path")]
+    pub path: PathBuf,
     pub title: String,
     pub group: Option<String>,
     pub timestamp: String, // "YYYY-MM-DD HH:MM"
@@ -56,12 +79,22 @@ pub struct SpecSummary {
     pub status: SpecStatus,
     pub priority: Priority,
     pub tags: Vec<String>,
+    pub applications: Vec<String>,
     pub depends_on: Vec<String>,
     pub blocked: bool,
     pub tasks: Vec<TaskNode>,
     pub test_tasks: Vec<TaskNode>,
 }
 
+fn serialize_spec_path<S: serde::Serializer>(
+    path: &Path,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let root = specs_dir();
+    let relative = path.strip_prefix(&root).unwrap_or(path);
+    serializer.serialize_str(&relative.to_string_lossy())
+}
+
 /// Extract a human-friendly timestamp from a spec filename.
 /// `"2026-02-17-21-27-dashboard.md"` → `"2026-02-17 21:27"`
 fn extract_timestamp(filename: &str) -> String {
@@ -73,110 +106,487 @@ fn extract_timestamp(filename: &str) -> String {
     }
 }
 
-/// Parse a specific headed section (e.g. `# Implementation Plan` or `# Test Plan`)
-/// into a task tree. Stops at the next top-level `#` heading.
-fn parse_section_tasks(content: &str, section_heading: &str) -> Vec<TaskNode> {
-    let mut in_section = false;
-    let mut tasks: Vec<TaskNode> = Vec::new();
+/// Split an ATX heading line into its level and text.
+/// `"## Implementation Plan"` → `Some((2, "Implementation Plan"))`
+pub(crate) fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim();
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((level, rest.trim()))
+}
 
-    for line in content.lines() {
-        let trimmed = line.trim();
+/// Tracks fenced code blocks (```` ``` ```` or `~~~`) while scanning a spec line
+/// by line, so checkboxes and headings documented inside them are ignored.
+#[derive(Default)]
+pub(crate) struct CodeFence {
+    open: Option<char>,
+}
 
-        if trimmed == section_heading {
-            in_section = true;
-            continue;
+impl CodeFence {
+    /// Feed the next line; true if it belongs to a fenced block (delimiters included).
+    pub(crate) fn skip(&mut self, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        let marker = ['`', '~']
+            .into_iter()
+            .find(|&c| trimmed.starts_with(&c.to_string().repeat(3)));
+        match (self.open, marker) {
+            (None, None) => false,
+            (None, Some(m)) => {
+                self.open = Some(m);
+                true
+            }
+            (Some(open), Some(m)) if open == m => {
+                self.open = None;
+                true
+            }
+            (Some(_), _) => true,
         }
+    }
+}
+
+/// Parse a task line `- [ ] ID: description` into `(indent, checked, id, description)`,
+/// where `indent` is the line's leading whitespace (see `indent_width`).
+/// The ID ends at the first colon, so descriptions may contain colons of their own.
+pub(crate) fn parse_task_line(line: &str) -> Option<(&str, bool, &str, &str)> {
+    let trimmed = line.trim();
+    let (is_checked, rest) = if let Some(rest) = trimmed.strip_prefix("- [x] ") {
+        (true, rest)
+    } else if let Some(rest) = trimmed.strip_prefix("- [ ] ") {
+        (false, rest)
+    } else {
+        return None;
+    };
 
-        // Stop at next top-level heading
-        if in_section && trimmed.starts_with("# ") {
-            break;
+    // Parse "ID: description"
+    let (id, description) = rest.split_once(':')?;
+
+    // Nesting is decided by leading whitespace on the original line
+    let indent = &line[..line.len() - line.trim_start().len()];
+    Some((indent, is_checked, id.trim(), description.trim()))
+}
+
+/// Columns of leading whitespace, with each tab advancing to the next multiple
+/// of `tab_width`, so tab- and space-indented tasks line up alike.
+pub(crate) fn indent_width(line: &str, tab_width: usize) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .fold(0, |col, c| match c {
+            '\t' => col + tab_width - col % tab_width,
+            _ => col + 1,
+        })
+}
+
+/// Tab stop used when nesting parsed tasks by indent width, as in Markdown.
+const TAB_STOP: usize = 4;
+
+/// Finds the task lines of one headed section (e.g. `Implementation Plan`),
+/// fed a line at a time. The heading may be at any level; the section ends at
+/// the next heading of the same or higher level. Headings and tasks inside
+/// code fences are ignored. Shared by the tree parser and the streaming counter
+/// so both read a spec the same way.
+struct SectionScanner {
+    title: &'static str,
+    fence: CodeFence,
+    level: Option<usize>,
+    finished: bool,
+}
+
+impl SectionScanner {
+    fn new(title: &'static str) -> Self {
+        Self {
+            title,
+            fence: CodeFence::default(),
+            level: None,
+            finished: false,
         }
+    }
 
-        if !in_section {
-            continue;
+    /// Feed the next line; the parsed task if it's a task line in the section.
+    fn task<'a>(&mut self, line: &'a str) -> Option<(&'a str, bool, &'a str, &'a str)> {
+        if self.finished || self.fence.skip(line) {
+            return None;
         }
+        if let Some((level, text)) = parse_heading(line) {
+            match self.level {
+                None if text == self.title => {
+                    self.level = Some(level);
+                    return None;
+                }
+                Some(current) if level <= current => {
+                    self.finished = true;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        self.level?;
+        parse_task_line(line)
+    }
+}
 
-        let (is_checked, rest) = if let Some(rest) = trimmed.strip_prefix("- [x] ") {
-            (true, rest)
-        } else if let Some(rest) = trimmed.strip_prefix("- [ ] ") {
-            (false, rest)
-        } else {
-            continue;
-        };
+/// Parse a specific headed section (e.g. `Implementation Plan` or `Test Plan`)
+/// into a task tree.
+fn parse_section_tasks(content: &str, section_title: &'static str) -> Vec<TaskNode> {
+    let mut scanner = SectionScanner::new(section_title);
+    let mut tasks: Vec<TaskNode> = Vec::new();
+    // Tasks whose subtasks may still follow, outermost first, with their indent widths
+    let mut open: Vec<(usize, TaskNode)> = Vec::new();
 
-        // Parse "ID: description"
-        let Some(colon_pos) = rest.find(':') else {
+    for line in content.lines() {
+        let Some((indent, is_checked, id, description)) = scanner.task(line) else {
+            if scanner.finished {
+                break;
+            }
             continue;
         };
-        let id = rest[..colon_pos].trim().to_string();
-        let description = rest[colon_pos + 1..].trim().to_string();
-
-        // Determine nesting by leading whitespace on the original line
-        let indent = line.len() - line.trim_start().len();
-
-        if indent == 0 {
-            tasks.push(TaskNode {
-                id,
-                description,
-                checked: is_checked,
-                children: Vec::new(),
-            });
-        } else if let Some(parent) = tasks.last_mut() {
-            parent.children.push(TaskNode {
-                id,
-                description,
-                checked: is_checked,
-                children: Vec::new(),
-            });
+        let (description, done) = split_done_annotation(description);
+        let node = TaskNode {
+            id: id.to_string(),
+            description: description.to_string(),
+            checked: is_checked,
+            owner: task_owner(description),
+            done: done.map(String::from),
+            children: Vec::new(),
+        };
+
+        // Close the open tasks indented at least as far as this one; it nests
+        // under whichever is left. Indented tasks with no parent are dropped.
+        let width = indent_width(indent, TAB_STOP);
+        while open.last().is_some_and(|(w, _)| *w >= width) {
+            close_task(&mut open, &mut tasks);
+        }
+        if width == 0 || !open.is_empty() {
+            open.push((width, node));
         }
     }
+    while !open.is_empty() {
+        close_task(&mut open, &mut tasks);
+    }
 
     tasks
 }
 
+/// Pop the innermost open task onto its parent's children, or onto `tasks`
+/// when it's top-level.
+fn close_task(open: &mut Vec<(usize, TaskNode)>, tasks: &mut Vec<TaskNode>) {
+    let Some((_, node)) = open.pop() else {
+        return;
+    };
+    match open.last_mut() {
+        Some((_, parent)) => parent.children.push(node),
+        None => tasks.push(node),
+    }
+}
+
+/// Every task in a tree with its depth (0 for top-level), parents before their
+/// subtasks, in plan order.
+pub fn flatten_tasks(tasks: &[TaskNode]) -> Vec<(&TaskNode, usize)> {
+    let mut flat = Vec::new();
+    let mut stack: Vec<(&TaskNode, usize)> = tasks.iter().rev().map(|t| (t, 0)).collect();
+    while let Some((task, depth)) = stack.pop() {
+        flat.push((task, depth));
+        stack.extend(task.children.iter().rev().map(|c| (c, depth + 1)));
+    }
+    flat
+}
+
+/// One headed section of a spec body.
+#[derive(Debug, Clone, Serialize)]
+pub struct Section {
+    pub title: String,
+    pub level: usize,
+    /// 1-based line of the heading in the spec file.
+    pub line: usize,
+    /// Text under the heading, nested subsections included, up to the next
+    /// heading of the same or higher level.
+    pub body: String,
+}
+
+impl Section {
+    /// The section as it appears in the file: heading line followed by body.
+    pub fn to_markdown(&self) -> String {
+        format!("{} {}\n{}", "#".repeat(self.level), self.title, self.body)
+    }
+
+    /// True if the body holds nothing but blank lines.
+    pub fn is_empty(&self) -> bool {
+        self.body.trim().is_empty()
+    }
+}
+
+/// A spec body split into its headed sections, in file order.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SpecDocument {
+    pub sections: Vec<Section>,
+}
+
+impl SpecDocument {
+    /// Parse every heading outside front matter and code fences. Text before
+    /// the first heading is not part of any section.
+    pub fn parse(content: &str) -> Self {
+        let (front_matter, body) = super::format::split_front_matter(content);
+        let offset = front_matter.map_or(0, |fm| fm.lines().count());
+
+        let mut sections: Vec<Section> = Vec::new();
+        // Sections still accepting body text, as indices into `sections`
+        let mut open: Vec<usize> = Vec::new();
+        let mut fence = CodeFence::default();
+
+        for (i, line) in body.lines().enumerate() {
+            let in_fence = fence.skip(line);
+            if let Some((level, text)) = parse_heading(line).filter(|_| !in_fence) {
+                open.retain(|&idx| sections[idx].level < level);
+                for &idx in &open {
+                    sections[idx].body.push_str(line);
+                    sections[idx].body.push('\n');
+                }
+                open.push(sections.len());
+                sections.push(Section {
+                    title: text.to_string(),
+                    level,
+                    line: offset + i + 1,
+                    body: String::new(),
+                });
+                continue;
+            }
+            for &idx in &open {
+                sections[idx].body.push_str(line);
+                sections[idx].body.push('\n');
+            }
+        }
+
+        SpecDocument { sections }
+    }
+
+    /// The first section with the given heading text, at any level.
+    pub fn section(&self, title: &str) -> Option<&Section> {
+        self.sections.iter().find(|s| s.title == title)
+    }
+}
+
+/// Extract the raw text of a headed section, heading line included, up to the
+/// next heading of the same or higher level.
+pub fn extract_section(content: &str, section_title: &str) -> Option<String> {
+    SpecDocument::parse(content)
+        .section(section_title)
+        .map(Section::to_markdown)
+}
+
 /// Parse the `# Implementation Plan` section into a task tree.
 pub fn parse_tasks_from_content(content: &str) -> Vec<TaskNode> {
-    parse_section_tasks(content, "# Implementation Plan")
+    parse_section_tasks(content, "Implementation Plan")
 }
 
 /// Parse the `# Test Plan` section into a task tree.
 pub fn parse_test_tasks_from_content(content: &str) -> Vec<TaskNode> {
-    parse_section_tasks(content, "# Test Plan")
+    parse_section_tasks(content, "Test Plan")
 }
 
 /// Count total and checked tasks (including all nesting levels).
 fn count_tasks(tasks: &[TaskNode]) -> (u32, u32) {
     let mut total = 0u32;
     let mut checked = 0u32;
-    for task in tasks {
+    for (task, _) in flatten_tasks(tasks) {
         total += 1;
         if task.checked {
             checked += 1;
         }
-        for child in &task.children {
-            total += 1;
-            if child.checked {
-                checked += 1;
+    }
+    (total, checked)
+}
+
+/// Count total and checked leaf tasks only (tasks with no children), so that
+/// organizational parent tasks are not counted alongside their subtasks.
+fn count_leaf_tasks(tasks: &[TaskNode]) -> (u32, u32) {
+    let mut total = 0u32;
+    let mut checked = 0u32;
+    for task in tasks {
+        let (t, c) = if task.children.is_empty() {
+            (1, u32::from(task.checked))
+        } else {
+            count_leaf_tasks(&task.children)
+        };
+        total += t;
+        checked += c;
+    }
+    (total, checked)
+}
+
+/// Derive a spec's status from its impl and test task counts.
+fn status_from_counts(
+    total: u32,
+    checked: u32,
+    total_tests: u32,
+    checked_tests: u32,
+) -> SpecStatus {
+    if total == 0 && total_tests == 0 {
+        SpecStatus::Pending
+    } else if checked == total && checked_tests == total_tests {
+        SpecStatus::Completed
+    } else if checked > 0 || checked_tests > 0 {
+        SpecStatus::InProgress
+    } else {
+        SpecStatus::Pending
+    }
+}
+
+/// The owner named by a leading `@owner` token, e.g. `@alice Write tests` → `alice`.
+pub fn task_owner(description: &str) -> Option<String> {
+    let token = description.split_whitespace().next()?.strip_prefix('@')?;
+    let owner = token.trim_end_matches([':', ',']);
+    (!owner.is_empty()).then(|| owner.to_string())
+}
+
+/// Split a trailing ` (done YYYY-MM-DD)` annotation, as written by
+/// `check --timestamp`, off a task description or line.
+pub(crate) fn split_done_annotation(text: &str) -> (&str, Option<&str>) {
+    let trimmed = text.trim_end();
+    if let Some(rest) = trimmed.strip_suffix(')')
+        && let Some((before, date)) = rest.rsplit_once("(done ")
+        && chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok()
+    {
+        return (before.trim_end(), Some(date));
+    }
+    (text, None)
+}
+
+/// Outcome recorded on a Test Plan task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestResult {
+    Pass,
+    Fail,
+}
+
+/// Parse the `result:` annotation a Test Plan task may carry at the end of its
+/// description, e.g. `- [ ] T.2: Rejects bad tokens (result: fail)`. The value
+/// is `pass` or `fail` (case-insensitive); parentheses/brackets are optional.
+pub fn test_result(description: &str) -> Option<TestResult> {
+    let lower = description.to_ascii_lowercase();
+    let value = lower.rsplit_once("result:")?.1;
+    let value = value.trim().trim_end_matches([')', ']']).trim();
+    match value {
+        "pass" => Some(TestResult::Pass),
+        "fail" => Some(TestResult::Fail),
+        _ => None,
+    }
+}
+
+/// The first unchecked top-level task in plan order, with its first unchecked
+/// subtask if it has one (followed down to the deepest level).
+fn next_in(tasks: &[TaskNode]) -> Option<(&TaskNode, Option<&TaskNode>)> {
+    let task = tasks.iter().find(|t| !t.checked)?;
+    let mut subtask = None;
+    let mut children = &task.children;
+    while let Some(child) = children.iter().find(|c| !c.checked) {
+        subtask = Some(child);
+        children = &child.children;
+    }
+    Some((task, subtask))
+}
+
+impl SpecSummary {
+    /// The next top-level task to work on and its next subtask: Implementation
+    /// Plan first, then Test Plan.
+    pub fn next_step(&self) -> Option<(&TaskNode, Option<&TaskNode>)> {
+        next_in(&self.tasks).or_else(|| next_in(&self.test_tasks))
+    }
+
+    /// The next actionable task: the next step's subtask, or the top-level
+    /// task itself when it has no pending subtasks.
+    pub fn next_task(&self) -> Option<&TaskNode> {
+        self.next_step()
+            .map(|(task, subtask)| subtask.unwrap_or(task))
+    }
+
+    /// The most recently completed task in plan order (subtasks before their parent,
+    /// Test Plan after Implementation Plan). Used to undo `check <spec> next`.
+    pub fn last_checked_task(&self) -> Option<&TaskNode> {
+        fn last_checked(tasks: &[TaskNode]) -> Option<&TaskNode> {
+            tasks
+                .iter()
+                .rev()
+                .find_map(|t| t.checked.then_some(t).or_else(|| last_checked(&t.children)))
+        }
+        last_checked(&self.test_tasks).or_else(|| last_checked(&self.tasks))
+    }
+
+    /// Status glyph shared by the dashboard and `list`: `✓` done, `◑` impl done
+    /// but tests pending, `●` in progress, `○` pending.
+    pub fn status_glyph(&self) -> &'static str {
+        let impl_done = self.total == 0 || self.checked == self.total;
+        let tests_done = self.total_tests == 0 || self.checked_tests == self.total_tests;
+        if impl_done && tests_done {
+            "✓"
+        } else if impl_done {
+            "◑"
+        } else {
+            match self.status {
+                SpecStatus::InProgress => "●",
+                SpecStatus::Pending => "○",
+                SpecStatus::Completed => "✓",
             }
         }
     }
-    (total, checked)
+
+    /// Completed tasks (impl and tests) as a whole percentage of all tasks.
+    pub fn percent_complete(&self) -> u32 {
+        percent(
+            self.checked + self.checked_tests,
+            self.total + self.total_tests,
+        )
+    }
+
+    /// Test Plan tasks (and subtasks) annotated `result: fail`, in plan order.
+    pub fn failing_tests(&self) -> Vec<&TaskNode> {
+        flatten_tasks(&self.test_tasks)
+            .into_iter()
+            .map(|(t, _)| t)
+            .filter(|t| test_result(&t.description) == Some(TestResult::Fail))
+            .collect()
+    }
+
+    /// Recompute counts and status using only leaf tasks.
+    pub fn count_leaves_only(&mut self) {
+        (self.total, self.checked) = count_leaf_tasks(&self.tasks);
+        (self.total_tests, self.checked_tests) = count_leaf_tasks(&self.test_tasks);
+        self.status = status_from_counts(
+            self.total,
+            self.checked,
+            self.total_tests,
+            self.checked_tests,
+        );
+    }
 }
 
-/// Load a single spec file into a SpecSummary.
-pub fn load_spec_summary(path: &Path) -> Option<SpecSummary> {
+/// Build a summary's metadata from its path and front matter, with zero counts
+/// and no task tree.
+fn empty_summary(path: &Path, fm: Option<FrontMatter>) -> Option<SpecSummary> {
     let filename = path.file_name()?.to_str()?;
     let name = extract_spec_name(filename)?.to_string();
     let timestamp = extract_timestamp(filename);
-    let content = fs::read_to_string(path).ok()?;
 
-    let fm = parse_front_matter(&content);
     let title = fm
         .as_ref()
         .and_then(|f| f.title.clone())
         .unwrap_or_else(|| name.clone());
     let priority = fm.as_ref().and_then(|f| f.priority).unwrap_or_default();
     let tags = fm.as_ref().map(|f| f.tags.clone()).unwrap_or_default();
+    let applications = fm
+        .as_ref()
+        .map(|f| {
+            f.applications
+                .iter()
+                .filter(|a| !a.is_empty())
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
     let depends_on = fm
         .as_ref()
         .map(|f| f.depends_on.clone())
@@ -195,41 +605,162 @@ pub fn load_spec_summary(path: &Path) -> Option<SpecSummary> {
         }
     };
 
-    let tasks = parse_tasks_from_content(&content);
-    let (total, checked) = count_tasks(&tasks);
-
-    let test_tasks = parse_test_tasks_from_content(&content);
-    let (total_tests, checked_tests) = count_tasks(&test_tasks);
-
-    let status = if total == 0 && total_tests == 0 {
-        SpecStatus::Pending
-    } else if checked == total && checked_tests == total_tests {
-        SpecStatus::Completed
-    } else if checked > 0 || checked_tests > 0 {
-        SpecStatus::InProgress
-    } else {
-        SpecStatus::Pending
-    };
-
     Some(SpecSummary {
         name,
+        path: path.to_path_buf(),
         title,
         group,
         timestamp,
-        total,
-        checked,
-        total_tests,
-        checked_tests,
-        status,
+        total: 0,
+        checked: 0,
+        total_tests: 0,
+        checked_tests: 0,
+        status: SpecStatus::Pending,
         priority,
         tags,
+        applications,
         depends_on,
         blocked: false, // resolved later by load_all_summaries
-        tasks,
-        test_tasks,
+        tasks: Vec::new(),
+        test_tasks: Vec::new(),
     })
 }
 
+/// Load a single spec file into a SpecSummary.
+pub fn load_spec_summary(path: &Path) -> Option<SpecSummary> {
+    read_spec_summary(path).ok().flatten()
+}
+
+/// Like `load_spec_summary`, but reports files that can't be read (e.g. not
+/// valid UTF-8) as errors. `Ok(None)` means the filename isn't a spec name.
+pub fn read_spec_summary(path: &Path) -> Result<Option<SpecSummary>, String> {
+    let content = read_spec(path)?;
+    let Some(mut summary) = empty_summary(path, parse_front_matter(&content)) else {
+        return Ok(None);
+    };
+
+    summary.tasks = parse_tasks_from_content(&content);
+    (summary.total, summary.checked) = count_tasks(&summary.tasks);
+
+    summary.test_tasks = parse_test_tasks_from_content(&content);
+    (summary.total_tests, summary.checked_tests) = count_tasks(&summary.test_tasks);
+
+    summary.status = status_from_counts(
+        summary.total,
+        summary.checked,
+        summary.total_tests,
+        summary.checked_tests,
+    );
+    Ok(Some(summary))
+}
+
+/// Running task counts for one headed section, fed a line at a time.
+/// Gives the same numbers as `parse_section_tasks` + `count_tasks` without
+/// building the tree.
+struct SectionCounter {
+    scanner: SectionScanner,
+    has_parent: bool,
+    total: u32,
+    checked: u32,
+}
+
+impl SectionCounter {
+    fn new(title: &'static str) -> Self {
+        Self {
+            scanner: SectionScanner::new(title),
+            has_parent: false,
+            total: 0,
+            checked: 0,
+        }
+    }
+
+    fn feed(&mut self, line: &str) {
+        let Some((indent, checked, _, _)) = self.scanner.task(line) else {
+            return;
+        };
+        // Indented tasks only count once they have a parent, as in the tree
+        if indent.is_empty() {
+            self.has_parent = true;
+        } else if !self.has_parent {
+            return;
+        }
+        self.total += 1;
+        self.checked += u32::from(checked);
+    }
+}
+
+/// `checked` as a whole percentage of `total`, rounded down (so only finished
+/// work shows 100%), or 0 when there is nothing to do. Spec rows and group
+/// headers both use it.
+pub fn percent(checked: u32, total: u32) -> u32 {
+    (checked * 100).checked_div(total).unwrap_or(0)
+}
+
+/// Aggregate Implementation Plan progress `(checked, total)` across a group's
+/// specs, as shown in the group headers of the dashboard and `list`.
+pub fn group_progress<'a>(specs: impl IntoIterator<Item = &'a SpecSummary>) -> (u32, u32) {
+    specs
+        .into_iter()
+        .fold((0, 0), |(c, t), s| (c + s.checked, t + s.total))
+}
+
+/// Load a spec's counts by streaming it line by line, without building task
+/// trees (`tasks`/`test_tasks` are left empty). Used by the `status` overview.
+pub fn load_spec_counts(path: &Path) -> Result<Option<SpecSummary>, String> {
+    use std::io::{BufRead, BufReader};
+
+    let file =
+        fs::File::open(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let mut lines = BufReader::new(file).lines().map(|line| {
+        line.map_err(|e| {
+            if e.kind() == std::io::ErrorKind::InvalidData {
+                format!("{} is not valid UTF-8", path.display())
+            } else {
+                format!("Failed to read {}: {e}", path.display())
+            }
+        })
+    });
+
+    let mut impl_counter = SectionCounter::new("Implementation Plan");
+    let mut test_counter = SectionCounter::new("Test Plan");
+
+    // Collect just the front matter block, then stream the body
+    let mut front_matter = String::new();
+    let mut first = lines.next().transpose()?;
+    if first.as_deref() == Some("---") {
+        front_matter.push_str("---\n");
+        for line in lines.by_ref() {
+            let line = line?;
+            let closed = line == "---";
+            front_matter.push_str(&line);
+            front_matter.push('\n');
+            if closed {
+                break;
+            }
+        }
+        first = None;
+    }
+
+    for line in first.map(Ok).into_iter().chain(lines) {
+        let line = line?;
+        impl_counter.feed(&line);
+        test_counter.feed(&line);
+    }
+
+    let Some(mut summary) = empty_summary(path, parse_front_matter(&front_matter)) else {
+        return Ok(None);
+    };
+    (summary.total, summary.checked) = (impl_counter.total, impl_counter.checked);
+    (summary.total_tests, summary.checked_tests) = (test_counter.total, test_counter.checked);
+    summary.status = status_from_counts(
+        summary.total,
+        summary.checked,
+        summary.total_tests,
+        summary.checked_tests,
+    );
+    Ok(Some(summary))
+}
+
 /// Perform a topological sort of spec names based on `depends_on`.
 /// Returns `Err` with the cycle participants if a cycle is detected.
 pub fn detect_dependency_cycles(summaries: &[SpecSummary]) -> Result<Vec<String>, Vec<String>> {
@@ -306,20 +837,65 @@ fn resolve_blocked(summaries: &mut [SpecSummary]) {
 
 /// Load all specs and return them sorted by completion (incomplete first, then completed),
 /// then by priority within status group, then by group name, then by timestamp.
+/// Unreadable files are skipped with a warning on stderr.
 pub fn load_all_summaries() -> Result<Vec<SpecSummary>, String> {
-    let files = collect_spec_files()?;
+    load_all_with(read_spec_summary, true)
+}
+
+/// Like `load_all_summaries`, but skips unreadable files silently (for the TUI).
+pub fn load_all_summaries_quiet() -> Result<Vec<SpecSummary>, String> {
+    load_all_with(read_spec_summary, false)
+}
+
+/// Like `load_all_summaries`, but streams each file for counts only (no task trees).
+pub fn load_all_counts() -> Result<Vec<SpecSummary>, String> {
+    load_all_with(load_spec_counts, true)
+}
+
+/// `load_all_summaries` for a given set of files, e.g. including archived specs.
+pub fn load_summaries_of(files: &[std::path::PathBuf]) -> Vec<SpecSummary> {
+    load_files_with(files, read_spec_summary, true)
+}
+
+/// `load_all_counts` for a given set of files, e.g. including archived specs.
+pub fn load_counts_of(files: &[std::path::PathBuf]) -> Vec<SpecSummary> {
+    load_files_with(files, load_spec_counts, true)
+}
+
+fn load_all_with(
+    load: fn(&Path) -> Result<Option<SpecSummary>, String>,
+    warn: bool,
+) -> Result<Vec<SpecSummary>, String> {
+    Ok(load_files_with(&collect_spec_files()?, load, warn))
+}
+
+fn load_files_with(
+    files: &[std::path::PathBuf],
+    load: fn(&Path) -> Result<Option<SpecSummary>, String>,
+    warn: bool,
+) -> Vec<SpecSummary> {
     let mut summaries: Vec<SpecSummary> = files
         .iter()
-        .filter_map(|path| load_spec_summary(path))
+        .filter_map(|path| match load(path) {
+            Ok(summary) => summary,
+            Err(e) => {
+                if warn {
+                    report_warning(&format!("{e}; skipping it"));
+                }
+                None
+            }
+        })
         .collect();
 
     resolve_blocked(&mut summaries);
 
+    let order = SpecOrder::load();
     summaries.sort_by(|a, b| {
         let a_done = a.status == SpecStatus::Completed;
         let b_done = b.status == SpecStatus::Completed;
         a_done
             .cmp(&b_done) // incomplete (false) before completed (true)
+            .then_with(|| order.rank(&a.name).cmp(&order.rank(&b.name))) // pinned in .order
             .then_with(|| a.priority.cmp(&b.priority)) // High < Medium < Low
             .then_with(|| a.group.cmp(&b.group))
             .then_with(|| {
@@ -331,7 +907,7 @@ pub fn load_all_summaries() -> Result<Vec<SpecSummary>, String> {
             })
     });
 
-    Ok(summaries)
+    summaries
 }
 
 #[cfg(test)]
@@ -349,6 +925,7 @@ Some background.
 
 - [ ] A: First task
   - [x] A.1: Subtask one
+    - [ ] A.1.1: Grandchild
   - [ ] A.2: Subtask two
 - [x] B: Second task
 
@@ -372,17 +949,23 @@ Some background.
                 id: "A".into(),
                 description: "Task A".into(),
                 checked: false,
+                owner: None,
+                done: None,
                 children: vec![
                     TaskNode {
                         id: "A.1".into(),
                         description: "Sub".into(),
                         checked: true,
+                        owner: None,
+                        done: None,
                         children: vec![],
                     },
                     TaskNode {
                         id: "A.2".into(),
                         description: "Sub".into(),
                         checked: false,
+                        owner: None,
+                        done: None,
                         children: vec![],
                     },
                 ],
@@ -391,6 +974,8 @@ Some background.
                 id: "B".into(),
                 description: "Task B".into(),
                 checked: true,
+                owner: None,
+                done: None,
                 children: vec![],
             },
         ];
@@ -399,6 +984,21 @@ Some background.
         assert_eq!(checked, 2);
     }
 
+    #[test]
+    fn count_leaf_tasks_skips_parents() {
+        let content = "\
+# Implementation Plan
+
+- [ ] A: Group
+  - [x] A.1: Sub
+  - [ ] A.2: Sub
+- [x] B: Standalone
+";
+        let tasks = parse_tasks_from_content(content);
+        assert_eq!(count_tasks(&tasks), (4, 2));
+        assert_eq!(count_leaf_tasks(&tasks), (3, 2));
+    }
+
     #[test]
     fn status_sort_order() {
         assert!(SpecStatus::InProgress < SpecStatus::Pending);
@@ -474,18 +1074,176 @@ Some background.
         let test_tasks = parse_test_tasks_from_content(content);
         let (total, checked) = count_tasks(&tasks);
         let (total_tests, checked_tests) = count_tasks(&test_tasks);
-        let status = if total == 0 && total_tests == 0 {
-            SpecStatus::Pending
-        } else if checked == total && checked_tests == total_tests {
-            SpecStatus::Completed
-        } else if checked > 0 || checked_tests > 0 {
-            SpecStatus::InProgress
-        } else {
-            SpecStatus::Pending
-        };
+        let status = status_from_counts(total, checked, total_tests, checked_tests);
         assert_eq!(status, SpecStatus::InProgress);
     }
 
+    #[test]
+    fn parse_tasks_under_level_two_headings() {
+        let content = "\
+# Spec
+
+## Implementation Plan
+
+- [ ] A: First task
+  - [x] A.1: Subtask
+- [x] B: Second task
+
+### Notes
+
+- [ ] C: Still part of the plan
+
+## Test Plan
+
+- [ ] T.1: First test
+";
+        let tasks = parse_tasks_from_content(content);
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].id, "A");
+        assert_eq!(tasks[0].children.len(), 1);
+        assert_eq!(tasks[2].id, "C");
+
+        let test_tasks = parse_test_tasks_from_content(content);
+        assert_eq!(test_tasks.len(), 1);
+        assert_eq!(test_tasks[0].id, "T.1");
+    }
+
+    #[test]
+    fn parse_document_sections() {
+        let content = "---\ntinySpec: v0\n# not a heading\n---\n\n# Background\n\nWhy.\n\n# Implementation Plan\n\n## Notes\n\n```\n# still notes\n```\n\n- [ ] A: Task\n\n# Test Plan\n";
+        let doc = SpecDocument::parse(content);
+        let titles: Vec<_> = doc.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            ["Background", "Implementation Plan", "Notes", "Test Plan"]
+        );
+
+        let background = doc.section("Background").unwrap();
+        assert_eq!(background.line, 6);
+        assert_eq!(background.body, "\nWhy.\n\n");
+
+        let plan = doc.section("Implementation Plan").unwrap();
+        assert!(plan.body.contains("## Notes\n"));
+        assert!(plan.body.contains("# still notes\n"));
+        assert!(plan.body.ends_with("- [ ] A: Task\n\n"));
+        assert!(!doc.section("Notes").unwrap().body.contains("# Test Plan"));
+        assert!(doc.section("Test Plan").unwrap().is_empty());
+    }
+
+    #[test]
+    fn task_id_ends_at_first_colon() {
+        assert_eq!(
+            parse_task_line("- [ ] A: Fix the ratio 3:1 bug"),
+            Some(("", false, "A", "Fix the ratio 3:1 bug"))
+        );
+        assert_eq!(
+            parse_task_line("  - [x] B.2 : Note: keep the: colons"),
+            Some(("  ", true, "B.2", "Note: keep the: colons"))
+        );
+        assert_eq!(parse_task_line("- [ ] No colon here"), None);
+    }
+
+    #[test]
+    fn tab_indented_subtasks_nest() {
+        assert_eq!(indent_width("\t- [ ] A.1: Sub", 4), 4);
+        assert_eq!(indent_width("  \t- [ ] A.1: Sub", 4), 4);
+        assert_eq!(indent_width("\t\t- [ ] A.1: Sub", 2), 4);
+        assert_eq!(indent_width("    - [ ] A.1: Sub", 4), 4);
+
+        let content = "\
+# Implementation Plan
+
+- [ ] A: Group
+\t- [x] A.1: Tabbed
+    - [ ] A.2: Spaced
+- [ ] B: Next
+";
+        let tasks = parse_tasks_from_content(content);
+        assert_eq!(tasks.len(), 2);
+        let ids: Vec<&str> = tasks[0].children.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["A.1", "A.2"]);
+        assert!(tasks[0].children[0].checked);
+    }
+
+    #[test]
+    fn deeper_indents_nest_under_the_previous_shallower_task() {
+        let content = "\
+# Implementation Plan
+
+- [ ] A: Group
+  - [ ] A.1: Sub
+    - [ ] A.1.1: Grandchild
+      - [ ] A.1.1.1: Great-grandchild
+  - [ ] A.2: Back up a level
+- [ ] B: Next
+";
+        let tasks = parse_tasks_from_content(content);
+        assert_eq!(tasks.len(), 2);
+        let a = &tasks[0];
+        let ids: Vec<&str> = a.children.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["A.1", "A.2"]);
+        assert_eq!(a.children[0].children[0].id, "A.1.1");
+        assert_eq!(a.children[0].children[0].children[0].id, "A.1.1.1");
+
+        let flat: Vec<(&str, usize)> = flatten_tasks(&tasks)
+            .into_iter()
+            .map(|(t, depth)| (t.id.as_str(), depth))
+            .collect();
+        assert_eq!(
+            flat,
+            [
+                ("A", 0),
+                ("A.1", 1),
+                ("A.1.1", 2),
+                ("A.1.1.1", 3),
+                ("A.2", 1),
+                ("B", 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_heading_levels() {
+        assert_eq!(parse_heading("# Background"), Some((1, "Background")));
+        assert_eq!(
+            parse_heading("## Implementation Plan"),
+            Some((2, "Implementation Plan"))
+        );
+        assert_eq!(parse_heading("#hashtag"), None);
+        assert_eq!(parse_heading("- [ ] A: task"), None);
+    }
+
+    #[test]
+    fn parse_test_result_annotation() {
+        assert_eq!(
+            test_result("Rejects bad tokens (result: fail)"),
+            Some(TestResult::Fail)
+        );
+        assert_eq!(
+            test_result("Login works result: PASS"),
+            Some(TestResult::Pass)
+        );
+        assert_eq!(test_result("Covers [result: fail]"), Some(TestResult::Fail));
+        assert_eq!(test_result("Handles the result: of parsing"), None);
+        assert_eq!(test_result("No annotation"), None);
+    }
+
+    #[test]
+    fn parse_task_owner() {
+        let content = "\
+# Implementation Plan
+
+- [ ] A: @alice Build it
+  - [ ] A.1: @bob: Write tests
+  - [ ] A.2: Email @carol about it
+";
+        let tasks = parse_tasks_from_content(content);
+        assert_eq!(tasks[0].owner.as_deref(), Some("alice"));
+        assert_eq!(tasks[0].description, "@alice Build it");
+        assert_eq!(tasks[0].children[0].owner.as_deref(), Some("bob"));
+        assert_eq!(tasks[0].children[1].owner, None);
+    }
+
     #[test]
     fn extract_timestamp_from_filename() {
         assert_eq!(
@@ -498,4 +1256,65 @@ Some background.
         );
         assert_eq!(extract_timestamp("short.md"), "");
     }
+
+    #[test]
+    fn streamed_counts_match_full_parse() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("2026-02-17-21-27-big-spec.md");
+        let content = "\
+---
+title: Big Spec
+priority: high
+---
+
+# Implementation Plan
+
+  - [x] X.1: Orphan subtask (ignored)
+- [ ] A: First task
+  - [x] A.1: Subtask one
+    - [ ] A.1.1: Grandchild
+  - [ ] A.2: Subtask two
+- [x] B: Second task
+	- [x] B.1: Tab-indented subtask
+
+```markdown
+- [ ] Q: Documented example (ignored)
+# Test Plan
+```
+
+~~~
+## Implementation Plan
+~~~
+
+## Notes
+
+- [ ] N: Nested under the plan
+
+# Test Plan
+
+- [x] T.1: Test one
+- [ ] T.2: Test two
+
+# Appendix
+
+- [ ] Z: Outside any plan
+";
+        fs::write(&path, content).unwrap();
+
+        let full = load_spec_summary(&path).unwrap();
+        let streamed = load_spec_counts(&path).unwrap().unwrap();
+        assert_eq!(
+            (streamed.total, streamed.checked),
+            (full.total, full.checked)
+        );
+        assert_eq!(
+            (streamed.total_tests, streamed.checked_tests),
+            (full.total_tests, full.checked_tests)
+        );
+        assert_eq!((streamed.total, streamed.checked), (7, 3));
+        assert_eq!(streamed.status, full.status);
+        assert_eq!(streamed.title, "Big Spec");
+        assert_eq!(streamed.priority, Priority::High);
+        assert!(streamed.tasks.is_empty());
+    }
 }
@@ -0,0 +1,626 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use clap_complete::engine::CompletionCandidate;
+
+use super::config::configured_tab_width;
+use super::format::format_file;
+use super::summary::{
+    CodeFence, TaskNode, indent_width, load_spec_summary, parse_heading, parse_task_line,
+    parse_tasks_from_content, split_done_annotation,
+};
+use super::{find_spec, front_matter_close, read_spec};
+
+/// Check that a task ID follows the `A` / `A.1` convention: a group of uppercase
+/// letters, digits (see `reflow-ids`), or an emoji, followed by optional dotted
+/// numbers, e.g. `T.1.1` or `2.1`.
+pub(crate) fn validate_task_id(id: &str) -> Result<(), String> {
+    let mut segments = id.split('.');
+    let group = segments.next().unwrap_or_default();
+    let group_ok = !group.is_empty()
+        && (group.chars().all(|c| c.is_ascii_uppercase())
+            || group.chars().all(|c| c.is_ascii_digit())
+            || group.chars().all(|c| !c.is_ascii()));
+    let rest_ok = segments.all(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()));
+
+    if group_ok && rest_ok {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid task ID '{id}'. Use a group letter like A, or a subtask like A.1"
+        ))
+    }
+}
+
+/// A task line inside the section being edited.
+struct TaskLine {
+    index: usize,
+    /// Leading whitespace; empty for top-level tasks.
+    indent: String,
+    id: String,
+}
+
+/// Add a task to a spec's Implementation Plan (or Test Plan with `test`).
+/// Without `id`, the next free ID is assigned: the next group letter, the next
+/// `T.n` test task, or the next subtask of `under`.
+pub fn add_task(
+    name: &str,
+    description: &str,
+    under: Option<&str>,
+    id: Option<&str>,
+    test: bool,
+    format: bool,
+) -> Result<(), String> {
+    let path = find_spec(name)?;
+    let content = read_spec(&path)?;
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+
+    let section = if test {
+        "Test Plan"
+    } else {
+        "Implementation Plan"
+    };
+    let (heading, tasks) = section_tasks(&lines, section)
+        .ok_or_else(|| format!("Spec '{name}' has no {section} section"))?;
+
+    // A dotted `--id` (e.g. `A.3`) goes under its parent task when there is one
+    let parent_of_id = id
+        .and_then(|id| id.rsplit_once('.'))
+        .map(|(parent, _)| parent)
+        .filter(|parent| tasks.iter().any(|t| t.id == *parent));
+    let under = under.or(parent_of_id);
+
+    // Where the new task's siblings live: top-level tasks, or the children of `under`
+    let (siblings, indent, insert_at) = match under {
+        Some(parent_id) => {
+            let pos = tasks
+                .iter()
+                .position(|t| t.id == parent_id)
+                .ok_or_else(|| {
+                    format!("No task '{parent_id}' found in the {section} of spec '{name}'")
+                })?;
+            let tab_width = configured_tab_width();
+            let depth = |t: &TaskLine| indent_width(&t.indent, tab_width);
+            let parent_depth = depth(&tasks[pos]);
+            // Everything indented below the parent; only its `parent.N` IDs count
+            let nested: Vec<&TaskLine> = tasks[pos + 1..]
+                .iter()
+                .take_while(|t| depth(t) > parent_depth)
+                .collect();
+            let indent = nested.first().map_or(parent_depth + 2, |c| depth(c));
+            let after = nested.last().map_or(tasks[pos].index, |c| c.index);
+            (nested, indent, after + 1)
+        }
+        None => {
+            let top: Vec<&TaskLine> = tasks.iter().filter(|t| t.indent.is_empty()).collect();
+            let after = tasks.last().map_or(heading, |t| t.index);
+            (top, 0, after + 1)
+        }
+    };
+
+    let id = match id {
+        Some(id) => {
+            validate_task_id(id)?;
+            if let Some(parent_id) = under
+                && !id.starts_with(&format!("{parent_id}."))
+            {
+                return Err(format!("Task ID '{id}' is not a subtask of '{parent_id}'"));
+            }
+            let mut fence = CodeFence::default();
+            let exists = lines
+                .iter()
+                .filter(|l| !fence.skip(l))
+                .filter_map(|l| parse_task_line(l))
+                .any(|(_, _, existing, _)| existing == id);
+            if exists {
+                return Err(format!("Task '{id}' already exists in spec '{name}'"));
+            }
+            id.to_string()
+        }
+        None => {
+            let ids: Vec<&str> = siblings.iter().map(|t| t.id.as_str()).collect();
+            match under {
+                Some(parent_id) => next_numbered_id(parent_id, &ids),
+                None if test => next_numbered_id("T", &ids),
+                None => next_group_id(&ids)?,
+            }
+        }
+    };
+
+    let line = format!("{}- [ ] {id}: {description}", " ".repeat(indent));
+    insert_task_lines(&mut lines, insert_at, vec![line], tasks.is_empty());
+    write_lines(&path, &lines, format)?;
+
+    println!("Added task {id}: {description}");
+    Ok(())
+}
+
+/// Copy the Implementation Plan of `src` into `dest` as unchecked tasks.
+/// `replace` swaps out the task list of `dest`; `append` adds after it,
+/// renumbering copied groups to the next free letters. With neither, `dest`
+/// must not have any tasks yet.
+pub fn clone_tasks(
+    src: &str,
+    dest: &str,
+    append: bool,
+    replace: bool,
+    format: bool,
+) -> Result<(), String> {
+    let src_path = find_spec(src)?;
+    let mut tasks = parse_tasks_from_content(&read_spec(&src_path)?);
+    if tasks.is_empty() {
+        return Err(format!("Spec '{src}' has no Implementation Plan tasks"));
+    }
+
+    let path = find_spec(dest)?;
+    if path == src_path {
+        return Err("Source and destination are the same spec".into());
+    }
+    let content = read_spec(&path)?;
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let (heading, existing) = section_tasks(&lines, "Implementation Plan")
+        .ok_or_else(|| format!("Spec '{dest}' has no Implementation Plan section"))?;
+
+    if !existing.is_empty() && !append && !replace {
+        return Err(format!(
+            "Spec '{dest}' already has tasks. Pass --append to add after them or --replace to overwrite them"
+        ));
+    }
+
+    let insert_at = if replace && !existing.is_empty() {
+        let first = existing[0].index;
+        for task in existing.iter().rev() {
+            lines.remove(task.index);
+        }
+        first
+    } else {
+        if append {
+            let mut ids: Vec<String> = existing
+                .iter()
+                .filter(|t| t.indent.is_empty())
+                .map(|t| t.id.clone())
+                .collect();
+            for task in &mut tasks {
+                let id = next_group_id(&ids.iter().map(String::as_str).collect::<Vec<_>>())?;
+                renumber(task, &task.id.clone(), &id);
+                ids.push(id);
+            }
+        }
+        existing.last().map_or(heading, |t| t.index) + 1
+    };
+
+    let mut rendered = Vec::new();
+    render_unchecked(&tasks, 0, &mut rendered);
+    let count = rendered.len();
+    insert_task_lines(&mut lines, insert_at, rendered, existing.is_empty());
+    write_lines(&path, &lines, format)?;
+
+    println!("Copied {count} task(s) from '{src}' into '{dest}'");
+    Ok(())
+}
+
+/// Give a copied task group a new ID, rewriting its subtask prefixes to match.
+fn renumber(task: &mut TaskNode, old: &str, new: &str) {
+    if task.id == old {
+        task.id = new.to_string();
+    } else if let Some(rest) = task.id.strip_prefix(&format!("{old}.")) {
+        task.id = format!("{new}.{rest}");
+    }
+    for child in &mut task.children {
+        renumber(child, old, new);
+    }
+}
+
+/// Render a task tree as unchecked task lines, two spaces per level.
+fn render_unchecked(tasks: &[TaskNode], depth: usize, out: &mut Vec<String>) {
+    for task in tasks {
+        out.push(format!(
+            "{}- [ ] {}: {}",
+            "  ".repeat(depth),
+            task.id,
+            task.description
+        ));
+        render_unchecked(&task.children, depth + 1, out);
+    }
+}
+
+/// Insert task lines at `at`; the first tasks in a section are kept separated
+/// from the heading and whatever follows by blank lines.
+fn insert_task_lines(lines: &mut Vec<String>, mut at: usize, new: Vec<String>, first: bool) {
+    if first {
+        if lines.get(at).is_some_and(|l| l.trim().is_empty()) {
+            at += 1;
+        } else {
+            lines.insert(at, String::new());
+            at += 1;
+        }
+        if lines.get(at).is_some_and(|l| !l.trim().is_empty()) {
+            lines.insert(at, String::new());
+        }
+    }
+    lines.splice(at..at, new);
+}
+
+fn write_lines(path: &Path, lines: &[String], format: bool) -> Result<(), String> {
+    let mut output = lines.join("\n");
+    output.push('\n');
+    fs::write(path, &output).map_err(|e| format!("Failed to write spec: {e}"))?;
+    if format {
+        format_file(path)?;
+    }
+    Ok(())
+}
+
+/// Uncheck every task in a spec (both plans) to start a new iteration. A
+/// front-matter `status:` field, if the spec has one, goes back to `draft`.
+/// Asks for confirmation unless `yes`.
+pub fn reset(name: &str, yes: bool, format: bool) -> Result<(), String> {
+    let path = find_spec(name)?;
+    let content = read_spec(&path)?;
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+
+    let mut fence = CodeFence::default();
+    let checked: Vec<usize> = (0..lines.len())
+        .filter(|&i| !fence.skip(&lines[i]))
+        .filter(|&i| parse_task_line(&lines[i]).is_some_and(|(_, checked, _, _)| checked))
+        .collect();
+    if checked.is_empty() {
+        println!("No checked tasks in spec '{name}'.");
+        return Ok(());
+    }
+
+    if !yes {
+        eprint!(
+            "Uncheck all {} checked task(s) in {name}? [y/N] ",
+            checked.len()
+        );
+        io::stderr().flush().ok();
+        let mut input = String::new();
+        io::stdin()
+            .lock()
+            .read_line(&mut input)
+            .map_err(|e| format!("Failed to read input: {e}"))?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    for &i in &checked {
+        let unchecked = lines[i].replacen("- [x] ", "- [ ] ", 1);
+        lines[i] = split_done_annotation(&unchecked).0.to_string();
+    }
+    // Only within the front matter block
+    if let Some(close) = front_matter_close(&lines)
+        && let Some(status) = lines[1..close]
+            .iter_mut()
+            .find(|l| l.starts_with("status:"))
+    {
+        *status = "status: draft".to_string();
+    }
+
+    write_lines(&path, &lines, format)?;
+    println!("Reset {} task(s) in spec '{name}'", checked.len());
+    Ok(())
+}
+
+/// Renumber a spec's Implementation Plan groups in plan order as letters
+/// (`A`, `B`, ... skipping `T`) or numbers (`1`, `2`, ...), carrying subtasks
+/// along (`A.2` → `1.2`). Dotted references to the old IDs elsewhere in the
+/// Implementation Plan and Test Plan are rewritten too.
+pub fn reflow_ids(name: &str, scheme: &str, format: bool) -> Result<(), String> {
+    let numeric = match scheme {
+        "numeric" => true,
+        "letters" => false,
+        _ => {
+            return Err(format!(
+                "Invalid numbering scheme '{scheme}'. Use: letters, numeric"
+            ));
+        }
+    };
+
+    let path = find_spec(name)?;
+    let content = read_spec(&path)?;
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let (_, tasks) = section_tasks(&lines, "Implementation Plan")
+        .ok_or_else(|| format!("Spec '{name}' has no Implementation Plan section"))?;
+
+    // New ID for each task line, and old → new for rewriting references
+    let mut new_ids: Vec<(usize, String, String)> = Vec::new();
+    let mut group: Option<(String, String)> = None;
+    let mut groups = 0;
+    for task in &tasks {
+        let new = if task.indent.is_empty() {
+            let new = if numeric {
+                (groups + 1).to_string()
+            } else {
+                ('A'..='Z')
+                    .filter(|&c| c != 'T')
+                    .nth(groups)
+                    .ok_or("Too many task groups to letter (A–Z, skipping T)")?
+                    .to_string()
+            };
+            groups += 1;
+            group = Some((task.id.clone(), new.clone()));
+            new
+        } else {
+            match &group {
+                Some((old, new)) => match task.id.strip_prefix(&format!("{old}.")) {
+                    Some(rest) => format!("{new}.{rest}"),
+                    None => task.id.clone(),
+                },
+                None => task.id.clone(),
+            }
+        };
+        new_ids.push((task.index, task.id.clone(), new));
+    }
+
+    // Only the spec's own renamed subtask IDs are rewritten elsewhere, so other
+    // dotted numbers (`Python 3.1`) survive
+    let mut references: Vec<(&str, &str)> = Vec::new();
+    for (_, old, new) in &new_ids {
+        if old.contains('.') && old != new && !references.iter().any(|(o, _)| o == old) {
+            references.push((old, new));
+        }
+    }
+
+    let mut changed = 0;
+    for section in ["Implementation Plan", "Test Plan"] {
+        let Some((heading, end)) = section_range(&lines, section) else {
+            continue;
+        };
+        let mut fence = CodeFence::default();
+        for (index, line) in lines.iter_mut().enumerate().take(end).skip(heading + 1) {
+            if fence.skip(line) {
+                continue;
+            }
+            let task = new_ids.iter().find(|(i, _, _)| *i == index);
+            let split = task.and_then(|(_, old, new)| {
+                let (head, rest) = line.split_once(&format!("] {old}:"))?;
+                Some((head, new, rest, old != new))
+            });
+            *line = match split {
+                Some((head, new, rest, renamed)) => {
+                    changed += usize::from(renamed);
+                    format!("{head}] {new}:{}", rewrite_references(rest, &references))
+                }
+                None => rewrite_references(line, &references),
+            };
+        }
+    }
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    fs::write(&path, &output).map_err(|e| format!("Failed to write spec: {e}"))?;
+    if format {
+        format_file(&path)?;
+    }
+
+    println!("Renumbered {changed} task(s) in spec '{name}'");
+    Ok(())
+}
+
+/// Replace whole tokens equal to an old ID in `references` (e.g. `A.2`); any
+/// other token, dotted or not, is kept. Callers leave bare group IDs (`A`, `1`)
+/// out, since they are indistinguishable from prose.
+fn rewrite_references(text: &str, references: &[(&str, &str)]) -> String {
+    let is_token = |c: char| c.is_alphanumeric() || c == '.';
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while !rest.is_empty() {
+        let len = rest.find(|c| !is_token(c)).unwrap_or(rest.len());
+        if len == 0 {
+            let c = rest.chars().next().unwrap_or_default();
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        let token = &rest[..len];
+        // A trailing full stop ends the sentence, not the ID
+        let id = token.trim_end_matches('.');
+        match references.iter().find(|(old, _)| *old == id) {
+            Some((_, new)) => {
+                out.push_str(new);
+                out.push_str(&token[id.len()..]);
+            }
+            None => out.push_str(token),
+        }
+        rest = &rest[len..];
+    }
+    out
+}
+
+/// Shell completion for `check`'s task ID: unchecked tasks, with the one
+/// `check <spec> next` would pick listed first.
+pub fn complete_check_task_ids(current: &std::ffi::OsStr) -> Vec<CompletionCandidate> {
+    complete_task_ids(current, true)
+}
+
+/// Shell completion for `uncheck`'s task ID: checked tasks, most recent first.
+pub fn complete_uncheck_task_ids(current: &std::ffi::OsStr) -> Vec<CompletionCandidate> {
+    complete_task_ids(current, false)
+}
+
+fn complete_task_ids(current: &std::ffi::OsStr, check: bool) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    let Some(summary) = completing_spec_name()
+        .and_then(|name| find_spec(&name).ok())
+        .and_then(|path| load_spec_summary(&path))
+    else {
+        return Vec::new();
+    };
+
+    let first = if check {
+        summary.next_task()
+    } else {
+        summary.last_checked_task()
+    };
+    // Plan order, Implementation Plan before Test Plan, parents before subtasks
+    let mut tasks: Vec<&TaskNode> = Vec::new();
+    let mut stack: Vec<&TaskNode> = summary
+        .tasks
+        .iter()
+        .chain(&summary.test_tasks)
+        .rev()
+        .collect();
+    while let Some(task) = stack.pop() {
+        if task.checked != check {
+            tasks.push(task);
+        }
+        stack.extend(task.children.iter().rev());
+    }
+    if let Some(first) = first {
+        tasks.retain(|t| t.id != first.id);
+        tasks.insert(0, first);
+    }
+
+    tasks
+        .into_iter()
+        .map(|t| (t.id.clone(), Some(t.description.clone())))
+        .chain(std::iter::once(("next".to_string(), None)))
+        .filter(|(id, _)| id.starts_with(current.as_ref()))
+        .enumerate()
+        .map(|(i, (id, description))| {
+            CompletionCandidate::new(id)
+                .help(description.filter(|d| !d.is_empty()).map(Into::into))
+                .display_order(Some(i))
+        })
+        .collect()
+}
+
+/// The spec named on the command line being completed, i.e. the first
+/// positional word after `check`/`uncheck`.
+fn completing_spec_name() -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    let words = &args[args.iter().position(|a| a == "--")? + 1..];
+    let command = words.iter().position(|w| w == "check" || w == "uncheck")?;
+    words[command + 1..]
+        .iter()
+        .find(|w| !w.starts_with('-'))
+        .map(|w| w.split(':').next().unwrap_or(w).to_string())
+}
+
+/// Locate a headed section and return its heading line index and task lines.
+fn section_tasks(lines: &[String], section_title: &str) -> Option<(usize, Vec<TaskLine>)> {
+    let (heading, end) = section_range(lines, section_title)?;
+
+    let mut fence = CodeFence::default();
+    let tasks = (heading + 1..end)
+        .filter(|&i| !fence.skip(&lines[i]))
+        .filter_map(|index| {
+            let (indent, _, id, _) = parse_task_line(&lines[index])?;
+            Some(TaskLine {
+                index,
+                indent: indent.to_string(),
+                id: id.to_string(),
+            })
+        })
+        .collect();
+
+    Some((heading, tasks))
+}
+
+/// Line range of a headed section: its heading index and the index just past
+/// its last line (the next heading of the same or higher level, or EOF).
+fn section_range(lines: &[String], section_title: &str) -> Option<(usize, usize)> {
+    let mut fence = CodeFence::default();
+    let mut heading = None;
+    for (i, line) in lines.iter().enumerate() {
+        if fence.skip(line) {
+            continue;
+        }
+        let Some((level, text)) = parse_heading(line) else {
+            continue;
+        };
+        match heading {
+            None if text == section_title => heading = Some((i, level)),
+            Some((start, current)) if level <= current => return Some((start, i)),
+            _ => {}
+        }
+    }
+    heading.map(|(start, _)| (start, lines.len()))
+}
+
+/// The letter after the highest single-letter group ID (`A` when there are none),
+/// or the next number when the plan uses numeric groups.
+fn next_group_id(ids: &[&str]) -> Result<String, String> {
+    if let Some(highest) = ids.iter().filter_map(|id| id.parse::<u32>().ok()).max() {
+        return Ok((highest + 1).to_string());
+    }
+    let highest = ids
+        .iter()
+        .filter_map(|id| {
+            let mut chars = id.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_uppercase() && c != 'T' => Some(c),
+                _ => None,
+            }
+        })
+        .max();
+    match highest {
+        None => Ok("A".to_string()),
+        Some('Z') => Err("No group letters left after Z; pass --id to choose one".into()),
+        // Skip `T`, which is reserved for test tasks
+        Some('S') => Ok("U".to_string()),
+        Some(c) => Ok(((c as u8 + 1) as char).to_string()),
+    }
+}
+
+/// `{prefix}.{n+1}` where `n` is the highest existing `{prefix}.n` among `ids`.
+fn next_numbered_id(prefix: &str, ids: &[&str]) -> String {
+    let highest = ids
+        .iter()
+        .filter_map(|id| {
+            id.strip_prefix(prefix)?
+                .strip_prefix('.')?
+                .parse::<u32>()
+                .ok()
+        })
+        .max()
+        .unwrap_or(0);
+    format!("{prefix}.{}", highest + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn task_id_validation() {
+        for id in ["A", "B.2", "T.1.1", "AB", "\u{1F9EA}.1", "1", "2.1"] {
+            assert!(validate_task_id(id).is_ok(), "{id} should be valid");
+        }
+        for id in ["a", "A.", "A.x", "1A", "A1", ""] {
+            assert!(validate_task_id(id).is_err(), "{id} should be invalid");
+        }
+    }
+
+    #[test]
+    fn rewrite_dotted_references() {
+        let refs = [("A.1", "1.1"), ("B.2", "2.2")];
+        assert_eq!(
+            rewrite_references(" Covers A.1 and B.2.", &refs),
+            " Covers 1.1 and 2.2."
+        );
+        assert_eq!(
+            rewrite_references("A.12, A and A.1.3", &refs),
+            "A.12, A and A.1.3"
+        );
+        assert_eq!(
+            rewrite_references("Bump to 3.1 (see A.1)", &refs),
+            "Bump to 3.1 (see 1.1)"
+        );
+    }
+
+    #[test]
+    fn next_ids() {
+        assert_eq!(next_group_id(&[]).unwrap(), "A");
+        assert_eq!(next_group_id(&["A", "C", "B"]).unwrap(), "D");
+        assert_eq!(next_group_id(&["S"]).unwrap(), "U");
+        assert_eq!(next_group_id(&["1", "3"]).unwrap(), "4");
+        assert!(next_group_id(&["Z"]).is_err());
+        assert_eq!(next_numbered_id("A", &["A.1", "A.3"]), "A.4");
+        assert_eq!(next_numbered_id("T", &[]), "T.1");
+    }
+}
@@ -3,11 +3,18 @@ use std::fmt;
 use std::fs;
 use std::path::PathBuf;
 
+use serde::Serialize;
+
+use super::config::configured_template_dirs;
+use super::errors::with_hint;
 use super::specs_dir;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TemplateSource {
     Repo,
+    /// A `template_dirs` entry in `.tinyspec.yaml`
+    Project,
     User,
 }
 
@@ -15,6 +22,7 @@ impl fmt::Display for TemplateSource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             TemplateSource::Repo => write!(f, "repo"),
+            TemplateSource::Project => write!(f, "project"),
             TemplateSource::User => write!(f, "user"),
         }
     }
@@ -72,23 +80,29 @@ fn scan_templates(dir: &PathBuf, source: TemplateSource) -> Vec<TemplateInfo> {
         .collect()
 }
 
-/// Collect all available templates from both repo and user directories.
-/// Repo-level templates take precedence over user-level on name conflicts.
+/// Collect all available templates: repo, then the project's `template_dirs`
+/// in order, then user. The first template with a given name wins.
 pub fn collect_templates() -> Result<Vec<TemplateInfo>, String> {
+    let mut dirs = vec![(repo_templates_dir(), TemplateSource::Repo)];
+    dirs.extend(
+        configured_template_dirs()
+            .into_iter()
+            .map(|dir| (dir, TemplateSource::Project)),
+    );
+    // Without HOME there is no user directory, which just means no user templates.
+    dirs.extend(
+        user_templates_dir()
+            .ok()
+            .map(|dir| (dir, TemplateSource::User)),
+    );
+
     let mut templates = Vec::new();
     let mut seen_names = std::collections::HashSet::new();
-
-    // Repo templates first (higher precedence)
-    for t in scan_templates(&repo_templates_dir(), TemplateSource::Repo) {
-        seen_names.insert(t.name.clone());
-        templates.push(t);
-    }
-
-    // User templates (skip if name already seen from repo)
-    let user_dir = user_templates_dir()?;
-    for t in scan_templates(&user_dir, TemplateSource::User) {
-        if !seen_names.contains(&t.name) {
-            templates.push(t);
+    for (dir, source) in dirs {
+        for t in scan_templates(&dir, source) {
+            if seen_names.insert(t.name.clone()) {
+                templates.push(t);
+            }
         }
     }
 
@@ -102,7 +116,12 @@ pub fn find_template(name: &str) -> Result<TemplateInfo, String> {
     templates
         .into_iter()
         .find(|t| t.name == name)
-        .ok_or_else(|| format!("No template found matching '{name}'"))
+        .ok_or_else(|| {
+            with_hint(
+                format!("No template found matching '{name}'"),
+                "run `tinyspec templates` to see available templates",
+            )
+        })
 }
 
 /// Substitute template variables in the given content.
@@ -111,6 +130,25 @@ pub fn find_template(name: &str) -> Result<TemplateInfo, String> {
 /// blocks (``` ... ```) and inline code (` ... `) are left untouched.
 /// Unknown variables are left as-is.
 pub fn substitute_variables(content: &str, vars: &HashMap<&str, &str>) -> String {
+    walk_variables(content, |name| vars.get(name).map(|v| v.to_string()))
+}
+
+/// List the distinct variable names a template references, in order of first use.
+/// Uses the same rules as `substitute_variables`, so placeholders inside code are ignored.
+pub fn template_variables(content: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    walk_variables(content, |name| {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+        None
+    });
+    names
+}
+
+/// Walk `content`, calling `replace` for each `{{var}}`/`${var}` placeholder outside
+/// code. Placeholders for which `replace` returns `None` are copied through unchanged.
+fn walk_variables(content: &str, mut replace: impl FnMut(&str) -> Option<String>) -> String {
     let mut result = String::with_capacity(content.len());
     let chars: Vec<char> = content.chars().collect();
     let len = chars.len();
@@ -162,9 +200,9 @@ pub fn substitute_variables(content: &str, vars: &HashMap<&str, &str>) -> String
             && chars[i] == '{'
             && chars[i + 1] == '{'
             && let Some((name, end)) = extract_var_name(&chars, i + 2, '}', '}')
-            && let Some(value) = vars.get(name.as_str())
+            && let Some(value) = replace(&name)
         {
-            result.push_str(value);
+            result.push_str(&value);
             i = end;
             continue;
         }
@@ -174,9 +212,9 @@ pub fn substitute_variables(content: &str, vars: &HashMap<&str, &str>) -> String
             && chars[i] == '$'
             && chars[i + 1] == '{'
             && let Some((name, end)) = extract_var_name(&chars, i + 2, '}', '\0')
-            && let Some(value) = vars.get(name.as_str())
+            && let Some(value) = replace(&name)
         {
-            result.push_str(value);
+            result.push_str(&value);
             i = end;
             continue;
         }
@@ -230,14 +268,43 @@ fn extract_var_name(
 }
 
 /// List all available templates, showing name and source.
-pub fn list_templates() -> Result<(), String> {
+pub fn list_templates(json: bool) -> Result<(), String> {
     let templates = collect_templates()?;
 
+    if json {
+        #[derive(Serialize)]
+        struct TemplateJson {
+            name: String,
+            source: TemplateSource,
+            path: PathBuf,
+            variables: Vec<String>,
+        }
+
+        let entries: Vec<TemplateJson> = templates
+            .into_iter()
+            .map(|t| {
+                let content = fs::read_to_string(&t.path).unwrap_or_default();
+                TemplateJson {
+                    variables: template_variables(&content),
+                    name: t.name,
+                    source: t.source,
+                    path: t.path,
+                }
+            })
+            .collect();
+
+        let out = serde_json::to_string_pretty(&entries)
+            .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+        println!("{out}");
+        return Ok(());
+    }
+
     if templates.is_empty() {
         println!("No templates found.");
         println!();
         println!("Create templates as Markdown files in:");
         println!("  .specs/templates/       (repo-level)");
+        println!("  template_dirs in .tinyspec.yaml  (project-level)");
         println!("  ~/.config/tinyspec/templates/  (user-level)");
         return Ok(());
     }
@@ -326,6 +393,18 @@ mod tests {
         assert_eq!(substitute_variables(input, &vars()), input);
     }
 
+    #[test]
+    fn template_variables_lists_distinct_names() {
+        let input = "{{title}} on ${date}, again {{title}} and {{owner}}";
+        assert_eq!(template_variables(input), vec!["title", "date", "owner"]);
+    }
+
+    #[test]
+    fn template_variables_ignores_code() {
+        let input = "`{{inline}}`\n```\n${fenced}\n```\n{{real}}";
+        assert_eq!(template_variables(input), vec!["real"]);
+    }
+
     #[test]
     fn empty_braces_left_as_is() {
         let input = "{{}} and ${}";
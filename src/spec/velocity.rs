@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+
+use super::summary::{flatten_tasks, load_all_summaries};
+
+#[derive(Serialize)]
+struct Period {
+    period: String,
+    completed: u32,
+}
+
+/// Report how many tasks were completed per day (or ISO week with `weekly`),
+/// from the `(done YYYY-MM-DD)` annotations written by `check --timestamp`.
+pub fn velocity(weekly: bool, json: bool) -> Result<(), String> {
+    let summaries = load_all_summaries()?;
+
+    let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+    let done_dates = summaries
+        .iter()
+        .flat_map(|s| {
+            flatten_tasks(&s.tasks)
+                .into_iter()
+                .chain(flatten_tasks(&s.test_tasks))
+        })
+        .map(|(t, _)| t)
+        .filter(|t| t.checked)
+        .filter_map(|t| t.done.as_deref())
+        .filter_map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+    for date in done_dates {
+        let key = if weekly {
+            let week = date.iso_week();
+            format!("{}-W{:02}", week.year(), week.week())
+        } else {
+            date.format("%Y-%m-%d").to_string()
+        };
+        *counts.entry(key).or_default() += 1;
+    }
+
+    let periods: Vec<Period> = counts
+        .into_iter()
+        .map(|(period, completed)| Period { period, completed })
+        .collect();
+
+    if json {
+        let out = serde_json::to_string_pretty(&periods)
+            .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+        println!("{out}");
+        return Ok(());
+    }
+
+    if periods.is_empty() {
+        println!(
+            "No completion dates recorded. Use `tinyspec check <spec> <task> --timestamp` to track velocity."
+        );
+        return Ok(());
+    }
+
+    let unit = if weekly { "week" } else { "day" };
+    let header = if weekly { "Week" } else { "Day" };
+    let width = periods
+        .iter()
+        .map(|p| p.period.len())
+        .max()
+        .unwrap_or(0)
+        .max(header.len());
+    println!("{header:width$}  Done");
+    for p in &periods {
+        println!("{:width$}  {:>4}", p.period, p.completed);
+    }
+
+    let total: u32 = periods.iter().map(|p| p.completed).sum();
+    println!();
+    println!(
+        "{total} task(s) over {} {unit}(s), {:.1} per {unit}",
+        periods.len(),
+        total as f64 / periods.len() as f64
+    );
+    Ok(())
+}
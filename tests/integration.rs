@@ -6,7 +6,7 @@ use tempfile::TempDir;
 
 /// Helper: create a tinyspec command that runs in the given directory.
 fn tinyspec(dir: &TempDir) -> Command {
-    let mut cmd = Command::cargo_bin("tinyspec").unwrap();
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
     cmd.current_dir(dir.path());
     cmd
 }
@@ -69,8 +69,12 @@ fn t1_new_creates_spec_with_valid_name() {
         .args(["new", "my-feature"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Created spec:"))
-        .stdout(predicate::str::contains("my-feature.md"));
+        .stdout(
+            predicate::str::is_match(
+                r"Created spec: \.specs/\d{4}-\d{2}-\d{2}-\d{2}-\d{2}-my-feature\.md",
+            )
+            .unwrap(),
+        );
 
     // Verify file exists in .specs/
     let specs = dir.path().join(".specs");
@@ -386,7 +390,7 @@ fn t13_tab_completion_suggests_spec_names() {
     // Trigger the bash dynamic completion mechanism by setting the internal
     // env vars that clap_complete uses when the shell's completion function
     // calls the binary.
-    let mut cmd = Command::cargo_bin("tinyspec").unwrap();
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
     cmd.current_dir(dir.path());
     cmd.env("COMPLETE", "bash");
     cmd.env("_CLAP_COMPLETE_INDEX", "2");
@@ -777,6 +781,81 @@ Some text.
         .stdout(predicate::str::contains("Some text."));
 }
 
+// ─── T.72: View --resolve-check verifies applications silently ──────────────
+
+#[test]
+fn t72_view_resolve_check() {
+    let dir = TempDir::new().unwrap();
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    // Unmapped application → failure listing the missing name
+    fs::write(
+        config_dir.join("config.yaml"),
+        "repositories:\n  other-repo: /path/other\n",
+    )
+    .unwrap();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "hello-world", "--resolve-check"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("my-app"));
+
+    // All applications resolve → success with no output
+    fs::write(
+        config_dir.join("config.yaml"),
+        "repositories:\n  my-app: /resolved/my-app\n",
+    )
+    .unwrap();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "hello-world", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+// ─── T.73: A `-` spec name is read from stdin ────────────────────────────────
+
+#[test]
+fn t73_spec_name_from_stdin() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["status", "-"])
+        .write_stdin("hello-world\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello-world: 0/7 tasks complete"));
+
+    tinyspec(&dir)
+        .args(["check", "-", "A.1", "--no-hooks"])
+        .write_stdin("hello-world\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task A.1"));
+
+    tinyspec(&dir)
+        .args(["view", "-"])
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No spec name provided on stdin"));
+}
+
 // ─── T.27: Init generates updated slash command files ───────────────────────
 
 #[test]
@@ -868,8 +947,12 @@ fn t29_new_grouped_spec() {
         .args(["new", "v1/my-feature"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Created spec:"))
-        .stdout(predicate::str::contains("my-feature.md"));
+        .stdout(
+            predicate::str::is_match(
+                r"Created spec: \.specs/v1/\d{4}-\d{2}-\d{2}-\d{2}-\d{2}-my-feature\.md",
+            )
+            .unwrap(),
+        );
 
     // Verify file exists in .specs/v1/
     let group_dir = dir.path().join(".specs/v1");
@@ -1805,7 +1888,7 @@ fn t52_discover_specs_from_subdirectory() {
     let subdir = dir.path().join("src").join("deep");
     fs::create_dir_all(&subdir).unwrap();
 
-    let mut cmd = Command::cargo_bin("tinyspec").unwrap();
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
     cmd.current_dir(&subdir);
     cmd.args(["list"])
         .assert()
@@ -1845,7 +1928,7 @@ fn t54_new_creates_specs_at_git_root() {
     let subdir = dir.path().join("src").join("deep");
     fs::create_dir_all(&subdir).unwrap();
 
-    let mut cmd = Command::cargo_bin("tinyspec").unwrap();
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
     cmd.current_dir(&subdir);
     cmd.args(["new", "my-spec"])
         .assert()
@@ -2772,3 +2855,3905 @@ p
         "Non-focused spec should not have → marker"
     );
 }
+
+// ─── T.74: templates --json lists each template's variables ─────────────────
+
+#[test]
+fn t74_templates_json_lists_variables() {
+    let dir = TempDir::new().unwrap();
+    let templates_dir = dir.path().join(".specs/templates");
+    fs::create_dir_all(&templates_dir).unwrap();
+    fs::write(
+        templates_dir.join("rust-lib.md"),
+        "---\ntitle: {{title}}\n---\n\nCreated ${date} by {{owner}} for {{title}}.\n",
+    )
+    .unwrap();
+
+    let output = tinyspec(&dir)
+        .args(["templates", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let template = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "rust-lib")
+        .expect("rust-lib template should be listed");
+    assert_eq!(template["source"], "repo");
+    assert!(
+        template["path"]
+            .as_str()
+            .unwrap()
+            .ends_with("templates/rust-lib.md")
+    );
+    assert_eq!(
+        template["variables"],
+        serde_json::json!(["title", "date", "owner"])
+    );
+}
+
+// ─── T.75: A `.specs` file (not directory) produces a clear error ───────────
+
+#[test]
+fn t75_specs_file_not_directory() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(".specs"), "oops").unwrap();
+
+    tinyspec(&dir)
+        .args(["list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            ".specs exists but is not a directory",
+        ));
+
+    tinyspec(&dir)
+        .args(["new", "my-feature"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            ".specs exists but is not a directory",
+        ));
+}
+
+// ─── T.76: --no-format skips the post-write format pass ─────────────────────
+
+#[test]
+fn t76_check_no_format_preserves_layout() {
+    let dir = TempDir::new().unwrap();
+    // Four-space nesting and the extra blank line would be normalized by the formatter
+    let content = "\
+---
+tinySpec: v0
+title: Layout
+---
+
+# Implementation Plan
+
+- [ ] A: First task
+    - [ ] A.1: Subtask
+
+
+- [ ] B: Second task
+";
+    create_sample_spec(&dir, "2025-02-17-09-36-layout.md", content);
+    let path = dir.path().join(".specs/2025-02-17-09-36-layout.md");
+
+    tinyspec(&dir)
+        .args(["check", "layout", "A.1", "--no-format", "--no-hooks"])
+        .assert()
+        .success();
+
+    let updated = fs::read_to_string(&path).unwrap();
+    assert_eq!(updated, content.replace("- [ ] A.1", "- [x] A.1"));
+
+    // Without the flag the formatter rewrites the layout
+    tinyspec(&dir)
+        .args(["check", "layout", "B", "--no-hooks"])
+        .assert()
+        .success();
+
+    let formatted = fs::read_to_string(&path).unwrap();
+    assert_ne!(formatted, updated.replace("- [ ] B", "- [x] B"));
+}
+
+// ─── T.77: Format keeps ordered list numbering and is idempotent ────────────
+
+#[test]
+fn t77_format_preserves_ordered_list_numbering() {
+    let dir = TempDir::new().unwrap();
+    let content = "\
+---
+tinySpec: v0
+title: Steps
+---
+
+# Background
+
+1. Clone the repo
+2. Run the installer
+3. Restart the shell
+
+Continued from an earlier list:
+
+4. Open the dashboard
+5. Select a spec
+";
+    create_sample_spec(&dir, "2025-02-17-09-36-steps.md", content);
+    let path = dir.path().join(".specs/2025-02-17-09-36-steps.md");
+
+    tinyspec(&dir).args(["format", "steps"]).assert().success();
+    let first = fs::read_to_string(&path).unwrap();
+    assert_eq!(first, content, "Ordered lists should round-trip unchanged");
+
+    tinyspec(&dir).args(["format", "steps"]).assert().success();
+    let second = fs::read_to_string(&path).unwrap();
+    assert_eq!(first, second, "Formatting should be idempotent");
+}
+
+// ─── T.78: deps prints the dependency chain and flags problems ──────────────
+
+#[test]
+fn t78_deps_prints_chain_and_flags_unmet() {
+    let dir = TempDir::new().unwrap();
+    let spec = |title: &str, deps: &str, task: &str| {
+        format!(
+            "---\ntinySpec: v0\ntitle: {title}\ndepends_on: [{deps}]\n---\n\n\
+             # Implementation Plan\n\n- [{task}] A: Task\n"
+        )
+    };
+    create_sample_spec(&dir, "2025-04-01-10-00-base.md", &spec("Base", "", "x"));
+    create_sample_spec(
+        &dir,
+        "2025-04-01-10-01-middle.md",
+        &spec("Middle", "base", " "),
+    );
+    create_sample_spec(&dir, "2025-04-01-10-02-top.md", &spec("Top", "middle", " "));
+
+    tinyspec(&dir)
+        .args(["deps", "top"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("✗ middle (pending)"))
+        .stdout(predicate::str::contains("    ✓ base (completed)"))
+        .stdout(predicate::str::contains("1 unmet dependency: middle"));
+
+    tinyspec(&dir)
+        .args(["deps", "base"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(no dependencies)"));
+
+    // Missing references and cycles are errors
+    create_sample_spec(
+        &dir,
+        "2025-04-01-10-00-base.md",
+        &spec("Base", "top, ghost", "x"),
+    );
+    tinyspec(&dir)
+        .args(["deps", "top"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("? ghost (not found)"))
+        .stdout(predicate::str::contains("↻ top (cycle)"))
+        .stderr(predicate::str::contains("unknown specs: ghost"))
+        .stderr(predicate::str::contains("circular dependency detected"));
+}
+
+// ─── T.79: lint warns on Test Plan references to missing tasks ──────────────
+
+#[test]
+fn t79_lint_warns_dangling_test_plan_references() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-04-01-10-00-cross-ref.md",
+        "\
+---
+tinySpec: v0
+title: Cross Ref
+---
+
+# Background
+
+b
+
+# Proposal
+
+p
+
+# Implementation Plan
+
+- [ ] A: Task
+  - [ ] A.1: Subtask
+  - [ ] A.2: Subtask
+
+# Test Plan
+
+- [ ] T.1: A user can run A.2 end to end
+- [ ] T.2: Covers A.1 and C.3
+",
+    );
+
+    let output = tinyspec(&dir).args(["lint", "cross-ref"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Test task 'T.2' references 'C.3'"),
+        "Expected dangling reference warning, got: {stdout}"
+    );
+    assert!(!stdout.contains("references 'A.2'"));
+    assert!(!stdout.contains("references 'A'"));
+}
+
+// ─── T.80: status --count-leaves-only ignores parent tasks ──────────────────
+
+#[test]
+fn t80_status_count_leaves_only() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["status", "hello-world"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0/7 tasks complete"));
+
+    tinyspec(&dir)
+        .args(["status", "hello-world", "--count-leaves-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0/5 tasks complete"));
+}
+
+// ─── T.81: new --from-file creates a spec per line ──────────────────────────
+
+#[test]
+fn t81_new_from_file_creates_each_spec() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("names.txt"),
+        "first-feature\n\n# comment\nv2/grouped-feature\nNot Kebab\nfirst-feature\n",
+    )
+    .unwrap();
+
+    tinyspec(&dir)
+        .args(["new", "--from-file", "names.txt", "--no-hooks"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Created 2 spec(s), 2 failed."))
+        .stderr(predicate::str::contains("line 5: Not Kebab"))
+        .stderr(predicate::str::contains("line 6: first-feature"));
+
+    let specs = dir.path().join(".specs");
+    let top: Vec<_> = fs::read_dir(&specs)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            e.file_name()
+                .to_string_lossy()
+                .ends_with("first-feature.md")
+        })
+        .collect();
+    assert_eq!(top.len(), 1);
+    let grouped: Vec<_> = fs::read_dir(specs.join("v2"))
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .collect();
+    assert_eq!(grouped.len(), 1);
+}
+
+// ─── T.82: check/uncheck `next` resolves the task implicitly ────────────────
+
+#[test]
+fn t82_check_next_resolves_task() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["check", "hello-world", "next", "--no-hooks"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task A.1"));
+
+    tinyspec(&dir)
+        .args(["check", "hello-world", "next", "--no-hooks"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task A.2"));
+
+    // With all subtasks done, the parent itself is next
+    tinyspec(&dir)
+        .args(["check", "hello-world", "next", "--no-hooks"])
+        .assert()
+        .success()
+        .stdout("Checked task A\n");
+
+    tinyspec(&dir)
+        .args(["uncheck", "hello-world", "next", "--no-hooks"])
+        .assert()
+        .success()
+        .stdout("Unchecked task A\n");
+}
+
+// ─── T.83: Init distinguishes up-to-date from stale skill files ─────────────
+
+#[test]
+fn t83_init_reports_up_to_date_and_stale_skills() {
+    let dir = TempDir::new().unwrap();
+    tinyspec(&dir).args(["init"]).assert().success();
+
+    let refine = dir.path().join(".claude/skills/tinyspec-refine/SKILL.md");
+    fs::write(&refine, "custom content").unwrap();
+
+    tinyspec(&dir)
+        .args(["init"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Skipped tinyspec-refine/SKILL.md (differs — use --force to update)",
+        ))
+        .stdout(predicate::str::contains(
+            "Skipped tinyspec-do/SKILL.md (up to date)",
+        ));
+}
+
+// ─── T.84: graph emits a Mermaid flowchart of the task tree ─────────────────
+
+#[test]
+fn t84_graph_emits_mermaid() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["graph", "hello-world"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("flowchart TD"))
+        .stdout(predicate::str::contains("impl_1_2[\"B.3: Subtask three\"]"))
+        .stdout(predicate::str::contains("impl_1 --> impl_1_2"));
+
+    tinyspec(&dir)
+        .args(["graph", "hello-world", "--out", "graph.mmd"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote graph to graph.mmd"));
+    let written = fs::read_to_string(dir.path().join("graph.mmd")).unwrap();
+    assert!(written.starts_with("flowchart TD"));
+}
+
+// ─── T.85: reindex writes _index.md, which is excluded from spec scans ──────
+
+#[test]
+fn t85_reindex_and_list_index() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["list", "--index"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("tinyspec reindex"));
+
+    tinyspec(&dir)
+        .args(["reindex"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(1 spec(s))"));
+
+    let index = fs::read_to_string(dir.path().join(".specs/_index.md")).unwrap();
+    assert!(index.contains("hello-world"));
+    assert!(index.contains("Hello World"));
+    assert!(index.contains("pending"));
+
+    tinyspec(&dir)
+        .args(["list", "--index"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("# Spec Index"));
+
+    // The index itself never shows up as a spec
+    let output = tinyspec(&dir).args(["list"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("_index"), "got: {stdout}");
+    tinyspec(&dir)
+        .args(["format", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("_index").not());
+}
+
+// ─── T.86: Lenient view substitutes what it can and warns on the rest ───────
+
+#[test]
+fn t86_view_lenient_application_resolution() {
+    let dir = TempDir::new().unwrap();
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("config.yaml"),
+        "repositories:\n  other-repo: /path/other\n",
+    )
+    .unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "hello-world", "--lenient"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("- my-app"))
+        .stderr(predicate::str::contains("Warning:"))
+        .stderr(predicate::str::contains("my-app"));
+
+    // Config can make lenient the default; --strict restores fail-fast
+    fs::write(
+        config_dir.join("config.yaml"),
+        "repositories:\n  other-repo: /path/other\nlenient_applications: true\n",
+    )
+    .unwrap();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "hello-world"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "hello-world", "--strict"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found in config"));
+}
+
+// ─── T.87: Shell completion for configured repository names ─────────────────
+
+#[test]
+fn t87_config_remove_completes_repo_names() {
+    let dir = TempDir::new().unwrap();
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("config.yaml"),
+        "repositories:\n  my-app: /path/app\n  other-repo: /path/other\n",
+    )
+    .unwrap();
+
+    let complete = |config_home: &std::path::Path| {
+        let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
+        cmd.current_dir(dir.path());
+        cmd.env("TINYSPEC_HOME", config_home.to_str().unwrap());
+        cmd.env("COMPLETE", "bash");
+        cmd.env("_CLAP_COMPLETE_INDEX", "3");
+        cmd.env("_CLAP_COMPLETE_COMP_TYPE", "9");
+        cmd.env("_CLAP_COMPLETE_SPACE", "true");
+        cmd.args(["--", "tinyspec", "config", "remove", ""]);
+        String::from_utf8_lossy(&cmd.output().unwrap().stdout).to_string()
+    };
+
+    let stdout = complete(&config_dir);
+    assert!(
+        stdout.contains("my-app") && stdout.contains("other-repo"),
+        "Expected completions for my-app and other-repo, got: {stdout}"
+    );
+
+    // No config file: no candidates, no failure
+    let stdout = complete(&dir.path().join("missing"));
+    assert!(
+        !stdout.contains("my-app"),
+        "Unexpected completions: {stdout}"
+    );
+}
+
+// ─── T.88: Project config can point specs_dir outside the repo ──────────────
+
+#[test]
+fn t88_external_specs_dir_via_project_config() {
+    let root = TempDir::new().unwrap();
+    let repo = root.path().join("repo");
+    let docs = root.path().join("docs-specs");
+    fs::create_dir_all(&repo).unwrap();
+    fs::write(
+        repo.join(".tinyspec.yaml"),
+        format!("specs_dir: {}\n", docs.display()),
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
+        cmd.current_dir(&repo);
+        cmd.args(args);
+        cmd.assert()
+    };
+
+    run(&["new", "external-spec"]).success();
+    run(&["new", "v1/grouped-spec"]).success();
+    assert!(!repo.join(".specs").exists());
+
+    let files: Vec<_> = fs::read_dir(&docs)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    assert!(files.iter().any(|f| f.ends_with("-external-spec.md")));
+    assert!(docs.join("v1").is_dir());
+
+    run(&["list"])
+        .success()
+        .stdout(predicate::str::contains("external-spec"))
+        .stdout(predicate::str::contains("v1/"))
+        .stdout(predicate::str::contains("grouped-spec"));
+    run(&["view", "grouped-spec"]).success();
+}
+
+// ─── T.89: Deleting or archiving the last spec in a group removes the group ─
+
+#[test]
+fn t89_empty_group_dir_removed() {
+    let dir = TempDir::new().unwrap();
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    create_grouped_spec(
+        &dir,
+        "v2",
+        "2025-02-17-09-37-other-spec.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["delete", "hello-world"])
+        .write_stdin("y\n")
+        .assert()
+        .success();
+    assert!(!dir.path().join(".specs/v1").exists());
+    assert!(dir.path().join(".specs").is_dir());
+
+    tinyspec(&dir)
+        .args(["archive", "other-spec"])
+        .assert()
+        .success();
+    assert!(!dir.path().join(".specs/v2").exists());
+    assert!(dir.path().join(".specs/archive/v2").is_dir());
+
+    tinyspec(&dir)
+        .args(["unarchive", "other-spec"])
+        .assert()
+        .success();
+    assert!(dir.path().join(".specs/v2").is_dir());
+    assert!(!dir.path().join(".specs/archive/v2").exists());
+}
+
+// ─── T.90: `new` strips an accidental .md suffix ────────────────────────────
+
+#[test]
+fn t90_new_strips_md_suffix() {
+    let dir = TempDir::new().unwrap();
+
+    tinyspec(&dir)
+        .args(["new", "v1/my-feature.md"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("added automatically"));
+
+    let files: Vec<_> = fs::read_dir(dir.path().join(".specs/v1"))
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(files.len(), 1);
+    assert!(files[0].ends_with("-my-feature.md"), "got {files:?}");
+
+    // Still reject genuinely invalid names
+    tinyspec(&dir)
+        .args(["new", "My_Feature.md"])
+        .assert()
+        .failure();
+}
+
+// ─── T.91: `list --table` renders aligned columns ───────────────────────────
+
+#[test]
+fn t91_list_table_aligns_columns() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-18-10-00-a-much-longer-spec-name.md",
+        "---\ntinySpec: v0\ntitle: Long\n---\n\n# Implementation Plan\n\n- [x] A: Done\n",
+    );
+
+    let output = tinyspec(&dir).args(["list", "--table"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3, "got: {stdout}");
+    assert!(lines[0].contains("SPEC") && lines[0].contains("PROGRESS"));
+    assert!(lines[2].contains("v1/a-much-longer-spec-name"));
+
+    // The TITLE column starts at the same offset on every row
+    let title_col = lines[0].find("TITLE").unwrap();
+    assert_eq!(lines[1].find("Hello World"), Some(title_col));
+    assert_eq!(lines[2].find("Long"), Some(title_col));
+
+    // Without --table (and not a TTY) the simple format is kept
+    tinyspec(&dir)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("SPEC").not());
+}
+
+// ─── T.92: `view --plan` prints only the Implementation Plan ────────────────
+
+#[test]
+fn t92_view_plan_section() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content().replace("applications:\n    - my-app\n", "");
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+
+    tinyspec(&dir)
+        .args(["view", "hello-world", "--plan"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("# Implementation Plan"))
+        .stdout(predicate::str::contains("- [ ] A.1: Do this subtask"))
+        .stdout(predicate::str::contains("Some background").not())
+        .stdout(predicate::str::contains("# Test Plan").not());
+
+    let output = tinyspec(&dir)
+        .args(["view", "hello-world", "--plan", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let tasks: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(tasks[0]["id"], "A");
+    assert_eq!(tasks[1]["children"][2]["id"], "B.3");
+}
+
+// ─── T.93: Format preserves front matter closed at end of file ──────────────
+
+#[test]
+fn t93_format_preserves_front_matter_without_trailing_newline() {
+    let dir = TempDir::new().unwrap();
+    let front_matter = "---\ntinySpec: v0\ntitle: Only Front Matter\ntags:\n  - auth\n---";
+    create_sample_spec(&dir, "2025-02-17-09-36-fm-only.md", front_matter);
+
+    tinyspec(&dir)
+        .args(["format", "fm-only"])
+        .assert()
+        .success();
+
+    let result = fs::read_to_string(dir.path().join(".specs/2025-02-17-09-36-fm-only.md")).unwrap();
+    assert_eq!(result, format!("{front_matter}\n"));
+}
+
+// ─── T.94: Application substitution never rewrites substituted text ─────────
+
+#[test]
+fn t94_view_substitutes_applications_in_one_pass() {
+    let dir = TempDir::new().unwrap();
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("config.yaml"),
+        "repositories:\n  app: /srv/app\n  srv: /opt/services\n  web: api\n  api: /code/api\n",
+    )
+    .unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-multi-app.md",
+        "---\ntinySpec: v0\ntitle: Multi\napplications:\n  - app\n  - srv\n  - web\n  - api\n---\n\n\
+         Self: app. Substring: srv. Chained: web then api.\n",
+    );
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "multi-app"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Self: /srv/app. Substring: /opt/services. Chained: api then /code/api.",
+        ))
+        .stdout(predicate::str::contains("/opt/services/app").not());
+}
+
+// ─── T.95: `init --list-skills` / `--print` preview without writing ─────────
+
+#[test]
+fn t95_init_previews_skills_without_writing() {
+    let dir = TempDir::new().unwrap();
+
+    tinyspec(&dir)
+        .args(["init", "--list-skills"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("tinyspec-refine"))
+        .stdout(predicate::str::contains("tinyspec-oneshot"))
+        .stdout(predicate::str::contains(
+            "Implement a single task from a spec's Implementation Plan",
+        ));
+
+    tinyspec(&dir)
+        .args(["init", "--print", "tinyspec-task"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("---\nname: tinyspec:task"));
+
+    tinyspec(&dir)
+        .args(["init", "--print", "nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown skill 'nope'"));
+
+    assert!(!dir.path().join(".claude").exists());
+}
+
+// ─── T.96: Non-UTF-8 spec files are skipped in batch commands ───────────────
+
+#[test]
+fn t96_non_utf8_spec_is_skipped_with_warning() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    fs::write(
+        dir.path().join(".specs/2025-02-17-09-37-broken.md"),
+        b"---\ntitle: Broken\n---\n\n\xff\xfe not utf-8\n",
+    )
+    .unwrap();
+
+    for args in [
+        vec!["list"],
+        vec!["list", "--json"],
+        vec!["status"],
+        vec!["format", "--all"],
+        vec!["search", "Do this"],
+    ] {
+        tinyspec(&dir)
+            .args(&args)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("hello-world"))
+            .stderr(predicate::str::contains(
+                "broken.md is not valid UTF-8; skipping it",
+            ))
+            .stderr(predicate::str::contains("broken.md").count(1));
+    }
+
+    tinyspec(&dir)
+        .args(["format", "broken"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not valid UTF-8"));
+    tinyspec(&dir)
+        .args(["status", "broken"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not valid UTF-8"));
+}
+
+// ─── T.97: `view --all` concatenates every spec in list order ───────────────
+
+#[test]
+fn t97_view_all_concatenates_specs() {
+    let dir = TempDir::new().unwrap();
+    let config_dir = dir.path().join(".nonexistent-config");
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-17-09-30-grouped.md",
+        "---\ntinySpec: v0\ntitle: Grouped\n---\n\nGrouped body.\n",
+    );
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-40-plain.md",
+        "---\ntinySpec: v0\ntitle: Plain\n---\n\nPlain body.\n",
+    );
+
+    let output = tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "--all"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let hello = stdout.find("===== hello-world =====").unwrap();
+    let plain = stdout.find("===== plain =====").unwrap();
+    let grouped = stdout.find("===== v1/grouped =====").unwrap();
+    assert!(hello < plain && plain < grouped, "got: {stdout}");
+    assert!(stdout.contains("Plain body.") && stdout.contains("Grouped body."));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Warning:"));
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "--all", "--strict"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no config file found"));
+}
+
+// ─── T.98: check/uncheck accept a single `spec:task` argument ───────────────
+
+#[test]
+fn t98_check_accepts_spec_colon_task() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    let path = dir.path().join(".specs/2025-02-17-09-36-hello-world.md");
+
+    tinyspec(&dir)
+        .args(["check", "hello-world:A.1"])
+        .assert()
+        .success();
+    assert!(fs::read_to_string(&path).unwrap().contains("- [x] A.1:"));
+
+    tinyspec(&dir)
+        .args(["uncheck", "hello-world:A.1"])
+        .assert()
+        .success();
+    assert!(fs::read_to_string(&path).unwrap().contains("- [ ] A.1:"));
+
+    tinyspec(&dir)
+        .args(["check", "hello-world"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Missing task ID"));
+}
+
+// ─── T.99: `status --group-by application` pivots by application ────────────
+
+#[test]
+fn t99_status_group_by_application() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-api-work.md",
+        "---\ntinySpec: v0\ntitle: API\napplications:\n  - api\n  - web\n---\n\n\
+         # Implementation Plan\n\n- [x] A: One\n- [ ] B: Two\n",
+    );
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-37-web-work.md",
+        "---\ntinySpec: v0\ntitle: Web\napplications:\n  - web\n---\n\n\
+         # Implementation Plan\n\n- [x] A: One\n- [x] B: Two\n- [ ] C: Three\n",
+    );
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-38-docs.md",
+        "---\ntinySpec: v0\ntitle: Docs\n---\n\n# Implementation Plan\n\n- [ ] A: One\n",
+    );
+
+    tinyspec(&dir)
+        .args(["status", "--group-by", "application"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "api: 1/2 tasks complete across 1 spec(s)",
+        ))
+        .stdout(predicate::str::contains(
+            "web: 3/5 tasks complete across 2 spec(s)",
+        ))
+        .stdout(predicate::str::contains(
+            "(no application): 0/1 tasks complete across 1 spec(s)",
+        ))
+        .stdout(predicate::str::contains(
+            "  [M] web-work: 2/3 tasks complete",
+        ));
+
+    let output = tinyspec(&dir)
+        .args(["status", "--group-by", "application", "--json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[1]["application"], "web");
+    assert_eq!(json[1]["checked"], 3);
+    assert_eq!(json[1]["total"], 5);
+
+    tinyspec(&dir)
+        .args(["status", "--group-by", "owner"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --group-by value"));
+}
+
+// ─── T.100: Format keeps blank lines inside code blocks ─────────────────────
+
+#[test]
+fn t100_format_preserves_blank_lines_in_code_blocks() {
+    let dir = TempDir::new().unwrap();
+    let code = "fn a() {}\n\n\nfn b() {\n\n    let x = 1;\n}\n";
+    let content = format!(
+        "---\ntinySpec: v0\ntitle: Code\n---\n\n# Background\n\n```rust\n{code}```\n\n\
+         - Step\n\n  ```sh\n  echo a\n\n  echo b\n  ```\n"
+    );
+    create_sample_spec(&dir, "2025-02-17-09-36-code.md", &content);
+
+    tinyspec(&dir).args(["format", "code"]).assert().success();
+
+    let result = fs::read_to_string(dir.path().join(".specs/2025-02-17-09-36-code.md")).unwrap();
+    assert!(
+        result.contains(&format!("```rust\n{code}```\n")),
+        "fenced code changed:\n{result}"
+    );
+    // Nested in a list item the blank line survives (only container indentation may differ)
+    let nested: Vec<&str> = result
+        .lines()
+        .skip_while(|l| !l.trim_start().starts_with("```sh"))
+        .skip(1)
+        .take(3)
+        .map(str::trim)
+        .collect();
+    assert_eq!(nested, ["echo a", "", "echo b"], "got:\n{result}");
+}
+
+// ─── T.101: `new --group` is equivalent to the group/name syntax ────────────
+
+#[test]
+fn t101_new_with_group_flag() {
+    let dir = TempDir::new().unwrap();
+
+    tinyspec(&dir)
+        .args(["new", "my-feature", "--group", "v1"])
+        .assert()
+        .success();
+    let files: Vec<_> = fs::read_dir(dir.path().join(".specs/v1"))
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    assert!(files.iter().any(|f| f.ends_with("-my-feature.md")));
+
+    tinyspec(&dir)
+        .args(["new", "v2/other", "--group", "v1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already includes a group"));
+
+    tinyspec(&dir)
+        .args(["new", "other", "--group", "Bad_Group"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid group name"));
+}
+
+// ─── T.102: JSON summaries include the spec path relative to .specs/ ────────
+
+#[test]
+fn t102_summary_json_includes_relative_path() {
+    let dir = TempDir::new().unwrap();
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    for args in [vec!["list", "--json"], vec!["status", "--json"]] {
+        let output = tinyspec(&dir).args(&args).output().unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json[0]["path"], "v1/2025-02-17-09-36-hello-world.md");
+    }
+
+    let output = tinyspec(&dir)
+        .args(["status", "hello-world", "--json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["path"], "v1/2025-02-17-09-36-hello-world.md");
+}
+
+// ─── T.103: Interactive check needs a terminal ──────────────────────────────
+
+#[test]
+fn t103_check_interactive_requires_terminal() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["check", "hello-world", "--interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("requires a terminal"));
+
+    tinyspec(&dir)
+        .args(["check", "hello-world", "A.1", "--interactive"])
+        .assert()
+        .failure();
+
+    let content =
+        fs::read_to_string(dir.path().join(".specs/2025-02-17-09-36-hello-world.md")).unwrap();
+    assert_eq!(content, sample_spec_content());
+}
+
+// ─── T.104: `format --all` summary and --check mode ─────────────────────────
+
+#[test]
+fn t104_format_all_summary_and_check() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-01-01-10-00-messy.md",
+        "---\ntinySpec: v0\ntitle: Messy\n---\n# Background\n*  item\n",
+    );
+    create_sample_spec(
+        &dir,
+        "2025-01-01-10-01-clean.md",
+        "---\ntinySpec: v0\ntitle: Clean\n---\n\n# Background\n\nText.\n",
+    );
+    let messy = dir.path().join(".specs/2025-01-01-10-00-messy.md");
+    let before = fs::read_to_string(&messy).unwrap();
+
+    tinyspec(&dir)
+        .args(["format", "--all", "--check"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Would reformat 2025-01-01-10-00-messy.md",
+        ))
+        .stdout(predicate::str::contains(
+            "1 of 2 spec(s) would be reformatted",
+        ));
+    assert_eq!(fs::read_to_string(&messy).unwrap(), before);
+
+    tinyspec(&dir)
+        .args(["format", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Formatted 2 spec(s) (1 changed)"));
+
+    tinyspec(&dir)
+        .args(["format", "--all", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "0 of 2 spec(s) would be reformatted",
+        ));
+}
+
+// ─── T.105: `config path` prints the resolved config location ───────────────
+
+#[test]
+fn t105_config_path() {
+    let dir = TempDir::new().unwrap();
+    let config_dir = dir.path().join(".tinyspec-config");
+    let expected = config_dir.join("config.yaml");
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["config", "path"])
+        .assert()
+        .success()
+        .stdout(format!("{} (not found)\n", expected.display()));
+
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(&expected, "repositories: {}\n").unwrap();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["config", "path"])
+        .assert()
+        .success()
+        .stdout(format!("{} (exists)\n", expected.display()));
+}
+
+// ─── T.106: Dashboard refresh interval is range-checked ─────────────────────
+
+#[test]
+fn t106_dashboard_interval_validated() {
+    let dir = TempDir::new().unwrap();
+
+    tinyspec(&dir)
+        .args(["dashboard", "--interval", "10"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("between 50 and 5000 ms (got 10)"));
+
+    fs::write(
+        dir.path().join(".tinyspec.yaml"),
+        "dashboard:\n  refresh_ms: 60000\n",
+    )
+    .unwrap();
+    tinyspec(&dir)
+        .arg("dashboard")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("(got 60000)"));
+
+    // A valid interval gets past validation (then fails for lack of a terminal)
+    tinyspec(&dir)
+        .args(["dashboard", "--interval", "1000"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("interactive terminal"));
+}
+
+// ─── T.107: add-task assigns the next ID and validates explicit ones ────────
+
+#[test]
+fn t107_add_task_assigns_and_validates_ids() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Wire it up", "--no-format"])
+        .assert()
+        .success()
+        .stdout("Added task C: Wire it up\n");
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Edge case", "--under", "A"])
+        .assert()
+        .success()
+        .stdout("Added task A.3: Edge case\n");
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Covers A", "--test"])
+        .assert()
+        .success()
+        .stdout("Added task T.1: Covers A\n");
+
+    let content =
+        fs::read_to_string(dir.path().join(".specs/2025-02-17-09-36-hello-world.md")).unwrap();
+    let a2 = content.find("A.2: Do this other subtask").unwrap();
+    let a3 = content.find("A.3: Edge case").unwrap();
+    let b = content.find("B: Do that").unwrap();
+    let c = content.find("- [ ] C: Wire it up").unwrap();
+    let t1 = content.find("- [ ] T.1: Covers A").unwrap();
+    assert!(a2 < a3 && a3 < b && b < c && c < t1);
+
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Dup", "--id", "B.1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Task 'B.1' already exists"));
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Bad", "--id", "b1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid task ID 'b1'"));
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Orphan", "--under", "Q"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No task 'Q' found"));
+
+    // A dotted --id lands under its parent; --under also accepts a subtask
+    tinyspec(&dir)
+        .args([
+            "add-task",
+            "hello-world",
+            "Explicit",
+            "--id",
+            "A.5",
+            "--no-format",
+        ])
+        .assert()
+        .success()
+        .stdout("Added task A.5: Explicit\n");
+    tinyspec(&dir)
+        .args([
+            "add-task",
+            "hello-world",
+            "Deeper",
+            "--under",
+            "A.1",
+            "--no-format",
+        ])
+        .assert()
+        .success()
+        .stdout("Added task A.1.1: Deeper\n");
+    let content =
+        fs::read_to_string(dir.path().join(".specs/2025-02-17-09-36-hello-world.md")).unwrap();
+    assert!(content.contains("\n    - [ ] A.1.1: Deeper\n  - [ ] A.2: "));
+    assert!(content.contains("\n  - [ ] A.3: Edge case\n  - [ ] A.5: Explicit\n- [ ] B: "));
+
+    // check rejects malformed IDs before searching
+    tinyspec(&dir)
+        .args(["check", "hello-world", "a.1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid task ID 'a.1'"));
+}
+
+// ─── T.108: Config `sections` replaces the default scaffold headings ────────
+
+#[test]
+fn t108_new_uses_configured_sections() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join(".tinyspec.yaml"),
+        "sections:\n  - Context\n  - Goals\n  - Implementation Plan\n",
+    )
+    .unwrap();
+
+    tinyspec(&dir).args(["new", "custom"]).assert().success();
+
+    let entry = fs::read_dir(dir.path().join(".specs"))
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.extension().is_some_and(|e| e == "md"))
+        .unwrap();
+    let content = fs::read_to_string(entry).unwrap();
+    let context = content.find("# Context").unwrap();
+    let goals = content.find("# Goals").unwrap();
+    let plan = content.find("# Implementation Plan").unwrap();
+    assert!(context < goals && goals < plan);
+    assert!(content.contains("- [ ] A:"));
+    assert!(!content.contains("# Background"));
+    assert!(!content.contains("# Test Plan"));
+}
+
+// ─── T.109: dedupe reports duplicate spec names and trashes stale copies ────
+
+#[test]
+fn t109_dedupe_duplicate_specs() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content();
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+    create_grouped_spec(&dir, "v1", "2025-01-01-00-00-hello-world.md", &content);
+    create_sample_spec(&dir, "2025-02-18-10-00-unique.md", &content);
+
+    tinyspec(&dir)
+        .arg("dedupe")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello-world: 2 files"))
+        .stdout(predicate::str::contains(
+            "keep    2025-02-17-09-36-hello-world.md",
+        ))
+        .stdout(predicate::str::contains(
+            "stale   v1/2025-01-01-00-00-hello-world.md",
+        ))
+        .stdout(predicate::str::contains("unique").not());
+    assert!(dir.path().join(".specs/v1").exists());
+
+    tinyspec(&dir)
+        .args(["dedupe", "--apply"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "trashed v1/2025-01-01-00-00-hello-world.md -> .trash/v1/2025-01-01-00-00-hello-world.md",
+        ))
+        .stdout(predicate::str::contains(
+            "Moved 1 stale file(s) to .specs/.trash.",
+        ));
+    assert!(
+        dir.path()
+            .join(".specs/2025-02-17-09-36-hello-world.md")
+            .exists()
+    );
+    assert!(!dir.path().join(".specs/v1").exists());
+    // The stale copy is recoverable, and the trash is never treated as a group
+    assert!(
+        dir.path()
+            .join(".specs/.trash/v1/2025-01-01-00-00-hello-world.md")
+            .exists()
+    );
+    tinyspec(&dir)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(".trash").not());
+
+    tinyspec(&dir)
+        .arg("dedupe")
+        .assert()
+        .success()
+        .stdout("No duplicate specs found.\n");
+}
+
+// ─── T.110: --application fills the applications list, templates included ───
+
+#[test]
+fn t110_new_with_template_and_applications() {
+    let dir = TempDir::new().unwrap();
+    let templates = dir.path().join(".specs/templates");
+    fs::create_dir_all(&templates).unwrap();
+    fs::write(
+        templates.join("service.md"),
+        "---\ntinySpec: v0\ntitle: {{title}}\napplications:\n    -\npriority: high\n---\n\n# Background\n",
+    )
+    .unwrap();
+
+    tinyspec(&dir)
+        .args([
+            "new",
+            "billing",
+            "--template",
+            "service",
+            "--application",
+            "api",
+            "-a",
+            "web",
+        ])
+        .assert()
+        .success();
+
+    let path = fs::read_dir(dir.path().join(".specs"))
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.to_string_lossy().ends_with("-billing.md"))
+        .unwrap();
+    let content = fs::read_to_string(path).unwrap();
+    assert!(
+        content.contains("applications:\n    - api\n    - web\npriority: high\n"),
+        "{content}"
+    );
+
+    tinyspec(&dir)
+        .args(["view", "billing", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"api\""))
+        .stdout(predicate::str::contains("\"web\""));
+}
+
+// ─── T.111: status --tree prints the task hierarchy ─────────────────────────
+
+#[test]
+fn t111_status_tree() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content().replace("- [ ] A.1:", "- [x] A.1:"),
+    );
+
+    tinyspec(&dir)
+        .args(["status", "hello-world", "--tree"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Implementation Plan\n  ☐ A: Do this\n    ✓ A.1: Do this subtask\n    ☐ A.2: Do this other subtask\n  ☐ B: Do that\n",
+        ))
+        .stdout(predicate::str::contains("\x1b[").not());
+
+    tinyspec(&dir).args(["status", "--tree"]).assert().failure();
+}
+
+// ─── T.112: edit prefers TINYSPEC_EDITOR, then config, then EDITOR ──────────
+
+#[test]
+fn t112_edit_editor_precedence() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    let log = dir.path().join("editor.log");
+    let script = dir.path().join("fake-editor.sh");
+    fs::write(&script, format!("echo \"$@\" >> '{}'\n", log.display())).unwrap();
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+
+    // Multi-word TINYSPEC_EDITOR wins over EDITOR
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .env("EDITOR", "false")
+        .env(
+            "TINYSPEC_EDITOR",
+            format!("sh '{}' --wait", script.display()),
+        )
+        .args(["edit", "hello-world"])
+        .assert()
+        .success();
+
+    // Config `editor` is used when TINYSPEC_EDITOR is unset
+    fs::write(
+        config_dir.join("config.yaml"),
+        format!("editor: sh '{}' --config\n", script.display()),
+    )
+    .unwrap();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .env("EDITOR", "false")
+        .env_remove("TINYSPEC_EDITOR")
+        .args(["edit", "hello-world"])
+        .assert()
+        .success();
+
+    let logged = fs::read_to_string(&log).unwrap();
+    let lines: Vec<&str> = logged.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("--wait ") && lines[0].ends_with("-hello-world.md"));
+    assert!(lines[1].starts_with("--config ") && lines[1].ends_with("-hello-world.md"));
+}
+
+// ─── T.113: templates add/sync fetch into the user templates dir ────────────
+
+#[cfg(feature = "remote-templates")]
+#[test]
+fn t113_templates_add_and_sync() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path().join("home");
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&home).unwrap();
+    fs::create_dir_all(&config_dir).unwrap();
+    let user_templates = home.join(".config/tinyspec/templates");
+
+    // Single file by URL
+    let shared = dir.path().join("shared");
+    fs::create_dir_all(&shared).unwrap();
+    fs::write(shared.join("rfc.md"), "# RFC {{title}}\n").unwrap();
+    let url = format!("file://{}", shared.join("rfc.md").display());
+    tinyspec(&dir)
+        .env("HOME", &home)
+        .args(["template", "add", &url])
+        .assert()
+        .success()
+        .stdout(format!("Added template 'rfc' from {url}\n"));
+    assert!(user_templates.join("rfc.md").exists());
+
+    tinyspec(&dir)
+        .env("HOME", &home)
+        .args(["templates", "add", &url])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    // Plain http:// is refused rather than fetched unencrypted
+    tinyspec(&dir)
+        .env("HOME", &home)
+        .args(["templates", "add", "http://example.com/rfc.md"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("over plain http://"));
+
+    // Every template in a git repository, via template_sources
+    let repo = dir.path().join("team-templates.git");
+    fs::create_dir_all(repo.join("templates")).unwrap();
+    fs::write(repo.join("README.md"), "docs\n").unwrap();
+    fs::write(repo.join("templates/bugfix.md"), "# Bug\n").unwrap();
+    fs::write(repo.join("templates/feature.md"), "# Feature\n").unwrap();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+            .args(args)
+            .current_dir(&repo)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success());
+    };
+    git(&["init", "--quiet"]);
+    git(&["add", "."]);
+    git(&["commit", "--quiet", "-m", "templates"]);
+
+    fs::write(
+        config_dir.join("config.yaml"),
+        format!("template_sources:\n  - {}\n", repo.display()),
+    )
+    .unwrap();
+    tinyspec(&dir)
+        .env("HOME", &home)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["templates", "sync"])
+        .assert()
+        .success()
+        .stdout("Synced 2 template(s) from 1 source(s).\n");
+    assert!(user_templates.join("bugfix.md").exists());
+    assert!(user_templates.join("feature.md").exists());
+    assert!(!user_templates.join("README.md").exists());
+
+    tinyspec(&dir)
+        .env("HOME", &home)
+        .arg("templates")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("bugfix"))
+        .stdout(predicate::str::contains("rfc"));
+}
+
+// ─── T.114: list shows a status glyph and percentage per spec ───────────────
+
+#[test]
+fn t114_list_shows_inline_status() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content().replace("- [ ] A", "- [x] A"),
+    );
+    create_sample_spec(&dir, "2025-02-18-09-36-fresh.md", &sample_spec_content());
+
+    let output = tinyspec(&dir).arg("list").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let hello = stdout.lines().find(|l| l.contains("hello-world")).unwrap();
+    let fresh = stdout.lines().find(|l| l.contains("fresh")).unwrap();
+    assert!(
+        hello.contains("● hello-world") && hello.contains(" 42%"),
+        "{hello}"
+    );
+    assert!(
+        fresh.contains("○ fresh") && fresh.contains("  0%"),
+        "{fresh}"
+    );
+
+    tinyspec(&dir)
+        .args(["list", "--no-status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello-world"))
+        .stdout(predicate::str::contains("%").not())
+        .stdout(predicate::str::contains("●").not());
+}
+
+// ─── T.115: Local commands work without HOME ────────────────────────────────
+
+#[test]
+fn t115_commands_work_without_home() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    fs::create_dir_all(dir.path().join(".specs/templates")).unwrap();
+    fs::write(
+        dir.path().join(".specs/templates/default.md"),
+        "---\ntitle: {{title}}\n---\n\n# From Default\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join(".tinyspec.yaml"),
+        "hooks:\n  on_task_check:\n    - touch hook-ran\n",
+    )
+    .unwrap();
+
+    let no_home = || {
+        let mut cmd = tinyspec(&dir);
+        cmd.env_remove("HOME").env_remove("TINYSPEC_HOME");
+        cmd
+    };
+
+    no_home()
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello-world"));
+    no_home()
+        .arg("templates")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("default"));
+    no_home().args(["new", "fresh"]).assert().success();
+    no_home()
+        .args(["view", "fresh"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("# From Default"));
+    no_home()
+        .args(["check", "hello-world", "A.1"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("HOME").not());
+    assert!(dir.path().join("hook-ran").exists());
+
+    // Writing the user config still needs a location
+    no_home()
+        .args(["config", "set", "app", "/tmp/app"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("set HOME or TINYSPEC_HOME"));
+}
+
+// ─── T.116: status --failed lists specs with failing test results ───────────
+
+#[test]
+fn t116_status_failed() {
+    let dir = TempDir::new().unwrap();
+    let with_results = sample_spec_content()
+        + "- [x] T.1: Happy path (result: pass)\n- [ ] T.2: Rejects bad input (result: fail)\n";
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &with_results);
+    create_sample_spec(
+        &dir,
+        "2025-02-18-09-36-all-green.md",
+        &(sample_spec_content() + "- [x] T.1: Works (result: pass)\n"),
+    );
+
+    tinyspec(&dir)
+        .args(["status", "--failed"])
+        .assert()
+        .success()
+        .stdout("[M] hello-world: 1 failing test(s): T.2\n");
+
+    tinyspec(&dir)
+        .args(["status", "--failed", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"hello-world\""))
+        .stdout(predicate::str::contains("all-green").not());
+
+    fs::remove_file(dir.path().join(".specs/2025-02-17-09-36-hello-world.md")).unwrap();
+    tinyspec(&dir)
+        .args(["status", "--failed"])
+        .assert()
+        .success()
+        .stdout("No failing tests.\n");
+}
+
+// ─── T.117: new refuses to write outside .specs ─────────────────────────────
+
+#[cfg(unix)]
+#[test]
+fn t117_new_rejects_paths_outside_specs_dir() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".specs")).unwrap();
+
+    for input in ["../evil", "../../evil", "./evil/feature"] {
+        tinyspec(&dir).args(["new", input]).assert().failure();
+    }
+    tinyspec(&dir)
+        .args(["new", "feature", "--group", ".."])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid group name '..'"));
+
+    // A group directory that is a symlink pointing elsewhere is refused too
+    let outside = dir.path().join("outside");
+    fs::create_dir_all(&outside).unwrap();
+    std::os::unix::fs::symlink(&outside, dir.path().join(".specs/linked")).unwrap();
+    tinyspec(&dir)
+        .args(["new", "linked/feature"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Refusing to write outside the specs directory",
+        ));
+
+    assert_eq!(fs::read_dir(&outside).unwrap().count(), 0);
+    assert!(!dir.path().join("evil").exists());
+}
+
+// ─── T.118: status --owner counts only @owner's tasks ───────────────────────
+
+#[test]
+fn t118_status_by_owner() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content()
+            .replace("A.1: Do this subtask", "A.1: @alice Do this subtask")
+            .replace("- [ ] B.2: Subtask two", "- [x] B.2: @alice Subtask two")
+            .replace("B.3: Subtask three", "B.3: @bob Subtask three"),
+    );
+    create_sample_spec(
+        &dir,
+        "2025-02-18-09-36-other.md",
+        &(sample_spec_content() + "- [ ] T.1: @alice: Covers it\n"),
+    );
+
+    tinyspec(&dir)
+        .args(["status", "--owner", "alice"])
+        .assert()
+        .success()
+        .stdout(
+            "[M] hello-world: 1/2 tasks complete\n\
+             [M] other: 0/1 tasks complete\n\
+             \n\
+             @alice: 1/3 tasks complete across 2 spec(s)\n",
+        );
+
+    tinyspec(&dir)
+        .args(["status", "--owner", "@bob", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"B.3\""))
+        .stdout(predicate::str::contains("other").not());
+
+    tinyspec(&dir)
+        .args(["status", "--owner", "carol"])
+        .assert()
+        .success()
+        .stdout("No tasks assigned to @carol.\n");
+}
+
+// ─── T.119: format normalises blank lines at the front matter and EOF ───────
+
+#[test]
+fn t119_format_normalises_edge_blank_lines() {
+    let dir = TempDir::new().unwrap();
+    let fm = "---\ntinySpec: v0\ntitle: Edges\n---\n";
+    let expected = format!("{fm}\n# Background\n\nSome background.\n");
+    let inputs = [
+        format!("{fm}# Background\n\nSome background."),
+        format!("{fm}\n\n\n\n# Background\n\n\n\nSome background.\n\n\n\n"),
+        format!("{fm}\n   \n\n# Background\n\nSome background.\n  \n\n"),
+    ];
+
+    for (i, input) in inputs.iter().enumerate() {
+        let filename = format!("2025-02-17-09-3{i}-edges-{i}.md");
+        create_sample_spec(&dir, &filename, input);
+        tinyspec(&dir)
+            .args(["format", &format!("edges-{i}")])
+            .assert()
+            .success();
+        let formatted = fs::read_to_string(dir.path().join(".specs").join(&filename)).unwrap();
+        assert_eq!(formatted, expected, "input {i}: {input:?}");
+    }
+
+    // Front matter alone ends with exactly one newline
+    create_sample_spec(&dir, "2025-02-17-09-40-bare.md", &format!("{fm}\n\n\n"));
+    tinyspec(&dir).args(["format", "bare"]).assert().success();
+    assert_eq!(
+        fs::read_to_string(dir.path().join(".specs/2025-02-17-09-40-bare.md")).unwrap(),
+        fm
+    );
+}
+
+// ─── T.120: clone-tasks copies a plan as unchecked tasks ────────────────────
+
+#[test]
+fn t120_clone_tasks_append_and_replace() {
+    let dir = TempDir::new().unwrap();
+    let src = sample_spec_content().replace("- [ ] A.1:", "- [x] A.1:");
+    create_sample_spec(&dir, "2025-02-17-09-36-source.md", &src);
+    let dest = "---\ntinySpec: v0\ntitle: Dest\n---\n\n# Implementation Plan\n\nNotes stay.\n\n- [x] A: Existing\n\n# Test Plan\n";
+    let dest_path = dir.path().join(".specs/2025-02-17-09-37-dest.md");
+    create_sample_spec(&dir, "2025-02-17-09-37-dest.md", dest);
+
+    // Existing tasks need an explicit mode
+    tinyspec(&dir)
+        .args(["clone-tasks", "source", "dest"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--append"));
+
+    tinyspec(&dir)
+        .args(["clone-tasks", "source", "dest", "--append"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Copied 7 task(s) from 'source' into 'dest'",
+        ));
+    let content = fs::read_to_string(&dest_path).unwrap();
+    assert!(
+        content.contains("- [x] A: Existing\n- [ ] B: Do this\n  - [ ] B.1: Do this subtask\n")
+    );
+    assert!(content.contains("- [ ] C: Do that\n  - [ ] C.1: Subtask one\n"));
+
+    tinyspec(&dir)
+        .args(["clone-tasks", "source", "dest", "--replace"])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&dest_path).unwrap();
+    assert!(content.contains("Notes stay.\n\n- [ ] A: Do this\n  - [ ] A.1: Do this subtask\n"));
+    assert!(!content.contains("Existing"));
+    assert!(!content.contains("[x]"));
+    assert!(content.contains("  - [ ] B.3: Subtask three\n\n# Test Plan"));
+}
+
+// ─── T.121: check/uncheck complete task IDs, next actionable first ──────────
+
+#[test]
+fn t121_check_completes_task_ids_in_next_order() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content()
+        .replace("- [ ] A.1:", "- [x] A.1:")
+        .replace("- [ ] B.1:", "- [x] B.1:");
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+
+    let complete = |command: &str| {
+        let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
+        cmd.current_dir(dir.path());
+        cmd.env("COMPLETE", "bash");
+        cmd.env("_CLAP_COMPLETE_INDEX", "3");
+        cmd.env("_CLAP_COMPLETE_COMP_TYPE", "9");
+        cmd.env("_CLAP_COMPLETE_SPACE", "true");
+        cmd.args(["--", "tinyspec", command, "hello-world", ""]);
+        let stdout = String::from_utf8_lossy(&cmd.output().unwrap().stdout).to_string();
+        stdout
+            .lines()
+            .take_while(|l| !l.starts_with('-'))
+            .map(String::from)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        complete("check"),
+        ["A.2", "A", "B", "B.2", "B.3", "next"],
+        "next actionable task first, then unchecked tasks in plan order"
+    );
+    assert_eq!(complete("uncheck"), ["B.1", "A.1", "next"]);
+}
+
+// ─── T.122: Read commands tell a missing .specs apart from an empty one ─────
+
+#[test]
+fn t122_no_specs_hint_distinguishes_missing_and_empty() {
+    let dir = TempDir::new().unwrap();
+
+    for command in ["list", "status"] {
+        tinyspec(&dir)
+            .arg(command)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("There is no .specs directory"))
+            .stdout(predicate::str::contains("tinyspec init"));
+    }
+
+    fs::create_dir_all(dir.path().join(".specs")).unwrap();
+    for command in ["list", "status"] {
+        tinyspec(&dir)
+            .arg(command)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No specs found in"))
+            .stdout(predicate::str::contains("tinyspec new <name>"))
+            .stdout(predicate::str::contains("There is no .specs directory").not());
+    }
+}
+
+// ─── T.123: list --sort and --reverse ───────────────────────────────────────
+
+#[test]
+fn t123_list_sort_keys() {
+    let dir = TempDir::new().unwrap();
+    let spec = |title: &str, plan: &str| {
+        format!("---\ntinySpec: v0\ntitle: {title}\n---\n\n# Implementation Plan\n\n{plan}\n")
+    };
+    create_sample_spec(
+        &dir,
+        "2025-01-01-00-00-zeta.md",
+        &spec("Alpha", "- [x] A: One\n- [ ] B: Two"),
+    );
+    create_sample_spec(
+        &dir,
+        "2025-01-02-00-00-alpha.md",
+        &spec("Zulu", "- [ ] A: One"),
+    );
+    create_sample_spec(
+        &dir,
+        "2025-01-03-00-00-mid.md",
+        &spec("Mike", "- [x] A: One"),
+    );
+
+    let order = |args: &[&str]| {
+        let output = tinyspec(&dir).arg("list").args(args).output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|l| l.split_whitespace().nth(2).map(String::from))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(order(&[]), ["zeta", "alpha", "mid"]);
+    assert_eq!(order(&["--reverse"]), ["mid", "alpha", "zeta"]);
+    assert_eq!(order(&["--sort", "name"]), ["alpha", "mid", "zeta"]);
+    assert_eq!(order(&["--sort", "title"]), ["zeta", "mid", "alpha"]);
+    assert_eq!(order(&["--sort", "status"]), ["zeta", "alpha", "mid"]);
+    assert_eq!(
+        order(&["--sort", "progress", "--reverse"]),
+        ["mid", "zeta", "alpha"]
+    );
+
+    tinyspec(&dir)
+        .args(["list", "--sort", "size"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid sort key 'size'"));
+}
+
+// ─── T.124: list group headers show aggregate completion ────────────────────
+
+#[test]
+fn t124_list_group_header_progress() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content()
+        .replace("- [ ] A.1:", "- [x] A.1:")
+        .replace("- [ ] B:", "- [x] B:");
+    create_grouped_spec(&dir, "v1", "2025-02-17-09-36-one.md", &content);
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-17-09-37-two.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("v1/  (2/14, 14%)\n"));
+
+    // Headers round down like the spec rows: 5/14 is 35%, not 36%
+    let five = content
+        .replace("- [ ] A:", "- [x] A:")
+        .replace("- [ ] A.2:", "- [x] A.2:")
+        .replace("- [ ] B.1:", "- [x] B.1:");
+    create_grouped_spec(&dir, "v1", "2025-02-17-09-36-one.md", &five);
+    tinyspec(&dir)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("v1/  (5/14, 35%)\n"))
+        .stdout(predicate::str::contains(" 71%  Hello World"));
+
+    tinyspec(&dir)
+        .args(["list", "--no-status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("v1/\n"));
+}
+
+// ─── T.125: view --no-front-matter prints only the resolved body ────────────
+
+#[test]
+fn t125_view_without_front_matter() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content().replace("Some proposal.", "Change my-app.");
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("config.yaml"),
+        "repositories:\n  my-app: /path/to/my-app\n",
+    )
+    .unwrap();
+
+    let output = tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "hello-world", "--no-front-matter"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("# Background\n"), "got: {stdout}");
+    assert!(!stdout.contains("tinySpec: v0"));
+    assert!(stdout.contains("Change /path/to/my-app."));
+
+    tinyspec(&dir)
+        .args(["view", "hello-world", "--no-front-matter", "--json"])
+        .assert()
+        .failure();
+}
+
+// ─── T.126: Checkboxes inside code fences are not tasks ─────────────────────
+
+#[test]
+fn t126_check_ignores_checkboxes_in_code_fences() {
+    let dir = TempDir::new().unwrap();
+    let content = "\
+---
+tinySpec: v0
+title: Fenced
+---
+
+# Implementation Plan
+
+Tasks look like this:
+
+```markdown
+- [ ] A: Example task
+```
+
+- [ ] A: Real task
+";
+    create_sample_spec(&dir, "2025-02-17-09-36-fenced.md", content);
+
+    tinyspec(&dir)
+        .args(["status", "fenced"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0/1 tasks complete"));
+
+    tinyspec(&dir)
+        .args(["check", "fenced", "A"])
+        .assert()
+        .success();
+    let updated = fs::read_to_string(dir.path().join(".specs/2025-02-17-09-36-fenced.md")).unwrap();
+    assert!(updated.contains("```markdown\n- [ ] A: Example task\n```"));
+    assert!(updated.contains("- [x] A: Real task"));
+}
+
+// ─── T.127: reflow-ids converts between letter and numeric groups ───────────
+
+#[test]
+fn t127_reflow_ids_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content()
+        .replace("- [ ] A.1:", "- [x] A.1:")
+        .replace(
+            "# Test Plan\n",
+            "# Test Plan\n\n- [ ] T.1: Covers B.2\n- [ ] T.2: Runs on Python 3.1 and 2.10\n",
+        );
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+    let path = dir.path().join(".specs/2025-02-17-09-36-hello-world.md");
+
+    tinyspec(&dir)
+        .args(["reflow-ids", "hello-world", "--to", "numeric"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renumbered 7 task(s)"));
+    let numeric = fs::read_to_string(&path).unwrap();
+    assert!(numeric.contains("- [ ] 1: Do this\n"));
+    assert!(numeric.contains("- [x] 1.1: Do this subtask\n"));
+    assert!(numeric.contains("- [ ] 2: Do that\n"));
+    assert!(numeric.contains("- [ ] 2.1: Subtask one\n"));
+    assert!(numeric.contains("- [ ] T.1: Covers 2.2"));
+    assert!(numeric.contains("- [ ] T.2: Runs on Python 3.1 and 2.10\n"));
+
+    // Numeric IDs work with check and add-task
+    tinyspec(&dir)
+        .args(["check", "hello-world", "2.3"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Third"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added task 3: Third"));
+
+    tinyspec(&dir)
+        .args(["reflow-ids", "hello-world", "--to", "letters"])
+        .assert()
+        .success();
+    let letters = fs::read_to_string(&path).unwrap();
+    assert!(letters.contains("- [x] A.1: Do this subtask\n"));
+    assert!(letters.contains("- [x] B.3: Subtask three\n- [ ] C: Third\n"));
+    assert!(letters.contains("- [ ] T.1: Covers B.2"));
+    assert!(letters.contains("- [ ] T.2: Runs on Python 3.1 and 2.10\n"));
+}
+
+// ─── T.128: A `config.yml` user config is read and written in place ─────────
+
+#[test]
+fn t128_config_yml_extension() {
+    let dir = TempDir::new().unwrap();
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+    let yml = config_dir.join("config.yml");
+    fs::write(&yml, "repositories:\n  my-app: /path/to/my-app\n").unwrap();
+    let home = config_dir.to_str().unwrap();
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", home)
+        .args(["config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("my-app: /path/to/my-app"));
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", home)
+        .args(["config", "set", "other", "/path/to/other"])
+        .assert()
+        .success();
+    assert!(
+        fs::read_to_string(&yml)
+            .unwrap()
+            .contains("other: /path/to/other")
+    );
+    assert!(!config_dir.join("config.yaml").exists());
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", home)
+        .args(["config", "path"])
+        .assert()
+        .success()
+        .stdout(format!("{} (exists)\n", yml.display()));
+
+    // `config.yaml` wins when both exist
+    fs::write(config_dir.join("config.yaml"), "repositories: {}\n").unwrap();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", home)
+        .args(["config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No repositories configured."));
+}
+
+// ─── T.129: new --body-from wraps piped notes in generated front matter ─────
+
+#[test]
+fn t129_new_with_body_from_stdin() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".specs")).unwrap();
+
+    tinyspec(&dir)
+        .args(["new", "my-notes", "--body-from", "-", "-a", "my-app"])
+        .write_stdin("\n# Background\n\nNotes from the meeting.\n\n# Implementation Plan\n\n- [ ] A: Do it\n")
+        .assert()
+        .success();
+
+    let path = fs::read_dir(dir.path().join(".specs"))
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.extension().is_some_and(|e| e == "md"))
+        .unwrap();
+    let content = fs::read_to_string(path).unwrap();
+    assert!(content.starts_with("---\ntinySpec: v0\ntitle: My Notes\n"));
+    assert!(
+        content.contains(
+            "applications:\n    - my-app\n---\n\n# Background\n\nNotes from the meeting.\n"
+        )
+    );
+    assert!(content.contains("- [ ] A: Do it"));
+    assert!(!content.contains("# Proposal"));
+
+    tinyspec(&dir)
+        .args(["new", "my-notes", "--body-from", "-"])
+        .write_stdin("# Background\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    tinyspec(&dir)
+        .args(["new", "empty-notes", "--body-from", "-"])
+        .write_stdin("  \n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No spec body provided on stdin"));
+}
+
+// ─── T.130: list --by-status follows the dashboard's order and headers ──────
+
+#[test]
+fn t130_list_by_status() {
+    let dir = TempDir::new().unwrap();
+    let done = "---\ntinySpec: v0\ntitle: Done\n---\n\n# Implementation Plan\n\n- [x] A: One\n";
+    let open = "---\ntinySpec: v0\ntitle: Open\n---\n\n# Implementation Plan\n\n- [ ] A: One\n";
+    create_sample_spec(&dir, "2025-01-01-00-00-finished.md", done);
+    create_sample_spec(&dir, "2025-01-02-00-00-pending.md", open);
+    create_grouped_spec(&dir, "v1", "2025-01-03-00-00-grouped.md", open);
+
+    let output = tinyspec(&dir)
+        .args(["list", "--by-status"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().map(str::trim_end).collect();
+    assert_eq!(lines[0], "In Progress");
+    assert!(lines[1].contains("pending"));
+    assert_eq!(lines[2], "");
+    assert_eq!(lines[3], "v1/  (0/1, 0%)");
+    assert!(lines[4].contains("grouped"));
+    assert_eq!(lines[5], "");
+    assert_eq!(lines[6], "Completed");
+    assert!(lines[7].contains("finished"));
+
+    // Default order is still by filename
+    tinyspec(&dir)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("In Progress").not());
+}
+
+// ─── T.131: reset unchecks every task after confirmation ────────────────────
+
+#[test]
+fn t131_reset_unchecks_all_tasks() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content()
+        .replace("title: Hello World", "title: Hello World\nstatus: done")
+        .replace("- [ ] A.1:", "- [x] A.1:")
+        .replace("- [ ] B:", "- [x] B:")
+        .replace("# Test Plan\n", "# Test Plan\n\n- [x] T.1: Covered\n");
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+    let path = dir.path().join(".specs/2025-02-17-09-36-hello-world.md");
+
+    tinyspec(&dir)
+        .args(["reset", "hello-world"])
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Uncheck all 3 checked task(s) in hello-world?",
+        ))
+        .stdout(predicate::str::contains("Cancelled."));
+    assert!(fs::read_to_string(&path).unwrap().contains("[x]"));
+
+    tinyspec(&dir)
+        .args(["reset", "hello-world", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Reset 3 task(s) in spec 'hello-world'",
+        ));
+    let reset = fs::read_to_string(&path).unwrap();
+    assert!(!reset.contains("[x]"));
+    assert!(reset.contains("status: draft"));
+
+    tinyspec(&dir)
+        .args(["reset", "hello-world"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No checked tasks"));
+}
+
+// ─── T.132: check --timestamp records the completion date ───────────────────
+
+#[test]
+fn t132_check_timestamp_annotates_task() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    let path = dir.path().join(".specs/2025-02-17-09-36-hello-world.md");
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+    tinyspec(&dir)
+        .args(["check", "hello-world", "A.1", "--timestamp"])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains(&format!("- [x] A.1: Do this subtask (done {today})")));
+
+    // The annotation is not part of the description
+    tinyspec(&dir)
+        .args(["view", "hello-world", "--plan", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"description\": \"Do this subtask\"",
+        ))
+        .stdout(predicate::str::contains(format!("\"done\": \"{today}\"")));
+
+    tinyspec(&dir)
+        .args(["uncheck", "hello-world", "A.1"])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("- [ ] A.1: Do this subtask\n"));
+    assert!(!content.contains("(done "));
+}
+
+// ─── T.133: velocity counts completions per day and week ────────────────────
+
+#[test]
+fn t133_velocity_reports_completions() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .arg("velocity")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No completion dates recorded"));
+    tinyspec(&dir)
+        .args(["velocity", "--json"])
+        .assert()
+        .success()
+        .stdout("[]\n");
+
+    let content = sample_spec_content()
+        .replace(
+            "- [ ] A.1: Do this subtask",
+            "- [x] A.1: Do this subtask (done 2026-03-02)",
+        )
+        .replace(
+            "- [ ] A.2: Do this other subtask",
+            "- [x] A.2: Do this other subtask (done 2026-03-02)",
+        )
+        .replace(
+            "- [ ] B.1: Subtask one",
+            "- [x] B.1: Subtask one (done 2026-03-09)",
+        )
+        // Unchecked tasks don't count even if annotated
+        .replace(
+            "- [ ] B.2: Subtask two",
+            "- [ ] B.2: Subtask two (done 2026-03-10)",
+        );
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+
+    tinyspec(&dir)
+        .arg("velocity")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2026-03-02     2"))
+        .stdout(predicate::str::contains("2026-03-09     1"))
+        .stdout(predicate::str::contains("2026-03-10").not())
+        .stdout(predicate::str::contains(
+            "3 task(s) over 2 day(s), 1.5 per day",
+        ));
+
+    tinyspec(&dir)
+        .args(["velocity", "--weekly", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"period\": \"2026-W10\""))
+        .stdout(predicate::str::contains("\"period\": \"2026-W11\""))
+        .stdout(predicate::str::contains("\"completed\": 2"));
+}
+
+// ─── T.134: format is idempotent for Markdown tables ────────────────────────
+
+#[test]
+fn t134_format_tables_idempotent() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content().replace(
+        "Some background.\n",
+        "\
+Some background.
+
+| Option | Default | Notes |
+|:-------|--------:|:-----:|
+| `--json` | off | Machine-readable |
+| bar_width | 10 | escaped \\| pipe and **bold** |
+|  | empty | first cell blank |
+",
+    );
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+    let path = dir.path().join(".specs/2025-02-17-09-36-hello-world.md");
+
+    tinyspec(&dir)
+        .args(["format", "hello-world"])
+        .assert()
+        .success();
+    let first = fs::read_to_string(&path).unwrap();
+    for cell in [
+        "`--json`",
+        "Machine-readable",
+        "escaped \\| pipe and **bold**",
+        "first cell blank",
+    ] {
+        assert!(first.contains(cell), "lost table cell {cell:?}:\n{first}");
+    }
+    // Column alignment survives
+    assert!(first.contains(":-") && first.contains("-:"));
+
+    tinyspec(&dir)
+        .args(["format", "hello-world"])
+        .assert()
+        .success();
+    let second = fs::read_to_string(&path).unwrap();
+    assert_eq!(first, second);
+}
+
+// ─── T.135: rename-group moves every spec in a group ────────────────────────
+
+#[test]
+fn t135_rename_group() {
+    let dir = TempDir::new().unwrap();
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-17-09-36-alpha.md",
+        &sample_spec_content(),
+    );
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-17-09-37-beta.md",
+        &sample_spec_content(),
+    );
+    create_grouped_spec(
+        &dir,
+        "v2",
+        "2025-02-17-09-38-gamma.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["rename-group", "v1", "v2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Group 'v2' already exists"));
+    tinyspec(&dir)
+        .args(["rename-group", "v1", "Release_1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid group name"));
+    tinyspec(&dir)
+        .args(["rename-group", "v1", "archive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("reserved"));
+    tinyspec(&dir)
+        .args(["rename-group", "nope", "release-1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Group 'nope' does not exist"));
+
+    tinyspec(&dir)
+        .args(["rename-group", "v1", "release-1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Renamed group 'v1' to 'release-1' (2 spec(s))",
+        ));
+    let specs = dir.path().join(".specs");
+    assert!(!specs.join("v1").exists());
+    let content = fs::read_to_string(specs.join("release-1/2025-02-17-09-36-alpha.md")).unwrap();
+    assert_eq!(content, sample_spec_content());
+    assert!(specs.join("release-1/2025-02-17-09-37-beta.md").exists());
+
+    tinyspec(&dir)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("release-1/"))
+        .stdout(predicate::str::contains("v1/").not());
+    tinyspec(&dir).args(["status", "beta"]).assert().success();
+}
+
+// ─── T.136: .specs/.order pins list ordering ────────────────────────────────
+
+#[test]
+fn t136_order_file_pins_list_order() {
+    let dir = TempDir::new().unwrap();
+    for name in [
+        "2025-01-01-00-00-alpha.md",
+        "2025-01-02-00-00-beta.md",
+        "2025-01-03-00-00-gamma.md",
+        "2025-01-04-00-00-delta.md",
+    ] {
+        create_sample_spec(&dir, name, &sample_spec_content());
+    }
+    fs::write(
+        dir.path().join(".specs/.order"),
+        "# what to work on next\ngamma\n\nalpha\nmissing\n",
+    )
+    .unwrap();
+
+    let position = |out: &str, name: &str| out.find(name).unwrap();
+    for args in [vec!["list", "--no-status"], vec!["list", "--by-status"]] {
+        let out = tinyspec(&dir).args(&args).output().unwrap();
+        assert!(out.status.success());
+        let stdout = String::from_utf8(out.stdout).unwrap();
+        assert!(
+            position(&stdout, "gamma") < position(&stdout, "alpha")
+                && position(&stdout, "alpha") < position(&stdout, "beta")
+                && position(&stdout, "beta") < position(&stdout, "delta"),
+            "{args:?} ignored .order:\n{stdout}"
+        );
+        // The order file is not a spec
+        assert!(!stdout.contains(".order"));
+    }
+
+    // --reverse flips the sort key only; pinned specs still come first
+    let out = tinyspec(&dir)
+        .args(["list", "--no-status", "--reverse"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(
+        position(&stdout, "gamma") < position(&stdout, "alpha")
+            && position(&stdout, "alpha") < position(&stdout, "delta")
+            && position(&stdout, "delta") < position(&stdout, "beta"),
+        "--reverse moved pinned specs:\n{stdout}"
+    );
+
+    // Without the file, the default date order returns
+    fs::remove_file(dir.path().join(".specs/.order")).unwrap();
+    let out = tinyspec(&dir)
+        .args(["list", "--no-status"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(position(&stdout, "alpha") < position(&stdout, "beta"));
+    assert!(position(&stdout, "beta") < position(&stdout, "gamma"));
+}
+
+// ─── T.137: schema output and lint front matter validation ──────────────────
+
+#[test]
+fn t137_schema_and_front_matter_lint() {
+    let dir = TempDir::new().unwrap();
+
+    let out = tinyspec(&dir).arg("schema").output().unwrap();
+    assert!(out.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["tags"]["type"], "array");
+    assert_eq!(
+        schema["properties"]["priority"]["enum"],
+        serde_json::json!(["high", "medium", "low"])
+    );
+    assert_eq!(schema["required"], serde_json::json!(["tinySpec"]));
+
+    let content = sample_spec_content().replace(
+        "title: Hello World\n",
+        "title: Hello World\npriority: urgent\ntags: backend\nowner: sam\n",
+    );
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+    tinyspec(&dir)
+        .args(["lint", "hello-world"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "[error] Front matter 'priority' must be one of: high, medium, low",
+        ))
+        .stdout(predicate::str::contains(
+            "[error] Front matter 'tags' must be a list of strings",
+        ))
+        .stdout(predicate::str::contains(
+            "[warning] Unknown front matter key 'owner'",
+        ));
+
+    // The default scaffold passes
+    tinyspec(&dir).args(["new", "fresh"]).assert().success();
+    tinyspec(&dir)
+        .args(["lint", "fresh"])
+        .assert()
+        .stdout(predicate::str::contains("front matter").not())
+        .stdout(predicate::str::contains("Front matter").not());
+}
+
+// ─── T.138: colons in descriptions don't move the task ID boundary ──────────
+
+#[test]
+fn t138_colons_in_task_descriptions() {
+    let dir = TempDir::new().unwrap();
+    let content = sample_spec_content()
+        .replace("A.1: Do this subtask", "A.1: Fix the ratio 3:1 bug")
+        .replace("B.2: Subtask two", "B.2 : Note: spaced colon");
+    create_sample_spec(&dir, "2025-02-17-09-36-hello-world.md", &content);
+    let path = dir.path().join(".specs/2025-02-17-09-36-hello-world.md");
+
+    tinyspec(&dir)
+        .args(["view", "hello-world", "--plan", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"description\": \"Fix the ratio 3:1 bug\"",
+        ))
+        .stdout(predicate::str::contains("\"id\": \"B.2\""))
+        .stdout(predicate::str::contains(
+            "\"description\": \"Note: spaced colon\"",
+        ));
+
+    // `check` finds tasks by the same ID the parser reports
+    tinyspec(&dir)
+        .args(["check", "hello-world", "A.1", "--no-format"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .args(["check", "hello-world", "B.2", "--no-format"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .args(["check", "hello-world", "Fix the ratio 3"])
+        .assert()
+        .failure();
+    let updated = fs::read_to_string(&path).unwrap();
+    assert!(updated.contains("- [x] A.1: Fix the ratio 3:1 bug"));
+    assert!(updated.contains("- [x] B.2 : Note: spaced colon"));
+
+    tinyspec(&dir)
+        .args(["status", "hello-world"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2/7"));
+}
+
+// ─── T.139: edit --section passes a line jump to known editors ──────────────
+
+#[cfg(unix)]
+#[test]
+fn t139_edit_section_jumps_to_heading() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    let log = dir.path().join("editor.log");
+    let bin = dir.path().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    for name in ["vim", "code", "ed"] {
+        let script = bin.join(name);
+        fs::write(
+            &script,
+            format!("#!/bin/sh\necho {name} \"$@\" >> '{}'\n", log.display()),
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    for name in ["vim", "code", "ed"] {
+        tinyspec(&dir)
+            .env("TINYSPEC_EDITOR", bin.join(name))
+            .args(["edit", "hello-world", "--section", "Implementation Plan"])
+            .assert()
+            .success();
+    }
+    tinyspec(&dir)
+        .env("TINYSPEC_EDITOR", bin.join("vim"))
+        .args(["edit", "hello-world", "--section", "Rollout"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Spec 'hello-world' has no 'Rollout' section",
+        ));
+
+    // The heading is on line 16 of the sample spec
+    let logged = fs::read_to_string(&log).unwrap();
+    let lines: Vec<&str> = logged.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("vim +16 ") && lines[0].ends_with("-hello-world.md"));
+    assert!(lines[1].starts_with("code --goto ") && lines[1].ends_with("-hello-world.md:16"));
+    assert!(lines[2].starts_with("ed ") && lines[2].ends_with("-hello-world.md"));
+}
+
+// ─── T.140: new warns when a spec name matches a template ───────────────────
+
+#[test]
+fn t140_new_warns_on_template_name() {
+    let dir = TempDir::new().unwrap();
+    let templates = dir.path().join(".specs/templates");
+    fs::create_dir_all(&templates).unwrap();
+    fs::write(templates.join("design.md"), "# Background\n").unwrap();
+
+    tinyspec(&dir)
+        .env("HOME", dir.path())
+        .args(["new", "design"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Warning: 'design' is also the name of a repo template",
+        ));
+    let created = fs::read_dir(dir.path().join(".specs"))
+        .unwrap()
+        .flatten()
+        .any(|e| e.file_name().to_string_lossy().ends_with("-design.md"));
+    assert!(created);
+
+    tinyspec(&dir)
+        .env("HOME", dir.path())
+        .args(["new", "rollout"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Warning").not());
+}
+
+// ─── T.141: group-qualified spec names resolve within that group ────────────
+
+#[test]
+fn t141_group_qualified_spec_names() {
+    let dir = TempDir::new().unwrap();
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-02-17-09-36-alpha.md",
+        &sample_spec_content().replace("Some background.", "Version one."),
+    );
+    // A stray copy in another group (newer, so a bare name picks it)
+    create_grouped_spec(
+        &dir,
+        "v2",
+        "2025-02-18-09-36-alpha.md",
+        &sample_spec_content().replace("Some background.", "Version two."),
+    );
+
+    tinyspec(&dir)
+        .args(["view", "v1/alpha", "--lenient"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Version one."));
+    tinyspec(&dir)
+        .args(["view", "alpha", "--lenient"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Version two."));
+    tinyspec(&dir)
+        .args(["view", "v3/alpha"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No spec found matching 'v3/alpha'",
+        ));
+
+    tinyspec(&dir)
+        .args(["check", "v1/alpha", "A.1"])
+        .assert()
+        .success();
+    let v1 = fs::read_to_string(dir.path().join(".specs/v1/2025-02-17-09-36-alpha.md")).unwrap();
+    let v2 = fs::read_to_string(dir.path().join(".specs/v2/2025-02-18-09-36-alpha.md")).unwrap();
+    assert!(v1.contains("[x] A.1"));
+    assert!(!v2.contains("[x]"));
+
+    tinyspec(&dir)
+        .args(["status", "v1/alpha"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .args(["focus", "v1/alpha"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Focused on spec: alpha"));
+}
+
+// ─── T.142: common errors come with a remediation hint ──────────────────────
+
+#[test]
+fn t142_error_hints() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+    let config_dir = dir.path().join(".tinyspec-config");
+    fs::create_dir_all(&config_dir).unwrap();
+
+    tinyspec(&dir)
+        .args(["view", "nope"])
+        .assert()
+        .failure()
+        .stderr(
+            "Error: No spec found matching 'nope'\n  hint: run `tinyspec list` to see spec names\n",
+        );
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", &config_dir)
+        .args(["view", "hello-world"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no config file found"))
+        .stderr(predicate::str::contains(
+            "  hint: map each application with `tinyspec config set <repo-name> <path>`",
+        ));
+
+    fs::write(config_dir.join("config.yaml"), "repositories: {}\n").unwrap();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", &config_dir)
+        .args(["view", "hello-world"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "  hint: run `tinyspec config set my-app <path>`",
+        ));
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", &config_dir)
+        .args(["view", "hello-world", "--lenient"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Warning: Spec references applications",
+        ))
+        .stderr(predicate::str::contains(
+            "  hint: run `tinyspec config set my-app <path>`",
+        ));
+}
+
+// ─── T.143: spec files are collected in a stable path order ─────────────────
+
+#[test]
+fn t143_spec_completion_order_is_stable() {
+    let dir = TempDir::new().unwrap();
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-01-00-00-alpha.md",
+        &sample_spec_content(),
+    );
+    create_sample_spec(&dir, "2025-03-01-00-00-zeta.md", &sample_spec_content());
+    create_grouped_spec(
+        &dir,
+        "a-team",
+        "2025-02-01-00-00-beta.md",
+        &sample_spec_content(),
+    );
+    create_sample_spec(&dir, "2025-01-01-00-00-omega.md", &sample_spec_content());
+    // Sorts before the ungrouped timestamps by path, but groups still come after
+    create_grouped_spec(
+        &dir,
+        "0-legacy",
+        "2025-04-01-00-00-gamma.md",
+        &sample_spec_content(),
+    );
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
+    cmd.current_dir(dir.path());
+    cmd.env("COMPLETE", "bash");
+    cmd.env("_CLAP_COMPLETE_INDEX", "2");
+    cmd.env("_CLAP_COMPLETE_COMP_TYPE", "9");
+    cmd.env("_CLAP_COMPLETE_SPACE", "true");
+    cmd.args(["--", "tinyspec", "view", ""]);
+    let stdout = String::from_utf8_lossy(&cmd.output().unwrap().stdout).to_string();
+    let names: Vec<&str> = stdout.lines().take_while(|l| !l.starts_with('-')).collect();
+
+    // Ungrouped specs by timestamp, then each group in name order, then groups
+    assert_eq!(
+        names,
+        [
+            "omega",
+            "zeta",
+            "gamma",
+            "beta",
+            "alpha",
+            "0-legacy/",
+            "a-team/",
+            "v1/"
+        ]
+    );
+
+    // search and format --all walk specs in the same order
+    let expected = ["omega", "zeta", "0-legacy/gamma", "a-team/beta", "v1/alpha"];
+    let output = tinyspec(&dir).args(["search", "Do that"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let headers: Vec<&str> = stdout
+        .lines()
+        .filter(|l| !l.is_empty() && !l.starts_with(' '))
+        .filter_map(|l| l.split_whitespace().next())
+        .collect();
+    assert_eq!(headers, expected);
+
+    let output = tinyspec(&dir).args(["format", "--all"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let formatted: Vec<String> = stdout
+        .lines()
+        .filter_map(|l| l.strip_prefix("Formatted "))
+        .filter(|l| l.ends_with(".md"))
+        .map(|l| {
+            let (group, file) = l.rsplit_once('/').map_or(("", l), |(g, f)| (g, f));
+            let name = &file[17..file.len() - 3];
+            if group.is_empty() {
+                name.to_string()
+            } else {
+                format!("{group}/{name}")
+            }
+        })
+        .collect();
+    assert_eq!(formatted, expected);
+}
+
+// ─── T.144: list --changed scopes to specs touched in git ───────────────────
+
+#[test]
+fn t144_list_changed_specs() {
+    let dir = TempDir::new().unwrap();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+            .args(args)
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let list = |extra: &[&str]| {
+        let out = tinyspec(&dir)
+            .args(["list", "--no-status", "--changed"])
+            .args(extra)
+            .output()
+            .unwrap();
+        assert!(out.status.success());
+        String::from_utf8(out.stdout).unwrap()
+    };
+
+    // Outside a git repository every spec is listed, with a warning
+    create_sample_spec(&dir, "2025-01-01-00-00-alpha.md", &sample_spec_content());
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-02-00-00-beta.md",
+        &sample_spec_content(),
+    );
+    tinyspec(&dir)
+        .args(["list", "--no-status", "--changed"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("alpha"))
+        .stderr(predicate::str::contains("not in a git repository"));
+
+    // Before the first commit every spec, staged or untracked, is new
+    git(&["init", "--quiet"]);
+    git(&["add", ".specs/2025-01-01-00-00-alpha.md"]);
+    for extra in [&[][..], &["--base", "origin/main"]] {
+        let unborn = list(extra);
+        assert!(
+            unborn.contains("alpha") && unborn.contains("beta"),
+            "{unborn}"
+        );
+    }
+
+    git(&["add", "."]);
+    git(&["commit", "--quiet", "-m", "base"]);
+    let base = git(&["rev-parse", "HEAD"]).trim().to_string();
+    assert_eq!(list(&[]), "No changed specs.\n");
+
+    // A committed edit shows up only against --base; an untracked spec always
+    let beta = dir.path().join(".specs/v1/2025-01-02-00-00-beta.md");
+    fs::write(&beta, sample_spec_content().replace("- [ ] A:", "- [x] A:")).unwrap();
+    git(&["commit", "--quiet", "-am", "edit beta"]);
+    create_sample_spec(&dir, "2025-01-03-00-00-gamma.md", &sample_spec_content());
+
+    let uncommitted = list(&[]);
+    assert!(uncommitted.contains("gamma"));
+    assert!(!uncommitted.contains("beta") && !uncommitted.contains("alpha"));
+
+    let since_base = list(&["--base", &base]);
+    assert!(since_base.contains("gamma") && since_base.contains("beta"));
+    assert!(!since_base.contains("alpha"));
+}
+
+// ─── T.145: spec completion offers groups and group-qualified names ─────────
+
+#[test]
+fn t145_spec_completion_includes_groups() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-vault.md", &sample_spec_content());
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-02-00-00-alpha.md",
+        &sample_spec_content(),
+    );
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-03-00-00-beta.md",
+        &sample_spec_content(),
+    );
+    create_grouped_spec(
+        &dir,
+        "v2",
+        "2025-01-04-00-00-gamma.md",
+        &sample_spec_content(),
+    );
+
+    let complete = |dir: &TempDir, word: &str| {
+        let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("tinyspec");
+        cmd.current_dir(dir.path());
+        cmd.env("COMPLETE", "bash");
+        cmd.env("_CLAP_COMPLETE_INDEX", "2");
+        cmd.env("_CLAP_COMPLETE_COMP_TYPE", "9");
+        cmd.env("_CLAP_COMPLETE_SPACE", "true");
+        cmd.args(["--", "tinyspec", "status", word]);
+        let stdout = String::from_utf8_lossy(&cmd.output().unwrap().stdout).to_string();
+        stdout
+            .lines()
+            .take_while(|l| !l.starts_with('-'))
+            .map(String::from)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(complete(&dir, "v"), ["vault", "v1/", "v2/"]);
+    assert_eq!(complete(&dir, "v1/"), ["v1/alpha", "v1/beta"]);
+    assert_eq!(complete(&dir, "v1/b"), ["v1/beta"]);
+    assert!(complete(&dir, "v3/").is_empty());
+
+    // No .specs directory: no candidates, no error
+    let empty = TempDir::new().unwrap();
+    assert!(complete(&empty, "").is_empty());
+}
+
+// ─── T.146: check --subtasks and --all ──────────────────────────────────────
+
+#[test]
+fn t146_check_subtasks_and_all() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-scoped.md", &sample_spec_content());
+    let path = dir.path().join(".specs/2025-01-01-00-00-scoped.md");
+
+    tinyspec(&dir)
+        .args(["check", "scoped", "B.2"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .args(["check", "scoped", "B", "--subtasks"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task B.1"))
+        .stdout(predicate::str::contains("Checked task B.3"))
+        .stdout(predicate::str::contains("B.2").not());
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("- [ ] B: Do that"));
+    assert!(content.contains("- [x] B.1: Subtask one"));
+    assert!(content.contains("- [x] B.2: Subtask two"));
+    assert!(content.contains("- [x] B.3: Subtask three"));
+
+    tinyspec(&dir)
+        .args(["check", "scoped", "A", "--all"])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("- [x] A: Do this"));
+    assert!(content.contains("- [x] A.1: Do this subtask"));
+    assert!(content.contains("- [x] A.2: Do this other subtask"));
+
+    // Nothing left to check under B, and leaves have no subtasks
+    tinyspec(&dir)
+        .args(["check", "scoped", "B", "--subtasks"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No unchecked tasks under 'B'"));
+    tinyspec(&dir)
+        .args(["check", "scoped", "A.1", "--all"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Task 'A.1' has no subtasks"));
+    tinyspec(&dir)
+        .args(["check", "scoped", "B", "--subtasks", "--all"])
+        .assert()
+        .failure();
+
+    // --subtasks stops at direct subtasks; --all reaches every depth
+    let deep = sample_spec_content().replace(
+        "    - [ ] A.1: Do this subtask\n",
+        "    - [ ] A.1: Do this subtask\n        - [ ] A.1.1: Grandchild\n",
+    );
+    create_sample_spec(&dir, "2025-01-01-00-01-deep.md", &deep);
+    let deep_path = dir.path().join(".specs/2025-01-01-00-01-deep.md");
+    tinyspec(&dir)
+        .args(["check", "deep", "A", "--subtasks"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task A.1"))
+        .stdout(predicate::str::contains("Checked task A.2"))
+        .stdout(predicate::str::contains("A.1.1").not());
+    assert!(
+        fs::read_to_string(&deep_path)
+            .unwrap()
+            .contains("- [ ] A.1.1: Grandchild")
+    );
+    tinyspec(&dir)
+        .args(["check", "deep", "A.1", "--subtasks"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task A.1.1"));
+    assert!(
+        fs::read_to_string(&deep_path)
+            .unwrap()
+            .contains("- [x] A.1.1: Grandchild")
+    );
+
+    create_sample_spec(&dir, "2025-01-01-00-02-deeper.md", &deep);
+    tinyspec(&dir)
+        .args(["check", "deeper", "A", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task A.1.1"));
+    let content = fs::read_to_string(dir.path().join(".specs/2025-01-01-00-02-deeper.md")).unwrap();
+    assert!(content.contains("- [x] A: Do this"));
+    assert!(content.contains("- [x] A.1: Do this subtask"));
+    assert!(content.contains("- [x] A.1.1: Grandchild"));
+}
+
+// ─── T.147: view --render-apps-as-links ─────────────────────────────────────
+
+#[test]
+fn t147_view_render_apps_as_links() {
+    let dir = TempDir::new().unwrap();
+    let content = "\
+---
+tinySpec: v0
+title: Links
+applications:
+    - web
+    - api
+---
+
+# Background
+
+Touches web and api, see web/src for details.
+
+```
+cd web
+```
+";
+    create_sample_spec(&dir, "2025-01-01-00-00-links.md", content);
+    let config_dir = dir.path().join(".tinyspec-config");
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["config", "set", "web", "/code/web"])
+        .args(["--url", "https://example.com/web"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["config", "set", "api", "/code/my api"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "web: /code/web (https://example.com/web)",
+        ));
+
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "links", "--render-apps-as-links"])
+        .assert()
+        .success()
+        // Front matter keeps plain paths so the YAML stays a list of strings
+        .stdout(predicate::str::contains("    - /code/web\n"))
+        .stdout(predicate::str::contains(
+            "Touches [web](https://example.com/web) and [api](</code/my api>), see /code/web/src",
+        ))
+        // Links don't render inside code fences
+        .stdout(predicate::str::contains("cd /code/web\n"));
+
+    // Without the flag, plain path substitution is unchanged
+    tinyspec(&dir)
+        .env("TINYSPEC_HOME", config_dir.to_str().unwrap())
+        .args(["view", "links"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Touches /code/web and /code/my api",
+        ));
+}
+
+// ─── T.148: project-configured template directories ─────────────────────────
+
+#[test]
+fn t148_templates_from_project_template_dirs() {
+    let dir = TempDir::new().unwrap();
+    let project = dir.path().join("crate-a");
+    let shared = dir.path().join("shared-templates");
+    let home = dir.path().join("home");
+    let user = home.join(".config/tinyspec/templates");
+    for d in [
+        project.join(".specs/templates"),
+        shared.clone(),
+        user.clone(),
+    ] {
+        fs::create_dir_all(d).unwrap();
+    }
+    fs::write(
+        project.join(".tinyspec.yaml"),
+        "template_dirs:\n  - ../shared-templates\n",
+    )
+    .unwrap();
+    let template = "---\ntinySpec: v0\ntitle: {{title}}\n---\n\n# Background\n";
+    fs::write(project.join(".specs/templates/both.md"), template).unwrap();
+    fs::write(shared.join("both.md"), template).unwrap();
+    fs::write(shared.join("shared.md"), template).unwrap();
+    fs::write(user.join("shared.md"), template).unwrap();
+    fs::write(user.join("mine.md"), template).unwrap();
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("tinyspec")
+        .current_dir(&project)
+        .env("HOME", &home)
+        .args(["templates"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<_> = stdout
+        .lines()
+        .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect();
+    // Repo beats project, project beats user; each name is listed once
+    assert_eq!(
+        lines,
+        ["both (repo)", "mine (user)", "shared (project)"],
+        "{stdout}"
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("tinyspec")
+        .current_dir(&project)
+        .env("HOME", &home)
+        .args(["new", "feature", "--template", "shared"])
+        .assert()
+        .success();
+}
+
+// ─── T.149: new prints where a spec in a parent .specs landed ───────────────
+
+#[test]
+fn t149_new_prints_path_outside_cwd() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".specs")).unwrap();
+    let sub = dir.path().join("crates/core");
+    fs::create_dir_all(&sub).unwrap();
+
+    let specs = dir.path().join(".specs").canonicalize().unwrap();
+    assert_cmd::cargo::cargo_bin_cmd!("tinyspec")
+        .current_dir(&sub)
+        .args(["new", "nested"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Created spec: {}",
+            specs.display()
+        )))
+        .stdout(predicate::str::contains("-nested.md"));
+}
+
+// ─── T.150: migrate upgrades specs to the latest format ─────────────────────
+
+#[test]
+fn t150_migrate_adds_version_and_is_idempotent() {
+    let dir = TempDir::new().unwrap();
+    let legacy = "---\ntitle: Legacy\n---\n\n# Background\n";
+    create_sample_spec(&dir, "2025-01-01-00-00-legacy.md", legacy);
+    create_sample_spec(&dir, "2025-01-02-00-00-current.md", &sample_spec_content());
+    create_sample_spec(
+        &dir,
+        "2025-01-03-00-00-future.md",
+        "---\ntinySpec: v9\n---\n",
+    );
+    let path = dir.path().join(".specs/2025-01-01-00-00-legacy.md");
+
+    tinyspec(&dir)
+        .args(["migrate", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("legacy: add tinySpec: v0"))
+        .stdout(predicate::str::contains("current").not())
+        .stdout(predicate::str::contains("1 spec(s) would be migrated"))
+        .stderr(predicate::str::contains(
+            "skipping future: unknown format version 'v9'",
+        ));
+    assert_eq!(fs::read_to_string(&path).unwrap(), legacy);
+
+    tinyspec(&dir)
+        .args(["migrate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Migrated 1 spec(s) to v0."));
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "---\ntinySpec: v0\ntitle: Legacy\n---\n\n# Background\n"
+    );
+
+    tinyspec(&dir)
+        .args(["migrate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("All specs are up to date (v0)."));
+}
+
+// ─── T.151: check --match finds a task by description ───────────────────────
+
+#[test]
+fn t151_check_by_description_match() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-match.md", &sample_spec_content());
+    let path = dir.path().join(".specs/2025-01-01-00-00-match.md");
+
+    tinyspec(&dir)
+        .args(["check", "match", "--match", "SUBTASK TWO"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checked task B.2"));
+    assert!(
+        fs::read_to_string(&path)
+            .unwrap()
+            .contains("- [x] B.2: Subtask two")
+    );
+
+    // Several matches list the candidates; checked tasks no longer match
+    tinyspec(&dir)
+        .args(["check", "match", "--match", "subtask"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "4 unchecked tasks match 'subtask' in spec 'match'",
+        ))
+        .stderr(predicate::str::contains("  A.1: Do this subtask"))
+        .stderr(predicate::str::contains("  B.3: Subtask three"))
+        .stderr(predicate::str::contains("B.2").not());
+
+    tinyspec(&dir)
+        .args(["check", "match", "--match", "subtask two"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No unchecked task matching 'subtask two'",
+        ));
+    tinyspec(&dir)
+        .args(["check", "match", "A", "--match", "this"])
+        .assert()
+        .failure();
+}
+
+// ─── T.152: --color auto|always|never ───────────────────────────────────────
+
+#[test]
+fn t152_color_flag() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-paint.md", &sample_spec_content());
+
+    let tree = |args: &[&str], no_color: bool| {
+        let mut cmd = tinyspec(&dir);
+        cmd.args(["status", "paint", "--tree"]).args(args);
+        if no_color {
+            cmd.env("NO_COLOR", "1");
+        }
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // auto: stdout is a pipe here, so no colour
+    assert!(!tree(&[], false).contains('\x1b'));
+    assert!(tree(&["--color", "always"], false).contains('\x1b'));
+    // An explicit flag wins over NO_COLOR
+    assert!(tree(&["--color=always"], true).contains('\x1b'));
+    assert!(!tree(&["--color", "never"], false).contains('\x1b'));
+
+    // Errors follow the same setting
+    tinyspec(&dir)
+        .args(["--color", "always", "view", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("\x1b["))
+        .stderr(predicate::str::contains("No spec found matching 'missing'"));
+
+    tinyspec(&dir)
+        .args(["status", "--color", "rainbow"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid --color value 'rainbow'. Use: auto, always, never",
+        ));
+}
+
+// ─── T.153: format a single group ───────────────────────────────────────────
+
+#[test]
+fn t153_format_group() {
+    let dir = TempDir::new().unwrap();
+    let messy =
+        "---\ntinySpec: v0\ntitle: Messy\n---\n\n# Background\n\n\n\nSome   text.\n* item\n";
+    create_sample_spec(&dir, "2025-01-01-00-00-top.md", messy);
+    create_grouped_spec(&dir, "v1", "2025-01-02-00-00-one.md", messy);
+    create_grouped_spec(&dir, "v1", "2025-01-03-00-00-two.md", messy);
+    create_grouped_spec(&dir, "v2", "2025-01-04-00-00-three.md", messy);
+    let read = |rel: &str| fs::read_to_string(dir.path().join(".specs").join(rel)).unwrap();
+
+    tinyspec(&dir)
+        .args(["format", "--group", "v1", "--check"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "2 of 2 spec(s) would be reformatted",
+        ));
+
+    tinyspec(&dir)
+        .args(["format", "--group", "v1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Formatted 2 spec(s) (2 changed)"));
+    assert_ne!(read("v1/2025-01-02-00-00-one.md"), messy);
+    assert_ne!(read("v1/2025-01-03-00-00-two.md"), messy);
+    assert_eq!(read("2025-01-01-00-00-top.md"), messy);
+    assert_eq!(read("v2/2025-01-04-00-00-three.md"), messy);
+
+    // `group/` as the argument is the same as --group
+    tinyspec(&dir)
+        .args(["format", "v2/"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Formatted v2/2025-01-04-00-00-three.md",
+        ));
+    assert_eq!(read("2025-01-01-00-00-top.md"), messy);
+
+    tinyspec(&dir)
+        .args(["format", "--group", "v3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Group 'v3' does not exist"));
+    tinyspec(&dir)
+        .args(["format", "top", "--check"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--check needs --all or a group"));
+}
+
+// ─── T.154: init --print-completion ─────────────────────────────────────────
+
+#[test]
+fn t154_init_print_completion() {
+    let dir = TempDir::new().unwrap();
+
+    tinyspec(&dir)
+        .env("SHELL", "/usr/bin/zsh")
+        .args(["init", "--print-completion"])
+        .assert()
+        .success()
+        .stdout("source <(COMPLETE=zsh tinyspec)\n");
+    tinyspec(&dir)
+        .env("SHELL", "/usr/bin/zsh")
+        .args(["init", "--print-completion", "--shell", "fish"])
+        .assert()
+        .success()
+        .stdout("COMPLETE=fish tinyspec | source\n");
+    tinyspec(&dir)
+        .env_remove("SHELL")
+        .args(["init", "--print-completion"])
+        .assert()
+        .success()
+        .stdout("source <(COMPLETE=bash tinyspec)\n");
+    tinyspec(&dir)
+        .args(["init", "--print-completion", "--shell", "tcsh"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unsupported shell 'tcsh'. Use: bash, zsh, fish",
+        ));
+
+    // Nothing is written
+    assert!(fs::read_dir(dir.path()).unwrap().next().is_none());
+}
+
+// ─── T.155: tab-indented subtasks ───────────────────────────────────────────
+
+#[test]
+fn t155_tab_indented_subtasks() {
+    let dir = TempDir::new().unwrap();
+    let content = "---\ntinySpec: v0\ntitle: Tabs\n---\n\n# Implementation Plan\n\n- [ ] A: Group\n\t- [x] A.1: Tabbed\n    - [ ] A.2: Spaced\n- [ ] B: Next\n";
+    create_sample_spec(&dir, "2025-01-01-00-00-tabs.md", content);
+
+    let output = tinyspec(&dir)
+        .args(["status", "tabs", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let tasks = json["tasks"].as_array().unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0]["children"].as_array().unwrap().len(), 2);
+
+    // New subtasks take the tab's width in spaces (configurable)
+    fs::write(dir.path().join(".tinyspec.yaml"), "tab_width: 2\n").unwrap();
+    tinyspec(&dir)
+        .args(["add-task", "tabs", "Third", "--under", "A", "--no-format"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added task A.3"));
+    let updated = fs::read_to_string(dir.path().join(".specs/2025-01-01-00-00-tabs.md")).unwrap();
+    assert!(updated.contains("\t- [x] A.1: Tabbed\n    - [ ] A.2: Spaced\n  - [ ] A.3: Third\n"));
+}
+
+// ─── T.156: --archived is an alias for --include-archived ───────────────────
+
+#[test]
+fn t156_archived_alias() {
+    let dir = TempDir::new().unwrap();
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-01-00-00-shelved.md",
+        &sample_spec_content(),
+    );
+    create_sample_spec(&dir, "2025-01-02-00-00-active.md", &sample_spec_content());
+
+    tinyspec(&dir)
+        .args(["archive", "shelved"])
+        .assert()
+        .success();
+    assert!(
+        dir.path()
+            .join(".specs/archive/v1/2025-01-01-00-00-shelved.md")
+            .exists()
+    );
+
+    tinyspec(&dir)
+        .args(["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("shelved").not());
+    for args in [["list", "--archived"], ["status", "--archived"]] {
+        tinyspec(&dir)
+            .args(args)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("shelved"))
+            .stdout(predicate::str::contains("active"));
+    }
+}
+
+// ─── T.157: rename keeps timestamp and group, updates references ───────────
+
+#[test]
+fn t157_rename_spec() {
+    let dir = TempDir::new().unwrap();
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-01-00-00-old-name.md",
+        &sample_spec_content(),
+    );
+    create_sample_spec(
+        &dir,
+        "2025-01-02-00-00-block.md",
+        "---\ntinySpec: v0\ntitle: Block\ndepends_on:\n  - old-name\n  - other\n---\n",
+    );
+    create_sample_spec(
+        &dir,
+        "2025-01-03-00-00-flow.md",
+        "---\ntinySpec: v0\ntitle: Flow\ndepends_on: [other, old-name]\n---\n",
+    );
+    fs::write(dir.path().join(".specs/.order"), "block\nold-name\n").unwrap();
+    fs::write(dir.path().join(".tinyspec-focus"), "old-name\n").unwrap();
+
+    tinyspec(&dir)
+        .args(["rename", "old-name", "Bad_Name"])
+        .assert()
+        .failure();
+    tinyspec(&dir)
+        .args(["rename", "old-name", "flow"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "A spec named 'flow' already exists",
+        ));
+
+    tinyspec(&dir)
+        .args(["rename", "old-name", "new-name", "--update-title"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renamed old-name to new-name"))
+        .stdout(predicate::str::contains("Updated depends_on in 2 spec(s)"));
+
+    let renamed = dir.path().join(".specs/v1/2025-01-01-00-00-new-name.md");
+    assert!(renamed.exists());
+    assert!(
+        !dir.path()
+            .join(".specs/v1/2025-01-01-00-00-old-name.md")
+            .exists()
+    );
+    let content = fs::read_to_string(renamed).unwrap();
+    assert!(content.contains("title: New Name"));
+
+    let block = fs::read_to_string(dir.path().join(".specs/2025-01-02-00-00-block.md")).unwrap();
+    assert!(block.contains("  - new-name\n  - other\n"));
+    let flow = fs::read_to_string(dir.path().join(".specs/2025-01-03-00-00-flow.md")).unwrap();
+    assert!(flow.contains("depends_on: [other, new-name]"));
+    let order = fs::read_to_string(dir.path().join(".specs/.order")).unwrap();
+    assert_eq!(order, "block\nnew-name\n");
+    let focus = fs::read_to_string(dir.path().join(".tinyspec-focus")).unwrap();
+    assert_eq!(focus.trim(), "new-name");
+}
+
+// ─── T.158: move relocates a spec between groups ───────────────────────────
+
+#[test]
+fn t158_move_spec() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-loose.md", &sample_spec_content());
+
+    tinyspec(&dir)
+        .args(["move", "loose", "v2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Moved loose to group 'v2'"));
+    assert!(
+        dir.path()
+            .join(".specs/v2/2025-01-01-00-00-loose.md")
+            .exists()
+    );
+    assert!(!dir.path().join(".specs/2025-01-01-00-00-loose.md").exists());
+
+    tinyspec(&dir)
+        .args(["move", "loose", "v2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already in group 'v2'"));
+    tinyspec(&dir)
+        .args(["move", "loose", "archive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("reserved"));
+    tinyspec(&dir).args(["move", "loose"]).assert().failure();
+
+    // A stale copy with the same name in the target refuses the move
+    create_grouped_spec(
+        &dir,
+        "v3",
+        "2024-01-01-00-00-loose.md",
+        &sample_spec_content(),
+    );
+    tinyspec(&dir)
+        .args(["move", "v2/loose", "v3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "A spec named 'loose' already exists",
+        ));
+    fs::remove_dir_all(dir.path().join(".specs/v3")).unwrap();
+
+    // Ungrouping removes the emptied group folder
+    tinyspec(&dir)
+        .args(["move", "loose", "--ungroup"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Moved loose out of its group"));
+    assert!(dir.path().join(".specs/2025-01-01-00-00-loose.md").exists());
+    assert!(!dir.path().join(".specs/v2").exists());
+}
+
+// ─── T.159: search shows section headings, supports regex and titles ───────
+
+#[test]
+fn t159_search_sections_regex_title_only() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-hello.md", &sample_spec_content());
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-02-00-00-other.md",
+        "---\ntinySpec: v0\ntitle: Subtask Survey\n---\n\n# Background\n\nNothing here.\n",
+    );
+
+    tinyspec(&dir)
+        .args(["search", "subtask"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello  Hello World"))
+        .stdout(predicate::str::contains(
+            "  # Implementation Plan\n    line 19:",
+        ))
+        .stdout(predicate::str::contains("v1/other  Subtask Survey"));
+
+    // Highlighting follows --color
+    tinyspec(&dir)
+        .args(["--color", "always", "search", "proposal"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\u{1b}["));
+    tinyspec(&dir)
+        .args(["search", "proposal"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\u{1b}[").not());
+
+    tinyspec(&dir)
+        .args(["search", "subtask", "--title-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("v1/other"))
+        .stdout(predicate::str::contains("hello").not());
+
+    tinyspec(&dir)
+        .args(["search", "--regex", r"B\.[23]:"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("B.2: Subtask two"))
+        .stdout(predicate::str::contains("B.3: Subtask three"))
+        .stdout(predicate::str::contains("B.1").not());
+    tinyspec(&dir)
+        .args(["search", "B.2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("B.2: Subtask two"));
+
+    tinyspec(&dir)
+        .args(["search", "--regex", "("])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid regex"));
+}
+
+// ─── T.160: next prints the next unchecked task and subtask ────────────────
+
+#[test]
+fn t160_next_task() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-hello.md", &sample_spec_content());
+
+    tinyspec(&dir)
+        .args(["next", "hello"])
+        .assert()
+        .success()
+        .stdout("A: Do this\n  A.1: Do this subtask\n");
+
+    for task in ["A", "B.1"] {
+        tinyspec(&dir)
+            .args(["check", "hello", task])
+            .assert()
+            .success();
+    }
+    let output = tinyspec(&dir)
+        .args(["next", "hello", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["spec"], "hello");
+    assert_eq!(json["task"]["id"], "B");
+    assert_eq!(json["task"]["description"], "Do that");
+    assert_eq!(json["subtask"]["id"], "B.2");
+
+    tinyspec(&dir)
+        .args(["check", "hello", "B"])
+        .assert()
+        .success();
+    tinyspec(&dir)
+        .args(["next", "hello"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "All tasks in spec 'hello' are complete.",
+        ));
+    let output = tinyspec(&dir)
+        .args(["next", "hello", "--json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json["task"].is_null());
+    assert!(json["subtask"].is_null());
+}
+
+// ─── T.161: global --output json matches each command's --json ─────────────
+
+#[test]
+fn t161_global_output_json() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(&dir, "2025-01-01-00-00-hello.md", &sample_spec_content());
+
+    for args in [
+        vec!["list"],
+        vec!["status"],
+        vec!["status", "hello"],
+        vec!["view", "hello"],
+        vec!["templates"],
+        vec!["next", "hello"],
+        vec!["velocity"],
+    ] {
+        let flagged = tinyspec(&dir).args(&args).arg("--json").output().unwrap();
+        // The flag is global, so it works before or after the subcommand
+        let global = tinyspec(&dir)
+            .args(["--output", "json"])
+            .args(&args)
+            .output()
+            .unwrap();
+        assert!(global.status.success(), "{args:?}");
+        assert_eq!(global.stdout, flagged.stdout, "{args:?}");
+        serde_json::from_slice::<serde_json::Value>(&global.stdout).unwrap();
+    }
+
+    tinyspec(&dir)
+        .args(["status", "hello", "--output", "text"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello: 0/7 tasks complete"));
+
+    tinyspec(&dir)
+        .args(["list", "--output", "yaml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid --output value 'yaml'. Use: text, json",
+        ));
+    for args in [vec!["lint", "hello"], vec!["status", "hello", "--tree"]] {
+        tinyspec(&dir)
+            .args(&args)
+            .args(["--output", "json"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--output json is not supported"));
+    }
+}
+
+// ─── T.162: duplicate names resolve to the newest file across groups ───────
+
+#[test]
+fn t162_duplicate_name_resolves_to_newest_file() {
+    let dir = TempDir::new().unwrap();
+    // By path the grouped copy sorts last, but by filename it's the older one
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        "---\ntinySpec: v0\ntitle: Newest\n---\n",
+    );
+    create_grouped_spec(
+        &dir,
+        "v1",
+        "2025-01-01-00-00-hello-world.md",
+        "---\ntinySpec: v0\ntitle: Oldest\n---\n",
+    );
+
+    tinyspec(&dir)
+        .args(["view", "hello-world"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("title: Newest"));
+}
+
+// ─── T.163: tasks nest by indent at any depth ──────────────────────────────
+
+#[test]
+fn t163_grandchild_tasks_nest_under_their_parent() {
+    let dir = TempDir::new().unwrap();
+    create_sample_spec(
+        &dir,
+        "2025-02-17-09-36-hello-world.md",
+        &sample_spec_content(),
+    );
+
+    tinyspec(&dir)
+        .args(["add-task", "hello-world", "Deeper", "--under", "A.1"])
+        .assert()
+        .success()
+        .stdout("Added task A.1.1: Deeper\n");
+
+    tinyspec(&dir)
+        .args(["status", "hello-world", "--tree"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "  ☐ A: Do this\n    ☐ A.1: Do this subtask\n      ☐ A.1.1: Deeper\n    ☐ A.2: Do this other subtask\n",
+        ));
+
+    let output = tinyspec(&dir)
+        .args(["status", "hello-world", "--json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let a = &json["tasks"][0];
+    assert_eq!(a["children"].as_array().unwrap().len(), 2);
+    assert_eq!(a["children"][0]["children"][0]["id"], "A.1.1");
+    assert_eq!(json["total"], 8);
+}
+
+// ─── T.164: a malformed .tinyspec.yaml is an error, not a silent fallback ──
+
+#[test]
+fn t164_malformed_project_config_is_reported() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join(".tinyspec.yaml"),
+        "specs_dir: [docs/specs\n",
+    )
+    .unwrap();
+
+    for args in [vec!["new", "my-feature"], vec!["list"]] {
+        tinyspec(&dir)
+            .args(args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Failed to parse"))
+            .stderr(predicate::str::contains(".tinyspec.yaml"));
+    }
+    assert!(!dir.path().join(".specs").exists());
+}